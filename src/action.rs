@@ -1,7 +1,15 @@
 use std::fmt::Debug;
 
+use dyn_clone::DynClone;
+use dyn_eq::DynEq;
+use dyn_hash::DynHash;
+
 use crate::game::Game;
 
-pub trait Action: Debug {
+pub trait Action: Debug + DynClone + DynEq + DynHash + Send {
     fn run(&self, game: &mut Game);
 }
+
+dyn_clone::clone_trait_object!(Action);
+dyn_eq::eq_trait_object!(Action);
+dyn_hash::hash_trait_object!(Action);
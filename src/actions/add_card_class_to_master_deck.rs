@@ -3,6 +3,7 @@ use crate::{
     game::Game,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct AddCardClassToMasterDeckAction(pub CardClass);
 
 impl Action for AddCardClassToMasterDeckAction {
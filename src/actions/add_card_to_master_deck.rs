@@ -1,17 +1,18 @@
 use crate::{
     action::Action,
     actions::{gain_gold::GainGoldAction, increase_max_hp::IncreaseMaxHPAction},
-    card::CardRef,
+    card::{Card, CardRef},
     cards::CardType,
     game::Game,
     relic::RelicClass,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct AddCardToMasterDeckAction(pub CardRef);
 
 impl Action for AddCardToMasterDeckAction {
     fn run(&self, game: &mut Game) {
-        let mut c = self.0.borrow_mut();
+        let c = &game.cards[self.0];
         if c.class.ty() == CardType::Curse
             && let Some(v) = game.get_relic_value(RelicClass::Omamori)
             && v > 0
@@ -34,11 +35,10 @@ impl Action for AddCardToMasterDeckAction {
                 _ => false,
             };
         if should_upgrade {
-            c.upgrade();
+            game.update_card(self.0, Card::upgrade);
         }
-        drop(c);
 
-        game.master_deck.push(self.0.clone());
+        game.master_deck.push(self.0);
     }
 }
 
@@ -1,11 +1,13 @@
 use crate::{
     action::Action,
     actions::upgrade::UpgradeAction,
+    card::Card,
     game::Game,
     state::{GameState, Steps},
     step::Step,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ArmamentsAction();
 
 impl Action for ArmamentsAction {
@@ -13,11 +15,11 @@ impl Action for ArmamentsAction {
         let upgradable = game
             .hand
             .iter()
-            .filter(|c| c.borrow().can_upgrade())
+            .filter(|c| game.cards[*c].can_upgrade())
             .collect::<Vec<_>>();
         match upgradable.len() {
             0 => {}
-            1 => upgradable[0].borrow_mut().upgrade(),
+            1 => game.update_card(*upgradable[0], Card::upgrade),
             _ => game.state.push_state(ChooseArmamentsGameState),
         }
     }
@@ -29,18 +31,17 @@ impl std::fmt::Debug for ArmamentsAction {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct ChooseArmamentsGameState;
 
 impl GameState for ChooseArmamentsGameState {
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
-        let mut moves = Steps::default();
+    fn valid_steps(&self, game: &Game, moves: &mut Steps) -> bool {
         for (i, c) in game.hand.iter().enumerate() {
-            if c.borrow().can_upgrade() {
+            if game.cards[c].can_upgrade() {
                 moves.push(ArmamentsStep { hand_index: i });
             }
         }
-        Some(moves)
+        true
     }
 }
 
@@ -55,14 +56,13 @@ impl Step for ArmamentsStep {
     }
     fn run(&self, game: &mut Game) {
         game.action_queue
-            .push_top(UpgradeAction(game.hand[self.hand_index].clone()));
+            .push_top(UpgradeAction(game.hand[self.hand_index]));
     }
 
     fn description(&self, game: &Game) -> String {
         format!(
             "upgrade card {} ({:?})",
-            self.hand_index,
-            game.hand[self.hand_index].borrow()
+            self.hand_index, &game.cards[game.hand[self.hand_index]]
         )
     }
 }
@@ -20,6 +20,7 @@ pub fn calculate_modified_block(amount: i32, c: &Creature) -> i32 {
     amount_f as i32
 }
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct BlockAction {
     target: CreatureRef,
     amount: i32,
@@ -61,6 +62,7 @@ impl Action for BlockAction {
         if c.block > 999 {
             c.block = 999;
         }
+        c.rehash();
         if amount > 0
             && self.target.is_player()
             && let Some(j) = game.player.get_status(Status::Juggernaut)
@@ -0,0 +1,30 @@
+use crate::{
+    action::Action,
+    actions::block::BlockAction,
+    game::{CreatureRef, Game},
+};
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct BlockOtherMonstersAction {
+    pub source: CreatureRef,
+    pub amount: i32,
+}
+
+impl Action for BlockOtherMonstersAction {
+    fn run(&self, game: &mut Game) {
+        for (mi, m) in game.monsters.iter().enumerate().rev() {
+            let target = CreatureRef::monster(mi);
+            if !m.creature.is_actionable() || target == self.source {
+                continue;
+            }
+            game.action_queue
+                .push_top(BlockAction::monster(target, self.amount));
+        }
+    }
+}
+
+impl std::fmt::Debug for BlockOtherMonstersAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "block other monsters {}", self.amount)
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{action::Action, actions::block::BlockAction, cards::CardType, game::Game};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct BlockPerNonAttackInHandAction(pub i32);
 
 impl Action for BlockPerNonAttackInHandAction {
@@ -7,7 +8,7 @@ impl Action for BlockPerNonAttackInHandAction {
         let count = game
             .hand
             .iter()
-            .filter(|c| c.borrow().class.ty() != CardType::Attack)
+            .filter(|c| game.cards[*c].class.ty() != CardType::Attack)
             .count();
         for _ in 0..count {
             game.action_queue.push_top(BlockAction::player_card(self.0));
@@ -5,6 +5,7 @@ use crate::{
     rng::rand_slice,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct BlockRandomMonsterAction {
     pub source: CreatureRef,
     pub amount: i32,
@@ -6,15 +6,16 @@ use crate::{
     step::Step,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ChooseCardInDiscardToPlaceOnTopOfDrawAction();
 
 impl Action for ChooseCardInDiscardToPlaceOnTopOfDrawAction {
     fn run(&self, game: &mut Game) {
         match game.discard_pile.len() {
             0 => {}
-            1 => game
-                .action_queue
-                .push_top(PlaceCardOnTopOfDrawAction(game.discard_pile.pop().unwrap())),
+            1 => game.action_queue.push_top(PlaceCardOnTopOfDrawAction(
+                game.discard_pile.pop(&game.cards).unwrap(),
+            )),
             _ => game
                 .state
                 .push_state(ChooseCardInDiscardToPlaceOnTopOfDrawGameState),
@@ -28,16 +29,15 @@ impl std::fmt::Debug for ChooseCardInDiscardToPlaceOnTopOfDrawAction {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct ChooseCardInDiscardToPlaceOnTopOfDrawGameState;
 
 impl GameState for ChooseCardInDiscardToPlaceOnTopOfDrawGameState {
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
-        let mut moves = Steps::default();
+    fn valid_steps(&self, game: &Game, moves: &mut Steps) -> bool {
         for i in 0..game.discard_pile.len() {
             moves.push(PlaceCardInDiscardOnTopOfDrawStep { discard_index: i });
         }
-        Some(moves)
+        true
     }
 }
 
@@ -53,15 +53,14 @@ impl Step for PlaceCardInDiscardOnTopOfDrawStep {
 
     fn run(&self, game: &mut Game) {
         game.action_queue.push_top(PlaceCardOnTopOfDrawAction(
-            game.discard_pile.remove(self.discard_index),
+            game.discard_pile.remove(self.discard_index, &game.cards),
         ));
     }
 
     fn description(&self, game: &Game) -> String {
         format!(
             "place card on top of draw {} ({:?})",
-            self.discard_index,
-            game.discard_pile[self.discard_index].borrow()
+            self.discard_index, &game.cards[game.discard_pile[self.discard_index]]
         )
     }
 }
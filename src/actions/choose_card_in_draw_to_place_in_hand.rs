@@ -7,6 +7,7 @@ use crate::{
     step::Step,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ChooseCardInDrawToPlaceInHandAction(pub CardType);
 
 enum Count {
@@ -19,7 +20,7 @@ impl Action for ChooseCardInDrawToPlaceInHandAction {
     fn run(&self, game: &mut Game) {
         let mut count = Count::Zero;
         for (i, c) in game.draw_pile.get_all().into_iter().enumerate() {
-            if c.borrow().class.ty() == self.0 {
+            if game.cards[c].class.ty() == self.0 {
                 match count {
                     Count::Zero => count = Count::One(i),
                     Count::One(_) => {
@@ -33,7 +34,7 @@ impl Action for ChooseCardInDrawToPlaceInHandAction {
         match count {
             Count::Zero => unreachable!(),
             Count::One(i) => {
-                let c = game.draw_pile.take(i);
+                let c = game.draw_pile.take(i, &game.cards);
                 game.action_queue.push_top(PlaceCardInHandAction(c));
             }
             Count::Many => game.state.push_state(FetchCardFromDrawGameState(self.0)),
@@ -47,18 +48,17 @@ impl std::fmt::Debug for ChooseCardInDrawToPlaceInHandAction {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct FetchCardFromDrawGameState(CardType);
 
 impl GameState for FetchCardFromDrawGameState {
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
-        let mut moves = Steps::default();
+    fn valid_steps(&self, game: &Game, moves: &mut Steps) -> bool {
         for (i, c) in game.draw_pile.get_all().iter().enumerate() {
-            if c.borrow().class.ty() == self.0 {
+            if game.cards[*c].class.ty() == self.0 {
                 moves.push(FetchFromDrawStep { draw_index: i });
             }
         }
-        Some(moves)
+        true
     }
 
     fn run(&self, _: &mut Game) {}
@@ -75,7 +75,7 @@ impl Step for FetchFromDrawStep {
     }
 
     fn run(&self, game: &mut Game) {
-        let c = game.draw_pile.take(self.draw_index);
+        let c = game.draw_pile.take(self.draw_index, &game.cards);
         game.action_queue.push_top(PlaceCardInHandAction(c));
     }
 
@@ -83,7 +83,7 @@ impl Step for FetchFromDrawStep {
         format!(
             "fetch {} ({:?})",
             self.draw_index,
-            game.draw_pile.get(self.draw_index).borrow()
+            &game.cards[game.draw_pile.get(self.draw_index)]
         )
     }
 }
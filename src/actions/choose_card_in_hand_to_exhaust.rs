@@ -6,6 +6,7 @@ use crate::{
     step::Step,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ChooseCardInHandToExhaustAction();
 
 impl Action for ChooseCardInHandToExhaustAction {
@@ -14,7 +15,7 @@ impl Action for ChooseCardInHandToExhaustAction {
             0 => {}
             1 => game
                 .action_queue
-                .push_top(ExhaustCardAction(game.hand.pop().unwrap())),
+                .push_top(ExhaustCardAction(game.hand.pop(&game.cards).unwrap())),
             _ => game.state.push_state(ChooseExhaustOneCardInHandGameState),
         }
     }
@@ -26,16 +27,15 @@ impl std::fmt::Debug for ChooseCardInHandToExhaustAction {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct ChooseExhaustOneCardInHandGameState;
 
 impl GameState for ChooseExhaustOneCardInHandGameState {
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
-        let mut moves = Steps::default();
+    fn valid_steps(&self, game: &Game, moves: &mut Steps) -> bool {
         for i in 0..game.hand.len() {
             moves.push(ExhaustOneCardInHandStep { hand_index: i });
         }
-        Some(moves)
+        true
     }
 }
 
@@ -50,15 +50,15 @@ impl Step for ExhaustOneCardInHandStep {
     }
 
     fn run(&self, game: &mut Game) {
-        game.action_queue
-            .push_top(ExhaustCardAction(game.hand.remove(self.hand_index)));
+        game.action_queue.push_top(ExhaustCardAction(
+            game.hand.remove(self.hand_index, &game.cards),
+        ));
     }
 
     fn description(&self, game: &Game) -> String {
         format!(
             "exhaust {} ({:?})",
-            self.hand_index,
-            game.hand[self.hand_index].borrow()
+            self.hand_index, &game.cards[game.hand[self.hand_index]]
         )
     }
 }
@@ -6,15 +6,16 @@ use crate::{
     step::Step,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ChooseCardInHandToPlaceOnTopOfDrawAction();
 
 impl Action for ChooseCardInHandToPlaceOnTopOfDrawAction {
     fn run(&self, game: &mut Game) {
         match game.hand.len() {
             0 => {}
-            1 => game
-                .action_queue
-                .push_top(PlaceCardOnTopOfDrawAction(game.hand.pop().unwrap())),
+            1 => game.action_queue.push_top(PlaceCardOnTopOfDrawAction(
+                game.hand.pop(&game.cards).unwrap(),
+            )),
             _ => game
                 .state
                 .push_state(ChooseCardInHandToPlaceOnTopOfDrawGameState),
@@ -28,16 +29,15 @@ impl std::fmt::Debug for ChooseCardInHandToPlaceOnTopOfDrawAction {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct ChooseCardInHandToPlaceOnTopOfDrawGameState;
 
 impl GameState for ChooseCardInHandToPlaceOnTopOfDrawGameState {
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
-        let mut moves = Steps::default();
+    fn valid_steps(&self, game: &Game, moves: &mut Steps) -> bool {
         for i in 0..game.hand.len() {
             moves.push(PlaceCardInHandOnTopOfDrawStep { hand_index: i });
         }
-        Some(moves)
+        true
     }
 }
 
@@ -53,15 +53,14 @@ impl Step for PlaceCardInHandOnTopOfDrawStep {
 
     fn run(&self, game: &mut Game) {
         game.action_queue.push_top(PlaceCardOnTopOfDrawAction(
-            game.hand.remove(self.hand_index),
+            game.hand.remove(self.hand_index, &game.cards),
         ));
     }
 
     fn description(&self, game: &Game) -> String {
         format!(
             "place card on top of draw {} ({:?})",
-            self.hand_index,
-            game.hand[self.hand_index].borrow()
+            self.hand_index, &game.cards[game.hand[self.hand_index]]
         )
     }
 }
@@ -7,6 +7,7 @@ use crate::{
     step::Step,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ChooseCardToShuffleIntoDrawAction();
 
 impl Action for ChooseCardToShuffleIntoDrawAction {
@@ -29,19 +30,18 @@ impl std::fmt::Debug for ChooseCardToShuffleIntoDrawAction {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct ChooseCardToShuffleIntoDrawGameState {
     classes: Vec<CardClass>,
 }
 
 impl GameState for ChooseCardToShuffleIntoDrawGameState {
-    fn valid_steps(&self, _: &Game) -> Option<Steps> {
-        let mut moves = Steps::default();
+    fn valid_steps(&self, _: &Game, moves: &mut Steps) -> bool {
         moves.push(ContinueStep);
         for &class in &self.classes {
             moves.push(ChooseCardToShuffleIntoDrawStep { class })
         }
-        Some(moves)
+        true
     }
 }
 
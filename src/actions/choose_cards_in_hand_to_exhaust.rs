@@ -6,6 +6,7 @@ use crate::{
     step::Step,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ChooseCardsInHandToExhaustAction(pub i32);
 
 impl Action for ChooseCardsInHandToExhaustAction {
@@ -24,14 +25,13 @@ impl std::fmt::Debug for ChooseCardsInHandToExhaustAction {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct ChooseExhaustCardsInHandGameState {
     pub num_cards_remaining: i32,
 }
 
 impl GameState for ChooseExhaustCardsInHandGameState {
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
-        let mut moves = Steps::default();
+    fn valid_steps(&self, game: &Game, moves: &mut Steps) -> bool {
         moves.push(ChooseExhaustCardsInHandEndStep);
         if self.num_cards_remaining > 0 {
             for c in 0..game.hand.len() {
@@ -41,7 +41,7 @@ impl GameState for ChooseExhaustCardsInHandGameState {
                 });
             }
         }
-        Some(moves)
+        true
     }
 }
 
@@ -57,7 +57,8 @@ impl Step for ChooseExhaustCardsInHandStep {
     }
 
     fn run(&self, game: &mut Game) {
-        game.chosen_cards.push(game.hand.remove(self.hand_index));
+        game.chosen_cards
+            .push(game.hand.remove(self.hand_index, &game.cards), &game.cards);
         game.state.push_state(ChooseExhaustCardsInHandGameState {
             num_cards_remaining: self.num_cards_remaining - 1,
         });
@@ -66,8 +67,7 @@ impl Step for ChooseExhaustCardsInHandStep {
     fn description(&self, game: &Game) -> String {
         format!(
             "exhaust {} ({:?})",
-            self.hand_index,
-            game.hand[self.hand_index].borrow()
+            self.hand_index, &game.cards[game.hand[self.hand_index]]
         )
     }
 }
@@ -81,7 +81,7 @@ impl Step for ChooseExhaustCardsInHandEndStep {
     }
 
     fn run(&self, game: &mut Game) {
-        while let Some(c) = game.chosen_cards.pop() {
+        while let Some(c) = game.chosen_cards.pop(&game.cards) {
             game.action_queue.push_top(ExhaustCardAction(c));
         }
     }
@@ -10,6 +10,7 @@ use crate::{
     step::Step,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub enum ChooseDiscoveryType {
     Red,
     RedAttack,
@@ -18,6 +19,7 @@ pub enum ChooseDiscoveryType {
     Colorless,
 }
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ChooseDiscoveryAction {
     pub ty: ChooseDiscoveryType,
     pub amount: i32,
@@ -53,7 +55,7 @@ impl std::fmt::Debug for ChooseDiscoveryAction {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct ChooseDiscoveryGameState {
     classes: Vec<CardClass>,
     amount: i32,
@@ -61,8 +63,7 @@ struct ChooseDiscoveryGameState {
 }
 
 impl GameState for ChooseDiscoveryGameState {
-    fn valid_steps(&self, _: &Game) -> Option<Steps> {
-        let mut moves = Steps::default();
+    fn valid_steps(&self, _: &Game, moves: &mut Steps) -> bool {
         for &class in &self.classes {
             moves.push(DiscoveryStep {
                 class,
@@ -70,7 +71,7 @@ impl GameState for ChooseDiscoveryGameState {
                 is_free: self.is_free,
             });
         }
-        Some(moves)
+        true
     }
 }
 
@@ -1,13 +1,14 @@
 use crate::{
     action::Action,
     actions::dual_wield::DualWieldAction,
-    card::CardRef,
+    card::Card,
     cards::CardType,
     game::Game,
     state::{GameState, Steps},
     step::Step,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ChooseDualWieldAction(pub i32);
 
 enum Count {
@@ -16,15 +17,15 @@ enum Count {
     Many,
 }
 
-pub fn can_dual_wield(c: &CardRef) -> bool {
-    matches!(c.borrow().class.ty(), CardType::Attack | CardType::Power)
+pub fn can_dual_wield(c: &Card) -> bool {
+    matches!(c.class.ty(), CardType::Attack | CardType::Power)
 }
 
 impl Action for ChooseDualWieldAction {
     fn run(&self, game: &mut Game) {
         let mut count = Count::Zero;
         for (i, c) in game.hand.iter().enumerate() {
-            if can_dual_wield(c) {
+            if can_dual_wield(&game.cards[c]) {
                 match count {
                     Count::Zero => count = Count::One(i),
                     Count::One(_) => {
@@ -39,7 +40,7 @@ impl Action for ChooseDualWieldAction {
             Count::Zero => {}
             Count::One(i) => {
                 game.action_queue.push_top(DualWieldAction {
-                    card: game.hand.remove(i),
+                    card: game.hand.remove(i, &game.cards),
                     amount: self.0,
                     destroy_original: false,
                 });
@@ -68,7 +69,7 @@ impl Step for DualWieldStep {
 
     fn run(&self, game: &mut Game) {
         game.action_queue.push_top(DualWieldAction {
-            card: game.hand.remove(self.hand_index),
+            card: game.hand.remove(self.hand_index, &game.cards),
             amount: self.amount,
             destroy_original: true,
         });
@@ -77,28 +78,26 @@ impl Step for DualWieldStep {
     fn description(&self, game: &Game) -> String {
         format!(
             "dual wield {} ({:?})",
-            self.hand_index,
-            game.hand[self.hand_index].borrow()
+            self.hand_index, &game.cards[game.hand[self.hand_index]]
         )
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct DualWieldGameState {
     amount: i32,
 }
 
 impl GameState for DualWieldGameState {
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
-        let mut moves = Steps::default();
+    fn valid_steps(&self, game: &Game, moves: &mut Steps) -> bool {
         for (i, c) in game.hand.iter().enumerate() {
-            if can_dual_wield(c) {
+            if can_dual_wield(&game.cards[c]) {
                 moves.push(DualWieldStep {
                     hand_index: i,
                     amount: self.amount,
                 });
             }
         }
-        Some(moves)
+        true
     }
 }
@@ -6,6 +6,7 @@ use crate::{
     step::Step,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ChooseForethoughtAnyAction();
 
 impl Action for ChooseForethoughtAnyAction {
@@ -23,17 +24,16 @@ impl std::fmt::Debug for ChooseForethoughtAnyAction {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct ForethoughtAnyGameState;
 
 impl GameState for ForethoughtAnyGameState {
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
-        let mut moves = Steps::default();
+    fn valid_steps(&self, game: &Game, moves: &mut Steps) -> bool {
         moves.push(ForethoughtAnyEndStep);
         for c in 0..game.hand.len() {
             moves.push(ForethoughtAnyStep { hand_index: c });
         }
-        Some(moves)
+        true
     }
 }
 
@@ -48,15 +48,15 @@ impl Step for ForethoughtAnyStep {
     }
 
     fn run(&self, game: &mut Game) {
-        game.chosen_cards.push(game.hand.remove(self.hand_index));
+        game.chosen_cards
+            .push(game.hand.remove(self.hand_index, &game.cards), &game.cards);
         game.state.push_state(ForethoughtAnyGameState);
     }
 
     fn description(&self, game: &Game) -> String {
         format!(
             "forethought {} ({:?})",
-            self.hand_index,
-            game.hand[self.hand_index].borrow()
+            self.hand_index, &game.cards[game.hand[self.hand_index]]
         )
     }
 }
@@ -72,7 +72,7 @@ impl Step for ForethoughtAnyEndStep {
     fn run(&self, game: &mut Game) {
         while !game.chosen_cards.is_empty() {
             game.action_queue
-                .push_top(ForethoughtAction(game.chosen_cards.remove(0)));
+                .push_top(ForethoughtAction(game.chosen_cards.remove(0, &game.cards)));
         }
     }
 
@@ -6,6 +6,7 @@ use crate::{
     step::Step,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ChooseForethoughtOneAction();
 
 impl Action for ChooseForethoughtOneAction {
@@ -14,7 +15,7 @@ impl Action for ChooseForethoughtOneAction {
             0 => {}
             1 => game
                 .action_queue
-                .push_top(ForethoughtAction(game.hand.pop().unwrap())),
+                .push_top(ForethoughtAction(game.hand.pop(&game.cards).unwrap())),
             _ => game.state.push_state(ForethoughtOneGameState),
         }
     }
@@ -26,16 +27,15 @@ impl std::fmt::Debug for ChooseForethoughtOneAction {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct ForethoughtOneGameState;
 
 impl GameState for ForethoughtOneGameState {
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
-        let mut moves = Steps::default();
+    fn valid_steps(&self, game: &Game, moves: &mut Steps) -> bool {
         for c in 0..game.hand.len() {
             moves.push(ForethoughtOneStep { hand_index: c });
         }
-        Some(moves)
+        true
     }
 }
 
@@ -50,15 +50,14 @@ impl Step for ForethoughtOneStep {
     }
 
     fn run(&self, game: &mut Game) {
-        let c = game.hand.remove(self.hand_index);
+        let c = game.hand.remove(self.hand_index, &game.cards);
         game.action_queue.push_top(ForethoughtAction(c));
     }
 
     fn description(&self, game: &Game) -> String {
         format!(
             "forethought {} ({:?})",
-            self.hand_index,
-            game.hand[self.hand_index].borrow()
+            self.hand_index, &game.cards[game.hand[self.hand_index]]
         )
     }
 }
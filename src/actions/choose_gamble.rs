@@ -6,6 +6,7 @@ use crate::{
     step::Step,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ChooseGambleAction();
 
 impl Action for ChooseGambleAction {
@@ -23,17 +24,16 @@ impl std::fmt::Debug for ChooseGambleAction {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct ChooseGambleGameState;
 
 impl GameState for ChooseGambleGameState {
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
-        let mut moves = Steps::default();
+    fn valid_steps(&self, game: &Game, moves: &mut Steps) -> bool {
         moves.push(GambleEndStep);
         for c in 0..game.hand.len() {
             moves.push(GambleStep { hand_index: c });
         }
-        Some(moves)
+        true
     }
 }
 
@@ -48,14 +48,14 @@ impl Step for GambleStep {
     }
 
     fn run(&self, game: &mut Game) {
-        game.chosen_cards.push(game.hand.remove(self.hand_index));
+        game.chosen_cards
+            .push(game.hand.remove(self.hand_index, &game.cards), &game.cards);
     }
 
     fn description(&self, game: &Game) -> String {
         format!(
             "gamble {} ({:?})",
-            self.hand_index,
-            game.hand[self.hand_index].borrow()
+            self.hand_index, &game.cards[game.hand[self.hand_index]]
         )
     }
 }
@@ -71,7 +71,7 @@ impl Step for GambleEndStep {
     fn run(&self, game: &mut Game) {
         let count = game.chosen_cards.len() as i32;
         game.action_queue.push_top(DrawAction(count));
-        while let Some(c) = game.chosen_cards.pop() {
+        while let Some(c) = game.chosen_cards.pop(&game.cards) {
             game.action_queue.push_top(DiscardCardAction(c));
         }
     }
@@ -6,6 +6,7 @@ use crate::{
     step::Step,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ChooseMemoriesAction(pub i32);
 
 impl Action for ChooseMemoriesAction {
@@ -15,7 +16,7 @@ impl Action for ChooseMemoriesAction {
                 (game.discard_pile.len() as i32).min(Game::MAX_HAND_SIZE - game.hand.len() as i32);
             for _ in 0..count {
                 game.action_queue
-                    .push_top(MemoriesAction(game.discard_pile.remove(0)));
+                    .push_top(MemoriesAction(game.discard_pile.remove(0, &game.cards)));
             }
         } else {
             game.state.push_state(ChooseMemoriesGameState {
@@ -31,14 +32,13 @@ impl std::fmt::Debug for ChooseMemoriesAction {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct ChooseMemoriesGameState {
     num_cards_remaining: i32,
 }
 
 impl GameState for ChooseMemoriesGameState {
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
-        let mut moves = Steps::default();
+    fn valid_steps(&self, game: &Game, moves: &mut Steps) -> bool {
         moves.push(ChooseMemoriesEndStep);
         if self.num_cards_remaining != 0 {
             for c in 0..game.discard_pile.len() {
@@ -48,7 +48,7 @@ impl GameState for ChooseMemoriesGameState {
                 });
             }
         }
-        Some(moves)
+        true
     }
 }
 
@@ -64,10 +64,12 @@ impl Step for ChooseMemoriesStep {
     }
 
     fn run(&self, game: &mut Game) {
-        game.chosen_cards
-            .push(game.discard_pile.remove(self.discard_index));
+        game.chosen_cards.push(
+            game.discard_pile.remove(self.discard_index, &game.cards),
+            &game.cards,
+        );
         if self.num_cards_remaining == 0 {
-            while let Some(c) = game.chosen_cards.pop() {
+            while let Some(c) = game.chosen_cards.pop(&game.cards) {
                 game.action_queue.push_top(MemoriesAction(c));
             }
         } else {
@@ -80,8 +82,7 @@ impl Step for ChooseMemoriesStep {
     fn description(&self, game: &Game) -> String {
         format!(
             "memories {} ({:?})",
-            self.discard_index,
-            game.discard_pile[self.discard_index].borrow()
+            self.discard_index, &game.cards[game.discard_pile[self.discard_index]]
         )
     }
 }
@@ -95,7 +96,7 @@ impl Step for ChooseMemoriesEndStep {
     }
 
     fn run(&self, game: &mut Game) {
-        while let Some(c) = game.chosen_cards.pop() {
+        while let Some(c) = game.chosen_cards.pop(&game.cards) {
             game.action_queue.push_top(MemoriesAction(c));
         }
     }
@@ -2,6 +2,7 @@ use crate::{
     action::Action, actions::discard_card::DiscardCardAction, cards::CardClass, game::Game,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct CreateCardInDiscardAction(pub CardClass);
 
 impl Action for CreateCardInDiscardAction {
@@ -8,14 +8,14 @@ use crate::{
     queue::ActionQueue,
 };
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
 pub enum OnFatalType {
     Feed,
     HandOfGreed,
     RitualDagger { card_id: u32 },
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
 pub struct OnFatal {
     pub ty: OnFatalType,
     pub upgraded: bool,
@@ -39,7 +39,7 @@ impl OnFatal {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
 pub enum DamageType {
     Attack {
         source: CreatureRef,
@@ -51,6 +51,7 @@ pub enum DamageType {
     HPLoss,
 }
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct DamageAction {
     target: CreatureRef,
     amount: i32,
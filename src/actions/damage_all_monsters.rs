@@ -4,6 +4,7 @@ use crate::{
     game::{CreatureRef, Game},
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct DamageAllMonstersAction {
     amount: i32,
     thorns: bool,
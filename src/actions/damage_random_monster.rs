@@ -1,5 +1,6 @@
 use crate::{action::Action, actions::damage::DamageAction, game::Game};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct DamageRandomMonsterAction {
     pub amount: i32,
     pub thorns: bool,
@@ -1,16 +1,21 @@
-use crate::{action::Action, card::CardRef, game::Game};
+use crate::{
+    action::Action,
+    card::{Card, CardRef},
+    game::Game,
+};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct DiscardCardAction(pub CardRef);
 
 impl Action for DiscardCardAction {
     fn run(&self, game: &mut Game) {
-        self.0.borrow_mut().clear_temporary();
-        game.discard_pile.push(self.0.clone());
+        game.update_card(self.0, Card::clear_temporary);
+        game.discard_pile.push(self.0, &game.cards);
     }
 }
 
 impl std::fmt::Debug for DiscardCardAction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "discard {:?}", self.0.borrow())
+        write!(f, "discard {:?}", self.0)
     }
 }
@@ -1,10 +1,11 @@
 use crate::{action::Action, actions::discard_card::DiscardCardAction, game::Game};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct DiscardHandAction();
 
 impl Action for DiscardHandAction {
     fn run(&self, game: &mut Game) {
-        while let Some(c) = game.hand.pop() {
+        while let Some(c) = game.hand.pop(&game.cards) {
             game.action_queue.push_top(DiscardCardAction(c));
         }
     }
@@ -1,9 +1,10 @@
-use crate::{action::Action, card::CardRef, game::Game, rng::rand_slice};
+use crate::{action::Action, card::Card, game::Game, rng::rand_slice};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct DiscountRandomCardInHandAction();
 
-fn can_discount(card: &CardRef) -> bool {
-    match card.borrow().cost {
+fn can_discount(card: &Card) -> bool {
+    match card.cost {
         crate::cards::CardCost::Cost {
             base_cost,
             temporary_cost,
@@ -18,14 +19,14 @@ impl Action for DiscountRandomCardInHandAction {
         let cards = game
             .hand
             .iter()
-            .filter(|c| can_discount(c))
+            .filter(|c| can_discount(&game.cards[*c]))
             .collect::<Vec<_>>();
         let c = match cards.len() {
             0 => return,
             1 => cards[0],
             _ => rand_slice(&mut game.rng, &cards),
         };
-        c.borrow_mut().set_temporary_cost(0);
+        game.update_card(*c, |c| c.set_temporary_cost(0));
     }
 }
 
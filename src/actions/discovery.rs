@@ -5,6 +5,7 @@ use crate::{
     game::Game,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct DiscoveryAction {
     pub class: CardClass,
     pub amount: i32,
@@ -15,10 +16,12 @@ impl Action for DiscoveryAction {
     fn run(&self, game: &mut Game) {
         for _ in 0..self.amount {
             let c = game.new_card(self.class);
-            if self.is_free
-                && let CardCost::Cost { temporary_cost, .. } = &mut c.borrow_mut().cost
-            {
-                *temporary_cost = Some(0)
+            if self.is_free {
+                game.update_card(c, |c| {
+                    if let CardCost::Cost { temporary_cost, .. } = &mut c.cost {
+                        *temporary_cost = Some(0)
+                    }
+                });
             }
             game.action_queue.push_top(PlaceCardInHandAction(c));
         }
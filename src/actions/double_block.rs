@@ -1,5 +1,6 @@
 use crate::{action::Action, actions::block::BlockAction, game::Game};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct DoubleBlockAction();
 
 impl Action for DoubleBlockAction {
@@ -11,6 +11,7 @@ use crate::{
     status::Status,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct DrawAction(pub i32);
 
 impl Action for DrawAction {
@@ -38,21 +39,26 @@ impl Action for DrawAction {
         }
 
         for _ in 0..amount {
-            let c = game.draw_pile.pop(&mut game.rng);
+            let c = game.draw_pile.pop(&mut game.rng, &game.cards);
             {
-                let mut c = c.borrow_mut();
                 if game.player.has_status(Status::Confusion)
-                    && let CardCost::Cost {
-                        base_cost,
-                        temporary_cost,
-                        free_to_play_once,
-                    } = &mut c.cost
+                    && matches!(game.cards[c].cost, CardCost::Cost { .. })
                 {
-                    *base_cost = game.rng.random_range(0..=3);
-                    *temporary_cost = None;
-                    *free_to_play_once = false;
+                    let cost = game.rng.random_range(0..=3);
+                    game.update_card(c, |c| {
+                        if let CardCost::Cost {
+                            base_cost,
+                            temporary_cost,
+                            free_to_play_once,
+                        } = &mut c.cost
+                        {
+                            *base_cost = cost;
+                            *temporary_cost = None;
+                            *free_to_play_once = false;
+                        }
+                    });
                 }
-                let class = c.class;
+                let class = game.cards[c].class;
                 if class == CardClass::Void {
                     game.action_queue.push_bot(GainEnergyAction(-1));
                 }
@@ -66,7 +72,7 @@ impl Action for DrawAction {
                     }
                 }
             }
-            game.hand.push(c);
+            game.hand.push(c, &game.cards);
         }
     }
 }
@@ -107,8 +113,8 @@ mod tests {
         assert_eq!(g.discard_pile.len(), 0);
         assert_eq!(g.draw_pile.len(), 2);
 
-        g.discard_pile.push(g.hand.pop().unwrap());
-        g.discard_pile.push(g.hand.pop().unwrap());
+        g.discard_pile.push(g.hand.pop(&g.cards).unwrap(), &g.cards);
+        g.discard_pile.push(g.hand.pop(&g.cards).unwrap(), &g.cards);
 
         assert_eq!(g.hand.len(), 8);
         assert_eq!(g.discard_pile.len(), 2);
@@ -120,8 +126,8 @@ mod tests {
         assert_eq!(g.discard_pile.len(), 2);
         assert_eq!(g.draw_pile.len(), 0);
 
-        g.discard_pile.push(g.hand.pop().unwrap());
-        g.discard_pile.push(g.hand.pop().unwrap());
+        g.discard_pile.push(g.hand.pop(&g.cards).unwrap(), &g.cards);
+        g.discard_pile.push(g.hand.pop(&g.cards).unwrap(), &g.cards);
 
         assert_eq!(g.hand.len(), 8);
         assert_eq!(g.discard_pile.len(), 4);
@@ -141,10 +147,12 @@ mod tests {
         assert_eq!(g.discard_pile.len(), 0);
         assert_eq!(g.draw_pile.len(), 0);
 
-        g.discard_pile.push(g.hand.pop().unwrap());
-        g.discard_pile.push(g.hand.pop().unwrap());
-        g.draw_pile.push_top(g.hand.pop().unwrap());
-        g.draw_pile.push_top(g.hand.pop().unwrap());
+        g.discard_pile.push(g.hand.pop(&g.cards).unwrap(), &g.cards);
+        g.discard_pile.push(g.hand.pop(&g.cards).unwrap(), &g.cards);
+        g.draw_pile
+            .push_top(g.hand.pop(&g.cards).unwrap(), &g.cards);
+        g.draw_pile
+            .push_top(g.hand.pop(&g.cards).unwrap(), &g.cards);
 
         assert_eq!(g.hand.len(), 5);
         assert_eq!(g.discard_pile.len(), 2);
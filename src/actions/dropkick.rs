@@ -5,6 +5,7 @@ use crate::{
     status::Status,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct DropkickAction(pub CreatureRef);
 
 impl Action for DropkickAction {
@@ -2,6 +2,7 @@ use crate::{
     action::Action, actions::place_card_in_hand::PlaceCardInHandAction, card::CardRef, game::Game,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct DualWieldAction {
     pub card: CardRef,
     pub amount: i32,
@@ -20,14 +21,13 @@ impl Action for DualWieldAction {
                 let new_c = game.clone_card_ref_new_id(&self.card);
                 game.action_queue.push_top(PlaceCardInHandAction(new_c));
             }
-            game.action_queue
-                .push_top(PlaceCardInHandAction(self.card.clone()));
+            game.action_queue.push_top(PlaceCardInHandAction(self.card));
         }
     }
 }
 
 impl std::fmt::Debug for DualWieldAction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "dual wield {} {:?}", self.amount, self.card.borrow())
+        write!(f, "dual wield {} {:?}", self.amount, self.card)
     }
 }
@@ -6,6 +6,7 @@ use crate::{
     status::Status,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct DuvuAction();
 
 impl Action for DuvuAction {
@@ -13,7 +14,7 @@ impl Action for DuvuAction {
         let amount = game
             .master_deck
             .iter()
-            .filter(|c| c.borrow().class.ty() == CardType::Curse)
+            .filter(|c| game.cards[*c].class.ty() == CardType::Curse)
             .count() as i32;
         game.action_queue.push_top(GainStatusAction {
             status: Status::Strength,
@@ -3,6 +3,7 @@ use crate::{
     game::Game,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct EnchiridionAction();
 
 impl Action for EnchiridionAction {
@@ -5,6 +5,7 @@ use crate::{
     relic::RelicClass,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct EndOfTurnDiscardAction();
 
 impl Action for EndOfTurnDiscardAction {
@@ -14,13 +15,13 @@ impl Action for EndOfTurnDiscardAction {
         }
         let mut indexes_to_exhaust = Vec::new();
         for (i, c) in game.hand.iter().enumerate() {
-            if c.borrow().is_ethereal() {
+            if game.cards[c].is_ethereal() {
                 indexes_to_exhaust.push(i);
             }
         }
         for i in indexes_to_exhaust.into_iter().rev() {
             game.action_queue
-                .push_top(ExhaustCardAction(game.hand.remove(i)));
+                .push_top(ExhaustCardAction(game.hand.remove(i, &game.cards)));
         }
     }
 }
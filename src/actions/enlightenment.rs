@@ -1,14 +1,15 @@
 use crate::{action::Action, cards::CardCost, game::Game};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct EnlightenmentAction {
     pub for_combat: bool,
 }
 
 impl Action for EnlightenmentAction {
     fn run(&self, game: &mut Game) {
-        for c in &game.hand {
-            let mut c = c.borrow_mut();
-            match &mut c.cost {
+        let hand = game.hand.to_vec();
+        for c in hand {
+            game.update_card(c, |c| match &mut c.cost {
                 CardCost::Cost {
                     base_cost,
                     temporary_cost,
@@ -23,7 +24,7 @@ impl Action for EnlightenmentAction {
                     }
                 }
                 CardCost::X | CardCost::Zero => {}
-            }
+            });
         }
     }
 }
@@ -0,0 +1,26 @@
+use crate::{
+    action::Action,
+    actions::escape_monster::EscapeMonsterAction,
+    game::{CreatureRef, Game},
+};
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct EscapeAllMonstersAction;
+
+impl Action for EscapeAllMonstersAction {
+    fn run(&self, game: &mut Game) {
+        for (mi, m) in game.monsters.iter().enumerate().rev() {
+            if !m.creature.is_actionable() {
+                continue;
+            }
+            game.action_queue
+                .push_top(EscapeMonsterAction(CreatureRef::monster(mi)));
+        }
+    }
+}
+
+impl std::fmt::Debug for EscapeAllMonstersAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "escape all monsters")
+    }
+}
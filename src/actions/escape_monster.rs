@@ -4,11 +4,14 @@ use crate::{
     game::{CreatureRef, Game},
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct EscapeMonsterAction(pub CreatureRef);
 
 impl Action for EscapeMonsterAction {
     fn run(&self, game: &mut Game) {
-        game.get_creature_mut(self.0).state = CreatureState::Escaped;
+        let c = game.get_creature_mut(self.0);
+        c.state = CreatureState::Escaped;
+        c.rehash();
     }
 }
 
@@ -4,13 +4,14 @@ use crate::{
         block::BlockAction, damage_all_monsters::DamageAllMonstersAction, draw::DrawAction,
         gain_energy::GainEnergyAction, place_card_in_hand::PlaceCardInHandAction,
     },
-    card::CardRef,
+    card::{Card, CardRef},
     cards::{CardClass, random_red_in_combat},
     game::Game,
     relic::RelicClass,
     status::Status,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ExhaustCardAction(pub CardRef);
 
 impl Action for ExhaustCardAction {
@@ -33,8 +34,8 @@ impl Action for ExhaustCardAction {
         }
 
         {
-            let mut c = self.0.borrow_mut();
-            c.clear_temporary();
+            game.update_card(self.0, Card::clear_temporary);
+            let c = &game.cards[self.0];
             match c.class {
                 CardClass::Sentinel => {
                     game.action_queue
@@ -48,12 +49,12 @@ impl Action for ExhaustCardAction {
             }
         }
 
-        game.exhaust_pile.push(self.0.clone());
+        game.exhaust_pile.push(self.0, &game.cards);
     }
 }
 
 impl std::fmt::Debug for ExhaustCardAction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "exhaust {:?}", self.0.borrow())
+        write!(f, "exhaust {:?}", self.0)
     }
 }
@@ -1,10 +1,11 @@
 use crate::{action::Action, actions::exhaust_card::ExhaustCardAction, game::Game};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ExhaustHandAction();
 
 impl Action for ExhaustHandAction {
     fn run(&self, game: &mut Game) {
-        while let Some(c) = game.hand.pop() {
+        while let Some(c) = game.hand.pop(&game.cards) {
             game.action_queue.push_top(ExhaustCardAction(c));
         }
     }
@@ -2,19 +2,20 @@ use crate::{
     action::Action, actions::exhaust_card::ExhaustCardAction, cards::CardType, game::Game,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ExhaustNonAttackInHandAction();
 
 impl Action for ExhaustNonAttackInHandAction {
     fn run(&self, game: &mut Game) {
         let mut indexes_to_exhaust = Vec::new();
         for (i, c) in game.hand.iter().enumerate() {
-            if c.borrow().class.ty() != CardType::Attack {
+            if game.cards[c].class.ty() != CardType::Attack {
                 indexes_to_exhaust.push(i);
             }
         }
         while let Some(i) = indexes_to_exhaust.pop() {
             game.action_queue
-                .push_top(ExhaustCardAction(game.hand.remove(i)));
+                .push_top(ExhaustCardAction(game.hand.remove(i, &game.cards)));
         }
     }
 }
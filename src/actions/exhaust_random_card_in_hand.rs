@@ -2,6 +2,7 @@ use rand::RngExt;
 
 use crate::{action::Action, actions::exhaust_card::ExhaustCardAction, game::Game};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ExhaustRandomCardInHandAction();
 
 impl Action for ExhaustRandomCardInHandAction {
@@ -10,9 +11,10 @@ impl Action for ExhaustRandomCardInHandAction {
             0 => {}
             1 => game
                 .action_queue
-                .push_top(ExhaustCardAction(game.hand.pop().unwrap())),
+                .push_top(ExhaustCardAction(game.hand.pop(&game.cards).unwrap())),
             _ => game.action_queue.push_top(ExhaustCardAction(
-                game.hand.remove(game.rng.random_range(0..game.hand.len())),
+                game.hand
+                    .remove(game.rng.random_range(0..game.hand.len()), &game.cards),
             )),
         }
     }
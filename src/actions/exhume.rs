@@ -7,6 +7,7 @@ use crate::{
     step::Step,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ExhumeAction();
 
 enum Count {
@@ -22,7 +23,7 @@ impl Action for ExhumeAction {
         }
         let mut count = Count::Zero;
         for (i, c) in game.exhaust_pile.iter().enumerate() {
-            if c.borrow().class != CardClass::Exhume {
+            if game.cards[c].class != CardClass::Exhume {
                 match count {
                     Count::Zero => count = Count::One(i),
                     Count::One(_) => {
@@ -35,7 +36,9 @@ impl Action for ExhumeAction {
         }
         match count {
             Count::Zero => {}
-            Count::One(i) => game.hand.push(game.exhaust_pile.remove(i)),
+            Count::One(i) => game
+                .hand
+                .push(game.exhaust_pile.remove(i, &game.cards), &game.cards),
             Count::Many => game.state.push_state(ChooseExhumeGameState),
         }
     }
@@ -47,18 +50,17 @@ impl std::fmt::Debug for ExhumeAction {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct ChooseExhumeGameState;
 
 impl GameState for ChooseExhumeGameState {
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
-        let mut moves = Steps::default();
+    fn valid_steps(&self, game: &Game, moves: &mut Steps) -> bool {
         for (i, c) in game.exhaust_pile.iter().enumerate() {
-            if c.borrow().class != CardClass::Exhume {
+            if game.cards[c].class != CardClass::Exhume {
                 moves.push(ExhumeStep { exhaust_index: i });
             }
         }
-        Some(moves)
+        true
     }
 }
 
@@ -74,15 +76,14 @@ impl Step for ExhumeStep {
 
     fn run(&self, game: &mut Game) {
         game.action_queue.push_top(PlaceCardInHandAction(
-            game.exhaust_pile.remove(self.exhaust_index),
+            game.exhaust_pile.remove(self.exhaust_index, &game.cards),
         ));
     }
 
     fn description(&self, game: &Game) -> String {
         format!(
             "exhaust {} ({:?})",
-            self.exhaust_index,
-            game.exhaust_pile[self.exhaust_index].borrow()
+            self.exhaust_index, &game.cards[game.exhaust_pile[self.exhaust_index]]
         )
     }
 }
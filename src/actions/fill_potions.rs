@@ -4,6 +4,7 @@ use crate::{
     potion::{Potion, random_potion_weighted},
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct FillPotionsAction();
 
 impl Action for FillPotionsAction {
@@ -1,21 +1,24 @@
 use crate::{action::Action, card::CardRef, cards::CardCost, game::Game};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ForethoughtAction(pub CardRef);
 
 impl Action for ForethoughtAction {
     fn run(&self, game: &mut Game) {
-        if let CardCost::Cost {
-            free_to_play_once, ..
-        } = &mut self.0.borrow_mut().cost
-        {
-            *free_to_play_once = true;
-        }
-        game.draw_pile.push_bottom(self.0.clone());
+        game.update_card(self.0, |c| {
+            if let CardCost::Cost {
+                free_to_play_once, ..
+            } = &mut c.cost
+            {
+                *free_to_play_once = true;
+            }
+        });
+        game.draw_pile.push_bottom(self.0, &game.cards);
     }
 }
 
 impl std::fmt::Debug for ForethoughtAction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "forethought {:?}", self.0.borrow())
+        write!(f, "forethought {:?}", self.0)
     }
 }
@@ -1,5 +1,6 @@
 use crate::{action::Action, game::Game};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct GainEnergyAction(pub i32);
 
 impl Action for GainEnergyAction {
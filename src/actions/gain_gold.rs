@@ -5,6 +5,7 @@ use crate::{
     relic::RelicClass,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct GainGoldAction(pub i32);
 
 impl Action for GainGoldAction {
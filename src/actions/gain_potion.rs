@@ -1,5 +1,6 @@
 use crate::{action::Action, game::Game, potion::Potion, relic::RelicClass};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct GainPotionAction(pub Potion);
 
 impl Action for GainPotionAction {
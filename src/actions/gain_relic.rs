@@ -4,6 +4,7 @@ use crate::{
     relic::{RelicClass, new_relic},
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct GainRelicAction(pub RelicClass);
 
 impl Action for GainRelicAction {
@@ -6,6 +6,7 @@ use crate::{
     status::{Status, StatusType},
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct GainStatusAction {
     pub status: Status,
     pub amount: i32,
@@ -18,7 +19,7 @@ impl Action for GainStatusAction {
             assert_eq!(self.amount, 1);
         }
         if self.status.ty() != StatusType::Amount {
-            assert!(self.amount > 0);
+            assert!(self.amount > 0 || self.status.can_be_zero());
         }
         let c = game.get_creature(self.target);
         if self.status == Status::NoDraw && c.has_status(Status::NoDraw) {
@@ -63,11 +64,14 @@ impl Action for GainStatusAction {
         if self.status.bounded_999() {
             v = v.clamp(-999, 999);
         }
-        if v == 0 {
+        if v == 0 && !self.status.can_be_zero() {
             creature.remove_status(self.status);
         } else {
             creature.set_status(self.status, v);
         }
+        if self.status == Status::Malleable {
+            creature.gain_malleable(self.amount);
+        }
         if self.status.is_debuff(self.amount)
             && !self.target.is_player()
             && self.status != Status::GainStrength
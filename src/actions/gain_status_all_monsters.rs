@@ -5,6 +5,7 @@ use crate::{
     status::Status,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct GainStatusAllMonstersAction {
     pub status: Status,
     pub amount: i32,
@@ -4,6 +4,7 @@ use crate::{
     relic::RelicClass,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct HealAction {
     pub target: CreatureRef,
     pub amount: i32,
@@ -0,0 +1,28 @@
+use crate::{
+    action::Action,
+    actions::heal::HealAction,
+    game::{CreatureRef, Game},
+};
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct HealAllMonstersAction(pub i32);
+
+impl Action for HealAllMonstersAction {
+    fn run(&self, game: &mut Game) {
+        for (mi, m) in game.monsters.iter().enumerate().rev() {
+            if !m.creature.is_actionable() {
+                continue;
+            }
+            game.action_queue.push_top(HealAction {
+                target: CreatureRef::monster(mi),
+                amount: self.0,
+            });
+        }
+    }
+}
+
+impl std::fmt::Debug for HealAllMonstersAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "heal all monsters {}", self.0)
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{action::Action, actions::draw::DrawAction, cards::CardType, game::Game};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ImpatienceAction(pub i32);
 
 impl Action for ImpatienceAction {
@@ -7,7 +8,7 @@ impl Action for ImpatienceAction {
         if game
             .hand
             .iter()
-            .all(|c| c.borrow().class.ty() != CardType::Attack)
+            .all(|c| game.cards[c].class.ty() != CardType::Attack)
         {
             game.action_queue.push_top(DrawAction(self.0));
         }
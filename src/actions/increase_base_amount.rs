@@ -1,45 +1,31 @@
 use crate::{action::Action, card::CardRef, game::Game};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct IncreaseBaseAmountAction {
     pub card_id: u32,
     pub amount: i32,
     pub master: bool,
 }
 
-fn maybe_increase_base_amount(c: &CardRef, card_id: u32, amount: i32, done: &mut bool) {
-    if *done {
-        return;
-    }
-    let mut c = c.borrow_mut();
-    if c.id == card_id {
-        c.base_increase += amount;
-        *done = true;
-    }
-}
-
 impl Action for IncreaseBaseAmountAction {
     fn run(&self, g: &mut Game) {
-        if self.master {
-            let mut done = false;
-            for c in &g.master_deck {
-                maybe_increase_base_amount(c, self.card_id, self.amount, &mut done);
-            }
-        }
-        let mut done = false;
-        for c in &g.discard_pile {
-            maybe_increase_base_amount(c, self.card_id, self.amount, &mut done);
-        }
-        for c in &g.exhaust_pile {
-            maybe_increase_base_amount(c, self.card_id, self.amount, &mut done);
-        }
-        for c in g.draw_pile.get_all() {
-            maybe_increase_base_amount(c, self.card_id, self.amount, &mut done);
-        }
-        for c in &g.hand {
-            maybe_increase_base_amount(c, self.card_id, self.amount, &mut done);
-        }
-        if let Some(c) = &g.cur_card {
-            maybe_increase_base_amount(c, self.card_id, self.amount, &mut done);
+        let is_card = |c: &&CardRef| g.cards[*c].id == self.card_id;
+        let master = if self.master {
+            g.master_deck.iter().find(is_card).copied()
+        } else {
+            None
+        };
+        let combat = g
+            .discard_pile
+            .iter()
+            .chain(&g.exhaust_pile)
+            .chain(g.draw_pile.get_all())
+            .chain(&g.hand)
+            .chain(&g.cur_card)
+            .find(is_card)
+            .copied();
+        for c in master.into_iter().chain(combat) {
+            g.update_card(c, |c| c.base_increase += self.amount);
         }
     }
 }
@@ -1,5 +1,6 @@
 use crate::{action::Action, game::Game};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct IncreasePotionSlotsAction(pub i32);
 
 impl Action for IncreasePotionSlotsAction {
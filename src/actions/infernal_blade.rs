@@ -5,15 +5,18 @@ use crate::{
     game::Game,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct InfernalBladeAction();
 
 impl Action for InfernalBladeAction {
     fn run(&self, game: &mut Game) {
         let class = random_red_attack_in_combat(&mut game.rng);
         let c = game.new_card(class);
-        if let CardCost::Cost { temporary_cost, .. } = &mut c.borrow_mut().cost {
-            *temporary_cost = Some(0);
-        }
+        game.update_card(c, |c| {
+            if let CardCost::Cost { temporary_cost, .. } = &mut c.cost {
+                *temporary_cost = Some(0);
+            }
+        });
         game.action_queue.push_top(PlaceCardInHandAction(c));
     }
 }
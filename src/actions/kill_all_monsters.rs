@@ -0,0 +1,23 @@
+use crate::{
+    action::Action,
+    game::{CreatureRef, Game},
+};
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct KillAllMonstersAction;
+
+impl Action for KillAllMonstersAction {
+    fn run(&self, game: &mut Game) {
+        for mi in 0..game.monsters.len() {
+            if game.monsters[mi].creature.takes_turns() {
+                game.kill_monster(CreatureRef::monster(mi));
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for KillAllMonstersAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "kill all monsters")
+    }
+}
@@ -1,9 +1,10 @@
-use crate::{action::Action, card::CardRef, cards::CardCost, game::Game, rng::rand_slice};
+use crate::{action::Action, card::Card, cards::CardCost, game::Game, rng::rand_slice};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MadnessAction();
 
-fn temp_cost_is_zero(c: &CardRef) -> bool {
-    if let CardCost::Cost { temporary_cost, .. } = c.borrow().cost {
+fn temp_cost_is_zero(c: &Card) -> bool {
+    if let CardCost::Cost { temporary_cost, .. } = c.cost {
         temporary_cost == Some(0)
     } else {
         unreachable!();
@@ -16,11 +17,11 @@ impl Action for MadnessAction {
         let mut not_free = vec![];
         let mut not_free_and_not_temp_free = vec![];
         for c in &g.hand {
-            if let CardCost::Cost { base_cost, .. } = c.borrow().cost
+            if let CardCost::Cost { base_cost, .. } = g.cards[c].cost
                 && base_cost != 0
             {
                 not_free.push(c);
-                if !temp_cost_is_zero(c) {
+                if !temp_cost_is_zero(&g.cards[c]) {
                     not_free_and_not_temp_free.push(c);
                 }
             }
@@ -33,7 +34,7 @@ impl Action for MadnessAction {
         } else {
             rand_slice(&mut g.rng, &not_free_and_not_temp_free)
         };
-        match &mut c.borrow_mut().cost {
+        g.update_card(*c, |c| match &mut c.cost {
             CardCost::Cost {
                 base_cost,
                 temporary_cost,
@@ -43,7 +44,7 @@ impl Action for MadnessAction {
                 *temporary_cost = None;
             }
             _ => unreachable!(),
-        }
+        });
     }
 }
 
@@ -1,5 +1,6 @@
 use crate::{action::Action, actions::play_top_card::PlayTopCardAction, game::Game};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MayhemAction();
 
 impl Action for MayhemAction {
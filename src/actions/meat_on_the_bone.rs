@@ -4,6 +4,7 @@ use crate::{
     game::{CreatureRef, Game},
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct MeatOnTheBoneAction(pub i32);
 
 impl Action for MeatOnTheBoneAction {
@@ -2,6 +2,7 @@ pub mod add_card_class_to_master_deck;
 pub mod add_card_to_master_deck;
 pub mod armaments;
 pub mod block;
+pub mod block_other_monsters;
 pub mod block_per_non_attack_in_hand;
 pub mod block_random_monster;
 pub mod choose_card_in_discard_to_place_on_top_of_draw;
@@ -35,6 +36,7 @@ pub mod duvu;
 pub mod enchiridion;
 pub mod end_of_turn_discard;
 pub mod enlightenment;
+pub mod escape_all_monsters;
 pub mod escape_monster;
 pub mod escape_player;
 pub mod exhaust_card;
@@ -53,12 +55,14 @@ pub mod gain_relic;
 pub mod gain_status;
 pub mod gain_status_all_monsters;
 pub mod heal;
+pub mod heal_all_monsters;
 pub mod impatience;
 pub mod increase_base_amount;
 pub mod increase_draw_per_turn;
 pub mod increase_max_hp;
 pub mod increase_potion_slots;
 pub mod infernal_blade;
+pub mod kill_all_monsters;
 pub mod madness;
 pub mod magnetism;
 pub mod mayhem;
@@ -78,6 +82,7 @@ pub mod remove_all_debuffs;
 pub mod remove_relic;
 pub mod remove_status;
 pub mod removed_card_from_master_deck;
+pub mod revive_monster;
 pub mod rob;
 pub mod set_energy;
 pub mod set_hp_all_monsters;
@@ -87,6 +92,9 @@ pub mod sling_of_courage;
 pub mod split_monster;
 pub mod spot_weakness;
 pub mod start_of_turn_energy;
+pub mod stasis;
+pub mod suicide;
+pub mod summon_monster;
 pub mod try_remove_card_from_master_deck;
 pub mod upgrade;
 pub mod upgrade_all;
@@ -4,6 +4,7 @@ use crate::{
     game::{CombatType, Game},
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct PantographAction();
 
 impl Action for PantographAction {
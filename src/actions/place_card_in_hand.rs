@@ -1,20 +1,20 @@
 use crate::{action::Action, actions::discard_card::DiscardCardAction, card::CardRef, game::Game};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct PlaceCardInHandAction(pub CardRef);
 
 impl Action for PlaceCardInHandAction {
     fn run(&self, game: &mut Game) {
         if game.hand_is_full() {
-            game.action_queue
-                .push_top(DiscardCardAction(self.0.clone()));
+            game.action_queue.push_top(DiscardCardAction(self.0));
         } else {
-            game.hand.push(self.0.clone());
+            game.hand.push(self.0, &game.cards);
         }
     }
 }
 
 impl std::fmt::Debug for PlaceCardInHandAction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "place card in hand {:?}", self.0.borrow())
+        write!(f, "place card in hand {:?}", self.0)
     }
 }
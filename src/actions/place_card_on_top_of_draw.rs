@@ -1,15 +1,16 @@
 use crate::{action::Action, card::CardRef, game::Game};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct PlaceCardOnTopOfDrawAction(pub CardRef);
 
 impl Action for PlaceCardOnTopOfDrawAction {
     fn run(&self, game: &mut Game) {
-        game.draw_pile.push_top(self.0.clone());
+        game.draw_pile.push_top(self.0, &game.cards);
     }
 }
 
 impl std::fmt::Debug for PlaceCardOnTopOfDrawAction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "place card on top of draw {:?}", self.0.borrow())
+        write!(f, "place card on top of draw {:?}", self.0)
     }
 }
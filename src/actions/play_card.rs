@@ -1,3 +1,5 @@
+use std::hash::{Hash, Hasher};
+
 use rand::RngExt;
 
 use crate::{
@@ -9,31 +11,70 @@ use crate::{
     card::{Card, CardPlayInfo, CardRef},
     cards::{CardClass, CardCost, CardType},
     game::{CreatureRef, Game},
+    queue::ActionId,
     relic::RelicClass,
     status::Status,
 };
 
 use super::discard_card::DiscardCardAction;
 
+#[derive(Clone)]
 pub struct PlayCardAction {
     pub card: CardRef,
+    // class of `card`, for triggers that don't have the game
+    pub class: CardClass,
     pub target: Option<CreatureRef>,
     pub is_duplicated: bool,
     pub cost: i32,
     pub free: bool,
     pub force_exhaust: bool,
+    // what queued this play, while it waits in the card queue
+    pub parent: Option<ActionId>,
     _priv: (),
 }
 
+// compared without `parent`, which is only there for tracing
+impl PartialEq for PlayCardAction {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for PlayCardAction {}
+
+impl Hash for PlayCardAction {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
 impl PlayCardAction {
-    pub fn duplicated(play: &PlayCardAction) -> Self {
+    fn key(
+        &self,
+    ) -> (
+        CardRef,
+        CardClass,
+        Option<CreatureRef>,
+        (bool, i32, bool, bool),
+    ) {
+        (
+            self.card,
+            self.class,
+            self.target,
+            (self.is_duplicated, self.cost, self.free, self.force_exhaust),
+        )
+    }
+
+    pub fn duplicated(play: &PlayCardAction, parent: Option<ActionId>) -> Self {
         Self {
             is_duplicated: true,
             free: true,
             force_exhaust: false,
-            card: play.card.clone(),
+            card: play.card,
+            class: play.class,
             target: play.target,
             cost: play.cost,
+            parent,
             _priv: (),
         }
     }
@@ -53,7 +94,7 @@ impl PlayCardAction {
         }
     }
     pub fn new(card: CardRef, target: Option<CreatureRef>, game: &Game) -> Self {
-        let cost = match card.borrow().cost {
+        let cost = match game.cards[card].cost {
             CardCost::Zero => 0,
             CardCost::X => game.energy,
             CardCost::Cost {
@@ -61,7 +102,7 @@ impl PlayCardAction {
                 temporary_cost,
                 free_to_play_once,
             } => {
-                if free_to_play_once || PlayCardAction::is_corruption(game, &card.borrow()) {
+                if free_to_play_once || PlayCardAction::is_corruption(game, &game.cards[card]) {
                     0
                 } else {
                     temporary_cost.unwrap_or(base_cost)
@@ -69,12 +110,14 @@ impl PlayCardAction {
             }
         };
         Self {
-            card: card.clone(),
+            card,
+            class: game.cards[card].class,
             target,
             is_duplicated: false,
             cost,
             free: false,
             force_exhaust: false,
+            parent: None,
             _priv: (),
         }
     }
@@ -82,14 +125,14 @@ impl PlayCardAction {
 
 impl Action for PlayCardAction {
     fn run(&self, game: &mut Game) {
-        let c = self.card.borrow();
+        let c = game.cards[self.card].clone();
         assert!(game.can_play_card(self));
 
         game.num_cards_played_this_turn += 1;
-        game.cur_card = Some(self.card.clone());
+        game.cur_card = Some(self.card);
 
         for h in &game.hand {
-            if h.borrow().class == CardClass::Pain {
+            if game.cards[h].class == CardClass::Pain {
                 game.action_queue
                     .push_top(DamageAction::lose_hp(1, CreatureRef::player()));
             }
@@ -118,7 +161,6 @@ impl Action for PlayCardAction {
         } else {
             CardDestination::Discard
         };
-        drop(c);
 
         if matches!(dest, CardDestination::Exhaust)
             && game.has_relic(RelicClass::StrangeSpoon)
@@ -127,12 +169,14 @@ impl Action for PlayCardAction {
             dest = CardDestination::Discard;
         }
 
-        if let CardCost::Cost {
-            free_to_play_once, ..
-        } = &mut self.card.borrow_mut().cost
-        {
-            *free_to_play_once = false
-        }
+        game.update_card(self.card, |c| {
+            if let CardCost::Cost {
+                free_to_play_once, ..
+            } = &mut c.cost
+            {
+                *free_to_play_once = false
+            }
+        });
 
         game.player.trigger_statuses_on_card_played(
             &mut game.action_queue,
@@ -148,6 +192,23 @@ impl Action for PlayCardAction {
                     target: m,
                 });
             }
+            if c.class.ty() == CardType::Power
+                && let Some(amount) = game.get_creature(m).get_status(Status::Curiosity)
+            {
+                game.action_queue.push_top(GainStatusAction {
+                    status: Status::Strength,
+                    amount,
+                    target: m,
+                });
+            }
+            // after the card's own damage
+            if game.get_creature(m).has_status(Status::Slow) {
+                game.action_queue.push_bot(GainStatusAction {
+                    status: Status::Slow,
+                    amount: 1,
+                    target: m,
+                });
+            }
             if let Some(amount) = game.get_creature(m).get_status(Status::SharpHide) {
                 game.action_queue.push_top(DamageAction::thorns_no_rupture(
                     amount,
@@ -161,19 +222,15 @@ impl Action for PlayCardAction {
         game.action_queue.push_bot(ClearCurCardAction());
         match dest {
             CardDestination::None => {}
-            CardDestination::Discard => game
-                .action_queue
-                .push_bot(DiscardCardAction(self.card.clone())),
-            CardDestination::Exhaust => game
-                .action_queue
-                .push_bot(ExhaustCardAction(self.card.clone())),
+            CardDestination::Discard => game.action_queue.push_bot(DiscardCardAction(self.card)),
+            CardDestination::Exhaust => game.action_queue.push_bot(ExhaustCardAction(self.card)),
         }
     }
 }
 
 impl std::fmt::Debug for PlayCardAction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "play {:?}", self.card.borrow())?;
+        write!(f, "play {:?}", self.card)?;
         if let Some(t) = self.target {
             write!(f, " on {t:?}")?
         }
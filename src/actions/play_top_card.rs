@@ -1,9 +1,13 @@
 use crate::{
     action::Action,
-    actions::{play_card::PlayCardAction, shuffle_discard_into_draw::ShuffleDiscardIntoDrawAction},
+    actions::{
+        discard_card::DiscardCardAction, exhaust_card::ExhaustCardAction,
+        play_card::PlayCardAction, shuffle_discard_into_draw::ShuffleDiscardIntoDrawAction,
+    },
     game::Game,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct PlayTopCardAction {
     pub force_exhaust: bool,
 }
@@ -18,14 +22,23 @@ impl Action for PlayTopCardAction {
             g.action_queue.push_top(ShuffleDiscardIntoDrawAction());
             return;
         }
-        let c = g.draw_pile.pop(&mut g.rng);
-        let target = if c.borrow().has_target() {
+        let c = g.draw_pile.pop(&mut g.rng, &g.cards);
+        // e.g. the awakened one is between lives
+        if g.cards[c].has_target() && g.no_monsters_targetable() {
+            if self.force_exhaust {
+                g.action_queue.push_top(ExhaustCardAction(c));
+            } else {
+                g.action_queue.push_top(DiscardCardAction(c));
+            }
+            return;
+        }
+        let target = if g.cards[c].has_target() {
             Some(g.get_random_alive_monster())
         } else {
             None
         };
         g.card_queue
-            .push(PlayCardAction::new_free(c, target, g, self.force_exhaust));
+            .push_back(PlayCardAction::new_free(c, target, g, self.force_exhaust));
     }
 }
 
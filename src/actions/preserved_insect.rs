@@ -3,6 +3,7 @@ use crate::{
     game::{CombatType, Game},
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct PreservedInsectAction();
 
 impl Action for PreservedInsectAction {
@@ -10,6 +11,7 @@ impl Action for PreservedInsectAction {
         if matches!(game.in_combat, CombatType::Elite) {
             for m in &mut game.monsters {
                 m.creature.cur_hp = (m.creature.max_hp as f32 * 0.75) as i32;
+                m.creature.rehash();
             }
         }
     }
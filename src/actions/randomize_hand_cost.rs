@@ -2,20 +2,28 @@ use rand::RngExt;
 
 use crate::{action::Action, cards::CardCost, game::Game};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct RandomizeHandCostAction();
 
 impl Action for RandomizeHandCostAction {
     fn run(&self, game: &mut Game) {
-        for c in &game.hand {
-            if let CardCost::Cost {
-                base_cost,
-                temporary_cost,
-                ..
-            } = &mut c.borrow_mut().cost
-            {
-                *base_cost = game.rng.random_range(0..=3);
-                *temporary_cost = None;
+        let hand = game.hand.to_vec();
+        for c in hand {
+            if !matches!(game.cards[c].cost, CardCost::Cost { .. }) {
+                continue;
             }
+            let cost = game.rng.random_range(0..=3);
+            game.update_card(c, |c| {
+                if let CardCost::Cost {
+                    base_cost,
+                    temporary_cost,
+                    ..
+                } = &mut c.cost
+                {
+                    *base_cost = cost;
+                    *temporary_cost = None;
+                }
+            });
         }
     }
 }
@@ -4,6 +4,7 @@ use crate::{
     status::{Status, StatusType},
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ReduceStatusAction {
     pub status: Status,
     pub amount: i32,
@@ -21,6 +22,10 @@ impl Action for ReduceStatusAction {
             assert!(s >= 0);
             if s == 0 {
                 c.remove_status(self.status);
+                if !self.target.is_player() {
+                    game.monsters[self.target.monster_index()]
+                        .update_behavior(|b, _| b.on_lose_status(self.target, self.status));
+                }
             } else {
                 c.set_status(self.status, s);
             }
@@ -4,23 +4,27 @@ use crate::{
     game::{CreatureRef, Game},
 };
 
-pub struct RemoveAllDebuffsAction();
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct RemoveAllDebuffsAction(pub CreatureRef);
 
 impl Action for RemoveAllDebuffsAction {
     fn run(&self, game: &mut Game) {
-        for (&status, &amount) in game.player.all_statuses() {
-            if status.is_debuff(amount) {
-                game.action_queue.push_top(RemoveStatusAction {
-                    status,
-                    target: CreatureRef::player(),
-                });
-            }
+        let debuffs = game
+            .get_creature(self.0)
+            .all_statuses()
+            .filter(|&(status, amount)| status.is_debuff(amount))
+            .collect::<Vec<_>>();
+        for (status, _) in debuffs {
+            game.action_queue.push_top(RemoveStatusAction {
+                status,
+                target: self.0,
+            });
         }
     }
 }
 
 impl std::fmt::Debug for RemoveAllDebuffsAction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "remove all debuffs")
+        write!(f, "remove all debuffs {:?}", self.0)
     }
 }
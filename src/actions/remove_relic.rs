@@ -1,5 +1,6 @@
 use crate::{action::Action, game::Game, relic::RelicClass};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct RemoveRelicAction(pub RelicClass);
 
 impl Action for RemoveRelicAction {
@@ -4,6 +4,7 @@ use crate::{
     status::Status,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct RemoveStatusAction {
     pub status: Status,
     pub target: CreatureRef,
@@ -11,8 +12,15 @@ pub struct RemoveStatusAction {
 
 impl Action for RemoveStatusAction {
     fn run(&self, game: &mut Game) {
-        game.get_creature_mut(self.target)
-            .remove_status(self.status);
+        let c = game.get_creature_mut(self.target);
+        if !c.has_status(self.status) {
+            return;
+        }
+        c.remove_status(self.status);
+        if !self.target.is_player() {
+            game.monsters[self.target.monster_index()]
+                .update_behavior(|b, _| b.on_lose_status(self.target, self.status));
+        }
     }
 }
 
@@ -2,6 +2,7 @@ use crate::{
     action::Action, actions::decrease_max_hp::DecreaseMaxHPAction, cards::CardClass, game::Game,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct RemovedCardFromMasterDeckAction(pub CardClass);
 
 impl Action for RemovedCardFromMasterDeckAction {
@@ -0,0 +1,48 @@
+use crate::{
+    action::Action,
+    creature::CreatureState,
+    game::{CreatureRef, Game},
+};
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ReviveMonsterAction {
+    target: CreatureRef,
+    full_hp: bool,
+}
+
+impl ReviveMonsterAction {
+    pub fn half_hp(target: CreatureRef) -> Self {
+        Self {
+            target,
+            full_hp: false,
+        }
+    }
+    pub fn full_hp(target: CreatureRef) -> Self {
+        Self {
+            target,
+            full_hp: true,
+        }
+    }
+}
+
+impl Action for ReviveMonsterAction {
+    fn run(&self, game: &mut Game) {
+        let c = game.get_creature_mut(self.target);
+        if c.state != CreatureState::Reviving {
+            return;
+        }
+        c.state = CreatureState::Alive;
+        c.cur_hp = if self.full_hp { c.max_hp } else { c.max_hp / 2 };
+        c.rehash();
+    }
+}
+
+impl std::fmt::Debug for ReviveMonsterAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "revive {:?}", self.target)?;
+        if self.full_hp {
+            write!(f, " (full hp)")?;
+        }
+        Ok(())
+    }
+}
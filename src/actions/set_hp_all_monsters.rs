@@ -1,11 +1,13 @@
 use crate::{action::Action, game::Game};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct SetHPAllMonstersAction(pub i32);
 
 impl Action for SetHPAllMonstersAction {
     fn run(&self, game: &mut Game) {
         for m in &mut game.monsters {
             m.creature.cur_hp = 1;
+            m.creature.rehash();
         }
     }
 }
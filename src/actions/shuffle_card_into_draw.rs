@@ -4,6 +4,7 @@ use crate::{
     game::Game,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ShuffleCardIntoDrawAction {
     pub class: CardClass,
     pub is_free: bool,
@@ -12,12 +13,15 @@ pub struct ShuffleCardIntoDrawAction {
 impl Action for ShuffleCardIntoDrawAction {
     fn run(&self, game: &mut Game) {
         let card = game.new_card(self.class);
-        if self.is_free
-            && let CardCost::Cost { base_cost, .. } = &mut card.borrow_mut().cost
-        {
-            *base_cost = 0
+        if self.is_free {
+            game.update_card(card, |c| {
+                if let CardCost::Cost { base_cost, .. } = &mut c.cost {
+                    *base_cost = 0
+                }
+            });
         }
-        game.draw_pile.shuffle_in_one(card, &mut game.rng);
+        game.draw_pile
+            .shuffle_in_one(card, &mut game.rng, &game.cards);
     }
 }
 
@@ -1,13 +1,13 @@
 use crate::{action::Action, game::Game};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ShuffleDiscardIntoDrawAction();
 
 impl Action for ShuffleDiscardIntoDrawAction {
     fn run(&self, game: &mut Game) {
-        let mut discard = Vec::new();
-        std::mem::swap(&mut game.discard_pile, &mut discard);
-        for c in discard {
-            game.draw_pile.push_top(c);
+        let discard = std::mem::take(&mut game.discard_pile);
+        for &c in &discard {
+            game.draw_pile.push_top(c, &game.cards);
         }
         game.draw_pile.shuffle_all(&mut game.rng);
 
@@ -10,7 +10,7 @@ use crate::{
 };
 
 #[allow(dead_code)]
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SplitMonsterType {
     TestAttack,
     SlimeAcidL,
@@ -18,6 +18,7 @@ pub enum SplitMonsterType {
     SlimeBoss,
 }
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct SplitMonsterAction {
     pub monster: CreatureRef,
     pub ty: SplitMonsterType,
@@ -26,7 +27,9 @@ pub struct SplitMonsterAction {
 impl Action for SplitMonsterAction {
     fn run(&self, game: &mut Game) {
         let hp = game.get_creature(self.monster).cur_hp;
-        game.get_creature_mut(self.monster).state = CreatureState::Dead;
+        let c = game.get_creature_mut(self.monster);
+        c.state = CreatureState::Dead;
+        c.rehash();
         let turn_pos = game
             .monster_turn_queue_all
             .iter()
@@ -50,12 +53,31 @@ impl Action for SplitMonsterAction {
                 Monster::new_with_hp(AttackMonster::new(10), hp),
             ],
         };
-        for m in new_monsters {
-            game.monsters.push(m);
+        game.monster_turn_queue_all.remove(turn_pos);
+        // the first half takes the parent's slot and the second the first
+        // dead or escaped one, so splitting doesn't grow the fight
+        let parent = self.monster.monster_index();
+        let [first, second] = new_monsters;
+        game.monsters[parent] = first;
+        let spare = game
+            .monsters
+            .iter()
+            .enumerate()
+            .position(|(i, m)| i != parent && !m.creature.takes_turns());
+        let second_slot = match spare {
+            Some(slot) => {
+                game.monsters[slot] = second;
+                slot
+            }
+            None => {
+                game.monsters.push(second);
+                game.monsters.len() - 1
+            }
+        };
+        for slot in [parent, second_slot] {
             game.monster_turn_queue_all
-                .insert(turn_pos, CreatureRef::monster(game.monsters.len() - 1));
+                .insert(turn_pos, CreatureRef::monster(slot));
         }
-        game.monster_turn_queue_all.retain(|&c| c != self.monster);
     }
 }
 
@@ -78,6 +100,7 @@ mod tests {
         status::Status,
     };
 
+    #[derive(PartialEq, Eq, Hash, Clone)]
     struct TestSplitMonster;
 
     impl MonsterBehavior for TestSplitMonster {
@@ -111,12 +134,12 @@ mod tests {
         g.step_test(EndTurnStep);
         assert_eq!(g.player.cur_hp, 48);
         g.step_test(EndTurnStep);
-        assert!(!g.monsters[0].creature.is_actionable());
+        assert_eq!(g.monsters.len(), 2);
+        assert_eq!(g.monsters[0].creature.cur_hp, 12);
         assert_eq!(g.monsters[1].creature.cur_hp, 12);
-        assert_eq!(g.monsters[2].creature.cur_hp, 12);
         assert_eq!(g.player.cur_hp, 28);
         g.step_test(EndTurnStep);
-        assert_eq!(g.monsters.len(), 3);
+        assert_eq!(g.monsters.len(), 2);
         assert_eq!(g.player.cur_hp, 8);
     }
 
@@ -5,6 +5,7 @@ use crate::{
     relic::RelicClass,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct StartOfTurnEnergyAction();
 
 impl Action for StartOfTurnEnergyAction {
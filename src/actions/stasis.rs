@@ -0,0 +1,113 @@
+use crate::{
+    action::Action,
+    cards::CardRarity,
+    game::{CreatureRef, Game},
+    rng::rand_slice,
+};
+
+// Takes a card from the draw pile (the discard pile if it's empty), preferring
+// rarer cards, and holds it until the monster dies. A monster holds at most
+// one card.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct StasisAction(pub CreatureRef);
+
+impl Action for StasisAction {
+    fn run(&self, game: &mut Game) {
+        assert!(game.monsters[self.0.monster_index()].held_card.is_none());
+        let from_draw = !game.draw_pile.is_empty();
+        let classes = if from_draw {
+            game.draw_pile
+                .get_all()
+                .into_iter()
+                .map(|c| game.cards[c].class)
+                .collect::<Vec<_>>()
+        } else {
+            game.discard_pile
+                .iter()
+                .map(|c| game.cards[c].class)
+                .collect()
+        };
+        if classes.is_empty() {
+            return;
+        }
+        let mut candidates = vec![];
+        for rarity in [CardRarity::Rare, CardRarity::Uncommon, CardRarity::Common] {
+            candidates = (0..classes.len())
+                .filter(|&i| classes[i].rarity() == rarity)
+                .collect();
+            if !candidates.is_empty() {
+                break;
+            }
+        }
+        if candidates.is_empty() {
+            candidates = (0..classes.len()).collect();
+        }
+        let i = rand_slice(&mut game.rng, &candidates);
+        let c = if from_draw {
+            game.draw_pile.take(i, &game.cards)
+        } else {
+            game.discard_pile.remove(i, &game.cards)
+        };
+        game.monsters[self.0.monster_index()].held_card = Some(c);
+    }
+}
+
+impl std::fmt::Debug for StasisAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "stasis {:?}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        actions::damage::DamageAction, cards::CardClass, game::GameBuilder,
+        monsters::test::NoopMonster,
+    };
+
+    #[test]
+    fn test_prefers_rare() {
+        let mut g = GameBuilder::default()
+            .build_combat_with_monsters(NoopMonster::new(), NoopMonster::new());
+        g.draw_pile.clear();
+        g.add_cards_to_draw_pile(CardClass::Strike, 3);
+        g.add_card_to_draw_pile(CardClass::Inflame);
+        g.add_card_to_draw_pile(CardClass::Offering);
+        g.run_action(StasisAction(CreatureRef::monster(0)));
+        let held = g.monsters[0].held_card.unwrap();
+        assert_eq!(g.cards[held].class, CardClass::Offering);
+        assert_eq!(g.draw_pile.len(), 4);
+
+        g.run_action(StasisAction(CreatureRef::monster(1)));
+        let held2 = g.monsters[1].held_card.unwrap();
+        assert_eq!(g.cards[held2].class, CardClass::Inflame);
+        assert_eq!(g.monsters[0].held_card, Some(held));
+    }
+
+    #[test]
+    fn test_from_discard() {
+        let mut g = GameBuilder::default().build_combat_with_monster(NoopMonster::new());
+        g.draw_pile.clear();
+        g.discard_pile.clear();
+        g.add_cards_to_discard_pile(CardClass::Strike, 2);
+        g.run_action(StasisAction(CreatureRef::monster(0)));
+        assert_eq!(g.discard_pile.len(), 1);
+        assert!(g.monsters[0].held_card.is_some());
+    }
+
+    #[test]
+    fn test_returned_on_death() {
+        let mut g = GameBuilder::default()
+            .build_combat_with_monsters(NoopMonster::new(), NoopMonster::new());
+        g.add_card_to_draw_pile(CardClass::Strike);
+        g.run_action(StasisAction(CreatureRef::monster(0)));
+        let held = g.monsters[0].held_card.unwrap();
+        g.run_action(DamageAction::thorns_no_rupture(
+            999,
+            CreatureRef::monster(0),
+        ));
+        assert!(g.monsters[0].held_card.is_none());
+        assert_eq!(*g.hand, [held]);
+    }
+}
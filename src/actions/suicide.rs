@@ -0,0 +1,25 @@
+use crate::{
+    action::Action,
+    actions::damage::DamageType,
+    game::{CreatureRef, Game},
+};
+
+// loses all of its remaining hp, so it still counts as dying
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SuicideAction(pub CreatureRef);
+
+impl Action for SuicideAction {
+    fn run(&self, game: &mut Game) {
+        let c = game.get_creature(self.0);
+        if !c.is_actionable() {
+            return;
+        }
+        game.damage(self.0, c.cur_hp, DamageType::HPLoss);
+    }
+}
+
+impl std::fmt::Debug for SuicideAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "suicide {:?}", self.0)
+    }
+}
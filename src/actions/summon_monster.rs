@@ -0,0 +1,145 @@
+use crate::{
+    action::Action,
+    game::{CreatureRef, Game, Rand},
+    monster::{Monster, MonsterBehavior},
+    monsters::{
+        bronze_orb::BronzeOrb, gremlin_pool, snake_dagger::SnakeDagger, torch_head::TorchHead,
+    },
+    rng::remove_random,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SummonMonsterType {
+    Gremlin,
+    // supports the given automaton
+    BronzeOrb(CreatureRef),
+    TorchHead,
+    SnakeDagger,
+}
+
+impl SummonMonsterType {
+    fn behavior(&self, rng: &mut Rand) -> Box<dyn MonsterBehavior> {
+        match *self {
+            SummonMonsterType::Gremlin => remove_random(rng, &mut gremlin_pool()),
+            SummonMonsterType::BronzeOrb(automaton) => Box::new(BronzeOrb::new(automaton)),
+            SummonMonsterType::TorchHead => Box::new(TorchHead::new()),
+            SummonMonsterType::SnakeDagger => Box::new(SnakeDagger::new()),
+        }
+    }
+}
+
+fn summon(game: &mut Game, ty: &SummonMonsterType, slot: Option<usize>) {
+    let m = Monster::new_boxed(ty.behavior(&mut game.rng), &mut game.rng);
+    let slot = match slot {
+        Some(slot) => {
+            game.monsters[slot] = m;
+            slot
+        }
+        None => {
+            game.monsters.push(m);
+            game.monsters.len() - 1
+        }
+    };
+    let this = CreatureRef::monster(slot);
+    // take turns in slot order
+    let pos = game
+        .monster_turn_queue_all
+        .iter()
+        .position(|c| c.monster_index() > slot)
+        .unwrap_or(game.monster_turn_queue_all.len());
+    game.monster_turn_queue_all.insert(pos, this);
+    game.monsters[slot]
+        .behavior
+        .pre_combat(&mut game.action_queue, this, &mut game.rng);
+}
+
+// The summoned monster takes the slot of the first dead or escaped monster, or
+// a new one, and acts from the next monster turn on.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SummonMonsterAction(pub SummonMonsterType);
+
+impl Action for SummonMonsterAction {
+    fn run(&self, game: &mut Game) {
+        let slot = game.monsters.iter().position(|m| !m.creature.takes_turns());
+        summon(game, &self.0, slot);
+    }
+}
+
+// Summons into the first of `slots` that is free, for monsters that keep
+// their summons in fixed places. Nothing is summoned if they are all taken.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SummonMonsterInSlotAction {
+    pub ty: SummonMonsterType,
+    pub slots: Vec<usize>,
+}
+
+impl Action for SummonMonsterInSlotAction {
+    fn run(&self, game: &mut Game) {
+        if let Some(&slot) = self
+            .slots
+            .iter()
+            .find(|&&slot| !game.monsters[slot].creature.takes_turns())
+        {
+            summon(game, &self.ty, Some(slot));
+        }
+    }
+}
+
+impl std::fmt::Debug for SummonMonsterAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "summon {:?}", self.0)
+    }
+}
+
+impl std::fmt::Debug for SummonMonsterInSlotAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "summon {:?} in one of slots {:?}", self.ty, self.slots)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        actions::damage::DamageAction, combat::EndTurnStep, game::GameBuilder,
+        monsters::test::NoopMonster,
+    };
+
+    #[test]
+    fn test_summon() {
+        let mut g = GameBuilder::default().build_combat_with_monster(NoopMonster::new());
+        g.run_action(SummonMonsterAction(SummonMonsterType::Gremlin));
+        assert_eq!(g.monsters.len(), 2);
+        assert!(g.monsters[1].creature.is_actionable());
+        assert!(g.monsters[1].behavior.name().contains("gremlin"));
+        assert_eq!(
+            g.monster_turn_queue_all,
+            vec![CreatureRef::monster(0), CreatureRef::monster(1)]
+        );
+        g.step_test(EndTurnStep);
+        assert!(g.monsters[1].creature.is_actionable());
+    }
+
+    #[test]
+    fn test_summon_into_free_slot() {
+        let mut g = GameBuilder::default()
+            .build_combat_with_monsters(NoopMonster::new(), NoopMonster::new());
+        g.run_action(SummonMonsterAction(SummonMonsterType::TorchHead));
+        g.run_action(DamageAction::thorns_no_rupture(
+            999,
+            CreatureRef::monster(0),
+        ));
+        g.run_action(SummonMonsterAction(SummonMonsterType::TorchHead));
+        assert_eq!(g.monsters.len(), 3);
+        assert_eq!(g.monsters[0].behavior.name(), "torch head");
+        assert!(g.monsters[0].creature.is_actionable());
+        assert_eq!(
+            g.get_actionable_monsters_in_order(),
+            vec![
+                CreatureRef::monster(0),
+                CreatureRef::monster(1),
+                CreatureRef::monster(2)
+            ]
+        );
+    }
+}
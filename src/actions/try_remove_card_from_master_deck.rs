@@ -3,6 +3,7 @@ use crate::{
     cards::CardClass, game::Game,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TryRemoveCardFromMasterDeckAction(pub CardClass);
 
 impl Action for TryRemoveCardFromMasterDeckAction {
@@ -10,7 +11,7 @@ impl Action for TryRemoveCardFromMasterDeckAction {
         if let Some(i) = game
             .master_deck
             .iter()
-            .position(|c| c.borrow().class == self.0)
+            .position(|c| game.cards[c].class == self.0)
         {
             game.master_deck.remove(i);
             game.action_queue
@@ -1,17 +1,21 @@
-use crate::{action::Action, card::CardRef, game::Game};
+use crate::{
+    action::Action,
+    card::{Card, CardRef},
+    game::Game,
+};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct UpgradeAction(pub CardRef);
 
 impl Action for UpgradeAction {
-    fn run(&self, _: &mut Game) {
-        let mut c = self.0.borrow_mut();
-        assert!(c.can_upgrade());
-        c.upgrade();
+    fn run(&self, game: &mut Game) {
+        assert!(game.cards[self.0].can_upgrade());
+        game.update_card(self.0, Card::upgrade);
     }
 }
 
 impl std::fmt::Debug for UpgradeAction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "upgrade {:?}", self.0.borrow())
+        write!(f, "upgrade {:?}", self.0)
     }
 }
@@ -1,22 +1,29 @@
-use crate::{action::Action, card::CardRef, game::Game};
+use crate::{
+    action::Action,
+    card::{Card, CardRef},
+    game::Game,
+};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct UpgradeAllAction();
 
-fn upgrade<'a, T: Iterator<Item = &'a CardRef>>(cards: T) {
+fn upgrade(game: &mut Game, cards: Vec<CardRef>) {
     for c in cards {
-        let mut c = c.borrow_mut();
-        if c.can_upgrade() {
-            c.upgrade();
+        if game.cards[c].can_upgrade() {
+            game.update_card(c, Card::upgrade);
         }
     }
 }
 
 impl Action for UpgradeAllAction {
     fn run(&self, game: &mut Game) {
-        upgrade(game.hand.iter());
-        upgrade(game.discard_pile.iter());
-        upgrade(game.draw_pile.get_all().into_iter());
-        upgrade(game.exhaust_pile.iter());
+        upgrade(game, game.hand.to_vec());
+        upgrade(game, game.discard_pile.to_vec());
+        upgrade(
+            game,
+            game.draw_pile.get_all().into_iter().copied().collect(),
+        );
+        upgrade(game, game.exhaust_pile.to_vec());
     }
 }
 
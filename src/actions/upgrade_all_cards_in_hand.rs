@@ -1,13 +1,14 @@
-use crate::{action::Action, game::Game};
+use crate::{action::Action, card::Card, game::Game};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct UpgradeAllCardsInHandAction();
 
 impl Action for UpgradeAllCardsInHandAction {
     fn run(&self, game: &mut Game) {
-        for c in &game.hand {
-            let mut c = c.borrow_mut();
-            if c.can_upgrade() {
-                c.upgrade();
+        let hand = game.hand.to_vec();
+        for c in hand {
+            if game.cards[c].can_upgrade() {
+                game.update_card(c, Card::upgrade);
             }
         }
     }
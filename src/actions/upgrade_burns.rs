@@ -1,20 +1,19 @@
-use crate::{action::Action, card::CardRef, cards::CardClass, game::Game};
+use crate::{action::Action, cards::CardClass, game::Game};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct UpgradeBurnsAction();
 
 impl Action for UpgradeBurnsAction {
     fn run(&self, game: &mut Game) {
-        let upgrade_burn = |c: &CardRef| {
-            let mut c = c.borrow_mut();
-            if c.class == CardClass::Burn {
-                c.class = CardClass::BurnPlus;
-            }
-        };
-        for c in &game.discard_pile {
-            upgrade_burn(c);
-        }
-        for c in game.draw_pile.get_all() {
-            upgrade_burn(c);
+        let burns = game
+            .discard_pile
+            .iter()
+            .chain(game.draw_pile.get_all())
+            .copied()
+            .filter(|&c| game.cards[c].class == CardClass::Burn)
+            .collect::<Vec<_>>();
+        for c in burns {
+            game.update_card(c, |c| c.class = CardClass::BurnPlus);
         }
     }
 }
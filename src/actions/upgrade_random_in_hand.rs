@@ -1,7 +1,8 @@
 use rand::RngExt;
 
-use crate::{action::Action, game::Game};
+use crate::{action::Action, card::Card, game::Game};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct UpgradeRandomInHandAction();
 
 impl Action for UpgradeRandomInHandAction {
@@ -9,16 +10,16 @@ impl Action for UpgradeRandomInHandAction {
         let cards = game
             .hand
             .iter()
-            .filter(|c| c.borrow().can_upgrade())
+            .filter(|c| game.cards[*c].can_upgrade())
             .collect::<Vec<_>>();
         match cards.len() {
             0 => {}
             1 => {
-                cards[0].borrow_mut().upgrade();
+                game.update_card(*cards[0], Card::upgrade);
             }
             _ => {
                 let i = game.rng.random_range(0..cards.len());
-                cards[i].borrow_mut().upgrade();
+                game.update_card(*cards[i], Card::upgrade);
             }
         }
     }
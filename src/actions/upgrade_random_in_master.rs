@@ -1,7 +1,8 @@
 use rand::RngExt;
 
-use crate::{action::Action, cards::CardType, game::Game, rng::rand_slice};
+use crate::{action::Action, card::Card, cards::CardType, game::Game, rng::rand_slice};
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct UpgradeTwoRandomInMasterAction(pub Option<CardType>);
 
 impl Action for UpgradeTwoRandomInMasterAction {
@@ -10,7 +11,7 @@ impl Action for UpgradeTwoRandomInMasterAction {
             .master_deck
             .iter()
             .filter(|c| {
-                let c = c.borrow();
+                let c = &game.cards[*c];
                 if let Some(ty) = self.0
                     && ty != c.class.ty()
                 {
@@ -18,6 +19,7 @@ impl Action for UpgradeTwoRandomInMasterAction {
                 }
                 c.can_upgrade()
             })
+            .copied()
             .collect::<Vec<_>>();
         if cards.len() > 2 {
             let i1 = game.rng.random_range(0..cards.len());
@@ -27,7 +29,7 @@ impl Action for UpgradeTwoRandomInMasterAction {
             cards = vec![c1, c2];
         }
         for c in cards {
-            c.borrow_mut().upgrade();
+            game.update_card(c, Card::upgrade);
         }
     }
 }
@@ -38,6 +40,7 @@ impl std::fmt::Debug for UpgradeTwoRandomInMasterAction {
     }
 }
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct UpgradeRandomInMasterAction;
 
 impl Action for UpgradeRandomInMasterAction {
@@ -45,10 +48,11 @@ impl Action for UpgradeRandomInMasterAction {
         let cards = game
             .master_deck
             .iter()
-            .filter(|c| c.borrow().can_upgrade())
+            .filter(|c| game.cards[*c].can_upgrade())
             .collect::<Vec<_>>();
         if !cards.is_empty() {
-            rand_slice(&mut game.rng, &cards).borrow_mut().upgrade();
+            let c = rand_slice(&mut game.rng, &cards);
+            game.update_card(*c, Card::upgrade);
         }
     }
 }
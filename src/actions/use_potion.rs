@@ -6,6 +6,7 @@ use crate::{
     relic::RelicClass,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct UsePotionAction {
     pub potion: Potion,
     pub target: Option<CreatureRef>,
@@ -4,6 +4,7 @@ use crate::{
     game::{CreatureRef, Game},
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct VampireAction(pub Vec<CreatureRef>);
 
 impl Action for VampireAction {
@@ -27,3 +28,25 @@ impl std::fmt::Debug for VampireAction {
         write!(f, "vampire")
     }
 }
+
+// heals a monster by what its last attack took off the player
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MonsterVampireAction(pub CreatureRef);
+
+impl Action for MonsterVampireAction {
+    fn run(&self, game: &mut Game) {
+        let heal = game.player.last_damage_taken;
+        if heal != 0 {
+            game.action_queue.push_top(HealAction {
+                target: self.0,
+                amount: heal,
+            });
+        }
+    }
+}
+
+impl std::fmt::Debug for MonsterVampireAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "monster vampire {:?}", self.0)
+    }
+}
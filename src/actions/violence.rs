@@ -3,6 +3,7 @@ use crate::{
     game::Game, rng::rand_slice,
 };
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ViolenceAction(pub i32);
 
 impl Action for ViolenceAction {
@@ -14,14 +15,14 @@ impl Action for ViolenceAction {
                 .get_all()
                 .into_iter()
                 .enumerate()
-                .filter(|(_, c)| c.borrow().class.ty() == CardType::Attack)
+                .filter(|(_, c)| game.cards[*c].class.ty() == CardType::Attack)
                 .map(|(i, _)| i)
                 .collect::<Vec<_>>();
             if attack_indexes.is_empty() {
                 break;
             }
             let i = rand_slice(&mut game.rng, &attack_indexes);
-            cards.push(game.draw_pile.take(i));
+            cards.push(game.draw_pile.take(i, &game.cards));
         }
         while let Some(c) = cards.pop() {
             game.action_queue.push_top(PlaceCardInHandAction(c));
@@ -0,0 +1,165 @@
+use rand::RngExt;
+
+use crate::{
+    encoding,
+    game::{CombatType, CreatureRef, GameStatus, Rand},
+    gym::{Env, Info},
+};
+
+// Scores a state from the player's point of view, higher is better.
+pub trait Evaluator {
+    fn evaluate(&self, env: &Env) -> f32;
+}
+
+// Picks a structured action (see `encoding`) for the current state.
+pub trait Agent {
+    fn choose(&mut self, env: &Env) -> usize;
+}
+
+pub fn legal_actions(env: &Env) -> Vec<usize> {
+    encoding::action_mask(env.game())
+        .iter()
+        .enumerate()
+        .filter(|(_, m)| **m)
+        .map(|(i, _)| i)
+        .collect()
+}
+
+// Tries every legal action on a fork of the episode and returns the actions
+// with the evaluation of the state each one leads to.
+pub fn evaluate_actions<E: Evaluator + ?Sized>(env: &Env, evaluator: &E) -> Vec<(usize, f32)> {
+    legal_actions(env)
+        .into_iter()
+        .map(|a| {
+            let mut fork = env.fork();
+            fork.step(a);
+            (a, evaluator.evaluate(&fork))
+        })
+        .collect()
+}
+
+// first action with the best evaluation, so ties go to ending the turn
+pub fn best_action(evals: &[(usize, f32)]) -> usize {
+    let mut best = evals[0];
+    for &e in &evals[1..] {
+        if e.1 > best.1 {
+            best = e;
+        }
+    }
+    best.0
+}
+
+// Hand written evaluation used as the greedy baseline: hp that won't be lost
+// to the monsters' next attacks minus the hp the monsters have left.
+pub struct HeuristicEvaluator;
+
+impl Evaluator for HeuristicEvaluator {
+    fn evaluate(&self, env: &Env) -> f32 {
+        let game = env.game();
+        if matches!(game.status, GameStatus::Defeat) {
+            return -1e6;
+        }
+        if game.in_combat == CombatType::None {
+            return 1e6 + game.player.cur_hp as f32;
+        }
+        let mut incoming = 0;
+        let mut monster_hp = 0;
+        for c in game.get_actionable_monsters_in_order() {
+            let m = &game.monsters[c.monster_index()];
+            monster_hp += m.creature.cur_hp + m.creature.block;
+            let mut intent = m.behavior.get_intent();
+            intent.modify_damage(CreatureRef::monster(c.monster_index()), game);
+            if let Some((d, n)) = intent.damage() {
+                incoming += d * n;
+            }
+        }
+        let effective_hp = game.player.cur_hp + game.player.block.min(incoming);
+        (effective_hp - monster_hp) as f32
+    }
+}
+
+// One step lookahead with an evaluator.
+pub struct GreedyAgent<E: Evaluator> {
+    pub evaluator: E,
+}
+
+impl<E: Evaluator> Agent for GreedyAgent<E> {
+    fn choose(&mut self, env: &Env) -> usize {
+        best_action(&evaluate_actions(env, &self.evaluator))
+    }
+}
+
+pub fn greedy_baseline() -> GreedyAgent<HeuristicEvaluator> {
+    GreedyAgent {
+        evaluator: HeuristicEvaluator,
+    }
+}
+
+pub struct RandomAgent {
+    pub rng: Rand,
+}
+
+impl Agent for RandomAgent {
+    fn choose(&mut self, env: &Env) -> usize {
+        let actions = legal_actions(env);
+        actions[self.rng.random_range(0..actions.len())]
+    }
+}
+
+// Plays until the episode is done, or gives up after `max_steps` decisions.
+pub fn run_episode<A: Agent + ?Sized>(env: &mut Env, agent: &mut A, max_steps: usize) -> Info {
+    for _ in 0..max_steps {
+        if env.is_done() {
+            break;
+        }
+        let a = agent.choose(env);
+        env.step(a);
+    }
+    env.info()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::{
+        gym::{EnvConfig, Episode},
+        monsters::Combat,
+    };
+
+    fn combat_config(combat: Combat) -> EnvConfig {
+        EnvConfig {
+            episode: Episode::starting_deck_combat(combat),
+            rewards: Default::default(),
+            limits: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_greedy_beats_jaw_worm() {
+        let mut env = Env::default();
+        env.reset(0, combat_config(Combat::JawWorm));
+        let info = run_episode(&mut env, &mut greedy_baseline(), 200);
+        assert!(!info.defeat);
+        assert_eq!(info.combats_won, 1);
+    }
+
+    #[test]
+    fn test_random_agent_finishes() {
+        let mut env = Env::default();
+        env.reset(1, combat_config(Combat::Cultist));
+        let mut agent = RandomAgent {
+            rng: Rand::seed_from_u64(1),
+        };
+        let info = run_episode(&mut env, &mut agent, 1000);
+        assert!(env.is_done());
+        assert!(info.defeat || info.combats_won == 1);
+    }
+
+    #[test]
+    fn test_best_action_ties() {
+        assert_eq!(best_action(&[(0, 1.0), (3, 2.0), (5, 2.0)]), 3);
+        assert_eq!(best_action(&[(0, 2.0), (3, 2.0)]), 0);
+    }
+}
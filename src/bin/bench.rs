@@ -0,0 +1,99 @@
+use std::time::Instant;
+
+use rand::{RngExt, SeedableRng};
+use slay_i::{
+    game::{CombatType, Game, GameBuilder, Rand},
+    relic::RelicClass,
+    value::ACT1_COMBATS,
+};
+
+const USAGE: &str = "usage: bench [--combats N] [--seed N] [--threads N]";
+
+// a combat stuck for this many steps is abandoned
+const MAX_STEPS: usize = 2000;
+
+struct Args {
+    combats: usize,
+    seed: u64,
+    threads: usize,
+}
+
+fn parse_args() -> Args {
+    let mut args_out = Args {
+        combats: 2000,
+        seed: 0,
+        threads: 1,
+    };
+    let mut args = std::env::args().skip(1);
+    while let Some(flag) = args.next() {
+        let value = args.next().expect(USAGE);
+        match flag.as_str() {
+            "--combats" => args_out.combats = value.parse().expect(USAGE),
+            "--seed" => args_out.seed = value.parse().expect(USAGE),
+            "--threads" => args_out.threads = value.parse().expect(USAGE),
+            _ => panic!("unknown argument \"{flag}\"\n{USAGE}"),
+        }
+    }
+    args_out
+}
+
+fn build_game(seed: u64) -> Game {
+    GameBuilder::default()
+        .seed(seed)
+        .ironclad_starting_deck()
+        .add_relic(RelicClass::BurningBlood)
+        .build_fixed_combat(ACT1_COMBATS[seed as usize % ACT1_COMBATS.len()])
+}
+
+// Plays a combat with uniformly random steps, returns the number of steps.
+fn play(game: &mut Game, rng: &mut Rand) -> usize {
+    for steps in 0..MAX_STEPS {
+        if game.in_combat == CombatType::None || game.status.is_over() {
+            return steps;
+        }
+        let n = game.num_valid_steps();
+        game.step(rng.random_range(0..n));
+    }
+    MAX_STEPS
+}
+
+// Plays every combat whose index is `thread` modulo `threads`, returns the
+// number of steps and wins.
+fn play_share(seed: u64, combats: usize, thread: usize, threads: usize) -> (usize, usize) {
+    let mut rng = Rand::seed_from_u64(seed.wrapping_add(thread as u64));
+    let mut steps = 0;
+    let mut wins = 0;
+    for i in (thread..combats).step_by(threads) {
+        let mut game = build_game(seed.wrapping_add(i as u64));
+        steps += play(&mut game, &mut rng);
+        // combats cut off at MAX_STEPS are still going and don't count
+        if game.in_combat == CombatType::None && !game.status.is_over() {
+            wins += 1;
+        }
+    }
+    (steps, wins)
+}
+
+fn main() {
+    let Args {
+        combats,
+        seed,
+        threads,
+    } = parse_args();
+    let start = Instant::now();
+    let (steps, wins) = std::thread::scope(|s| {
+        let handles = (0..threads)
+            .map(|t| s.spawn(move || play_share(seed, combats, t, threads)))
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .map(|h| h.join().unwrap())
+            .fold((0, 0), |(a, b), (c, d)| (a + c, b + d))
+    });
+    let secs = start.elapsed().as_secs_f64();
+    println!(
+        "{combats} combats ({wins} won) and {steps} steps on {threads} threads in {secs:.3}s: {:.0} combats/s, {:.0} steps/s",
+        combats as f64 / secs,
+        steps as f64 / secs
+    );
+}
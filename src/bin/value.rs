@@ -0,0 +1,120 @@
+use slay_i::{
+    agent::greedy_baseline,
+    value::{ACT1_COMBATS, LinearValue, TrainConfig, evaluate, train, value_agent},
+};
+
+const USAGE: &str = "usage:
+  value train --out <weights> [--init <weights>] [--episodes N] [--seed N]
+              [--alpha X] [--lambda X] [--epsilon X]
+  value eval --weights <weights> [--episodes N] [--seed N]";
+
+// evaluation seeds start far away from the training seeds by default
+const EVAL_SEED: u64 = 1_000_000;
+
+struct Args(Vec<String>);
+
+impl Args {
+    fn take(&mut self, flag: &str) -> Option<String> {
+        let i = self.0.iter().position(|a| a == flag)?;
+        self.0.remove(i);
+        if i >= self.0.len() {
+            panic!("{flag} needs a value\n{USAGE}");
+        }
+        Some(self.0.remove(i))
+    }
+
+    fn parse<T: std::str::FromStr>(&mut self, flag: &str) -> Option<T> {
+        self.take(flag).map(|v| {
+            v.parse()
+                .unwrap_or_else(|_| panic!("invalid value \"{v}\" for {flag}"))
+        })
+    }
+
+    fn finish(self) {
+        if !self.0.is_empty() {
+            panic!("unknown arguments {:?}\n{USAGE}", self.0);
+        }
+    }
+}
+
+fn run_train(mut args: Args) {
+    let out = args.take("--out").expect(USAGE);
+    let mut model = match args.take("--init") {
+        Some(path) => LinearValue::load(path).expect("couldn't load weights"),
+        None => LinearValue::new(),
+    };
+    let mut config = TrainConfig::default();
+    if let Some(v) = args.parse("--episodes") {
+        config.episodes = v;
+    }
+    if let Some(v) = args.parse("--seed") {
+        config.seed = v;
+    }
+    if let Some(v) = args.parse("--alpha") {
+        config.alpha = v;
+    }
+    if let Some(v) = args.parse("--lambda") {
+        config.lambda = v;
+    }
+    if let Some(v) = args.parse("--epsilon") {
+        config.epsilon = v;
+    }
+    args.finish();
+
+    let mut recent = vec![];
+    train(&mut model, &config, |episode, ret| {
+        recent.push(ret);
+        if recent.len() == 100 || episode + 1 == config.episodes {
+            let mean = recent.iter().sum::<f32>() / recent.len() as f32;
+            println!("episode {}: mean return {mean:.3}", episode + 1);
+            recent.clear();
+        }
+    });
+    model.save(&out).expect("couldn't save weights");
+    println!("saved weights to {out}");
+}
+
+fn run_eval(mut args: Args) {
+    let weights = args.take("--weights").expect(USAGE);
+    let episodes = args.parse("--episodes").unwrap_or(100);
+    let seed = args.parse("--seed").unwrap_or(EVAL_SEED);
+    args.finish();
+
+    let model = LinearValue::load(weights).expect("couldn't load weights");
+    let max_steps = TrainConfig::default().max_steps;
+    let learned = evaluate(
+        &mut value_agent(model),
+        episodes,
+        seed,
+        &ACT1_COMBATS,
+        max_steps,
+    );
+    let baseline = evaluate(
+        &mut greedy_baseline(),
+        episodes,
+        seed,
+        &ACT1_COMBATS,
+        max_steps,
+    );
+    println!(
+        "value function: win rate {:.3}, mean return {:.3}",
+        learned.win_rate(),
+        learned.mean_return
+    );
+    println!(
+        "greedy baseline: win rate {:.3}, mean return {:.3}",
+        baseline.win_rate(),
+        baseline.mean_return
+    );
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let command = args.next().expect(USAGE);
+    let args = Args(args.collect());
+    match command.as_str() {
+        "train" => run_train(args),
+        "eval" => run_eval(args),
+        _ => panic!("unknown command \"{command}\"\n{USAGE}"),
+    }
+}
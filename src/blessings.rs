@@ -63,13 +63,11 @@ impl Blessing {
     }
 }
 
-#[derive(Debug)]
-
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ChooseBlessingGameState;
 
 impl GameState for ChooseBlessingGameState {
-    fn valid_steps(&self, _: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
+    fn valid_steps(&self, _: &Game, steps: &mut Steps) -> bool {
         steps.push(ChooseBlessingStep(Blessing::GainMaxHPSmall));
         steps.push(ChooseBlessingStep(Blessing::CommonRelic));
         steps.push(ChooseBlessingStep(Blessing::RemoveRelic));
@@ -77,7 +75,7 @@ impl GameState for ChooseBlessingGameState {
         steps.push(ChooseBlessingStep(Blessing::RemoveOne));
         steps.push(ChooseBlessingStep(Blessing::RandomUncommonColorless));
         steps.push(ChooseBlessingStep(Blessing::RandomPotion));
-        Some(steps)
+        true
     }
 }
 
@@ -8,17 +8,16 @@ use crate::{
     step::Step,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CampfireGameState;
 
 impl GameState for CampfireGameState {
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
+    fn valid_steps(&self, game: &Game, steps: &mut Steps) -> bool {
         if !game.has_relic(RelicClass::CoffeeDripper) {
             steps.push(CampfireRestStep);
         }
         if !game.has_relic(RelicClass::FusionHammer)
-            && game.master_deck.iter().any(|c| c.borrow().can_upgrade())
+            && game.master_deck.iter().any(|c| game.cards[c].can_upgrade())
         {
             steps.push(CampfireUpgradeStep);
         }
@@ -32,17 +31,17 @@ impl GameState for CampfireGameState {
             && game
                 .master_deck
                 .iter()
-                .any(|c| c.borrow().can_remove_from_master_deck())
+                .any(|c| game.cards[c].can_remove_from_master_deck())
         {
             steps.push(CampfireTokeStep);
         }
         if game.has_relic(RelicClass::Shovel) {
             steps.push(CampfireDigStep);
         }
-        if steps.steps.is_empty() {
+        if steps.is_empty() {
             steps.push(ContinueStep);
         }
-        Some(steps)
+        true
     }
 }
 
@@ -203,8 +202,8 @@ mod tests {
             .build_campfire();
         g.step_test(CampfireUpgradeStep);
         g.step_test(ChooseUpgradeMasterStep { master_index: 0 });
-        assert_eq!(g.master_deck[0].borrow().upgrade_count, 1);
-        assert_eq!(g.master_deck[1].borrow().upgrade_count, 0);
+        assert_eq!(g.cards[g.master_deck[0]].upgrade_count, 1);
+        assert_eq!(g.cards[g.master_deck[1]].upgrade_count, 0);
     }
 
     #[test]
@@ -276,7 +275,7 @@ mod tests {
             .add_relic(RelicClass::PeacePipe)
             .build_campfire();
         let c = g.new_card(CardClass::Bash);
-        c.borrow_mut().is_bottled = true;
+        g.update_card(c, |c| c.is_bottled = true);
         g.master_deck.push(c);
         assert_eq!(g.master_deck.len(), 5);
         g.step_test(CampfireTokeStep);
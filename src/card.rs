@@ -1,8 +1,10 @@
-use std::{cell::RefCell, rc::Rc};
+use std::ops::{Deref, Index};
 
 use crate::{
     cards::{CardClass, CardCost, CardType},
-    game::CreatureRef,
+    draw_pile::DrawPile,
+    game::{CreatureRef, Rand},
+    transposition,
 };
 
 #[derive(Clone)]
@@ -15,7 +17,7 @@ pub struct CardPlayInfo<'a> {
     pub cost: i32,
 }
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Card {
     pub class: CardClass,
     pub upgrade_count: i32,
@@ -184,8 +186,191 @@ impl std::fmt::Debug for Card {
     }
 }
 
-pub type CardRef = Rc<RefCell<Card>>;
-pub type CardPile = Vec<CardRef>;
+// Key of a card in the game's card arena. Unlike `Card::id`, which links
+// copies of the same master deck card, every card gets its own key.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CardRef(u32);
+
+impl std::fmt::Debug for CardRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "card #{}", self.0)
+    }
+}
+
+// Cards in a combat pile, with a hash of what they are that is kept up to
+// date as cards come and go. The hash is a sum over the cards, so it doesn't
+// depend on their order, and it's only changed through the methods here,
+// which is why they take the arena.
+#[derive(Default, Clone, Debug)]
+pub struct CardPile {
+    cards: Vec<CardRef>,
+    hash: u64,
+}
+
+impl CardPile {
+    pub fn push(&mut self, c: CardRef, arena: &CardArena) {
+        self.hash = self.hash.wrapping_add(arena.key(c));
+        self.cards.push(c);
+    }
+    pub fn insert(&mut self, i: usize, c: CardRef, arena: &CardArena) {
+        self.hash = self.hash.wrapping_add(arena.key(c));
+        self.cards.insert(i, c);
+    }
+    pub fn remove(&mut self, i: usize, arena: &CardArena) -> CardRef {
+        let c = self.cards.remove(i);
+        self.hash = self.hash.wrapping_sub(arena.key(c));
+        c
+    }
+    pub fn pop(&mut self, arena: &CardArena) -> Option<CardRef> {
+        let c = self.cards.pop()?;
+        self.hash = self.hash.wrapping_sub(arena.key(c));
+        Some(c)
+    }
+    pub fn clear(&mut self) {
+        self.cards.clear();
+        self.hash = 0;
+    }
+    pub fn hash(&self) -> u64 {
+        self.hash
+    }
+    // a card in the pile changed from `old` to `new`, see `CardArena::key`
+    pub fn rekey(&mut self, c: CardRef, old: u64, new: u64) {
+        for _ in self.cards.iter().filter(|&&d| d == c) {
+            self.hash = self.hash.wrapping_sub(old).wrapping_add(new);
+        }
+    }
+}
+
+impl Deref for CardPile {
+    type Target = [CardRef];
+    fn deref(&self) -> &[CardRef] {
+        &self.cards
+    }
+}
+
+impl<'a> IntoIterator for &'a CardPile {
+    type Item = &'a CardRef;
+    type IntoIter = std::slice::Iter<'a, CardRef>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.cards.iter()
+    }
+}
+
+// The draw pile, with a hash of which cards are in it kept up to date the
+// same way. How they are ordered is left to `Game::state_eq`.
+#[derive(Default, Clone)]
+pub struct CardDrawPile {
+    pile: DrawPile<CardRef>,
+    hash: u64,
+}
+
+impl CardDrawPile {
+    pub fn new(
+        has_frozen_eye: bool,
+        priority: Vec<CardRef>,
+        normal: Vec<CardRef>,
+        rng: &mut Rand,
+        arena: &CardArena,
+    ) -> Self {
+        let hash = priority
+            .iter()
+            .chain(&normal)
+            .fold(0u64, |h, &c| h.wrapping_add(arena.key(c)));
+        Self {
+            pile: DrawPile::new(has_frozen_eye, priority, normal, rng),
+            hash,
+        }
+    }
+    pub fn push_top(&mut self, c: CardRef, arena: &CardArena) {
+        self.hash = self.hash.wrapping_add(arena.key(c));
+        self.pile.push_top(c);
+    }
+    pub fn push_bottom(&mut self, c: CardRef, arena: &CardArena) {
+        self.hash = self.hash.wrapping_add(arena.key(c));
+        self.pile.push_bottom(c);
+    }
+    pub fn shuffle_in_one(&mut self, c: CardRef, rng: &mut Rand, arena: &CardArena) {
+        self.hash = self.hash.wrapping_add(arena.key(c));
+        self.pile.shuffle_in_one(c, rng);
+    }
+    pub fn shuffle_all(&mut self, rng: &mut Rand) {
+        self.pile.shuffle_all(rng);
+    }
+    pub fn take(&mut self, i: usize, arena: &CardArena) -> CardRef {
+        let c = self.pile.take(i);
+        self.hash = self.hash.wrapping_sub(arena.key(c));
+        c
+    }
+    pub fn pop(&mut self, rng: &mut Rand, arena: &CardArena) -> CardRef {
+        let c = self.pile.pop(rng);
+        self.hash = self.hash.wrapping_sub(arena.key(c));
+        c
+    }
+    pub fn clear(&mut self) {
+        self.pile.clear();
+        self.hash = 0;
+    }
+    pub fn install_order(&mut self, order: &[usize]) {
+        self.pile.install_order(order);
+    }
+    pub fn hash(&self) -> u64 {
+        self.hash
+    }
+    pub fn rekey(&mut self, c: CardRef, old: u64, new: u64) {
+        for _ in self.pile.get_all().into_iter().filter(|&&d| d == c) {
+            self.hash = self.hash.wrapping_sub(old).wrapping_add(new);
+        }
+    }
+}
+
+impl Deref for CardDrawPile {
+    type Target = DrawPile<CardRef>;
+    fn deref(&self) -> &DrawPile<CardRef> {
+        &self.pile
+    }
+}
+
+// Owns every card in the game. Piles, queued plays and choices refer to cards
+// by key. Cards are never removed; a whole run only creates a few thousand.
+#[derive(Default, Clone)]
+pub struct CardArena {
+    cards: Vec<Card>,
+}
+
+impl CardArena {
+    pub fn insert(&mut self, card: Card) -> CardRef {
+        self.cards.push(card);
+        CardRef(self.cards.len() as u32 - 1)
+    }
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+    // what a card in a pile adds to the pile's hash
+    pub fn key(&self, c: CardRef) -> u64 {
+        transposition::mix(transposition::key(&self[c]))
+    }
+    // only for `Game::update_card`, which keeps the pile hashes up to date
+    pub(crate) fn get_mut(&mut self, c: CardRef) -> &mut Card {
+        &mut self.cards[c.0 as usize]
+    }
+}
+
+impl Index<CardRef> for CardArena {
+    type Output = Card;
+    fn index(&self, c: CardRef) -> &Card {
+        &self.cards[c.0 as usize]
+    }
+}
+
+impl Index<&CardRef> for CardArena {
+    type Output = Card;
+    fn index(&self, c: &CardRef) -> &Card {
+        &self[*c]
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -200,7 +385,7 @@ mod tests {
         let mut g = GameBuilder::default().build_combat();
         for (init_temp, final_temp) in [(3, 2), (2, 1), (1, 0), (0, 0)] {
             let c = g.new_card(CardClass::BodySlam);
-            let mut c = c.borrow_mut();
+            let mut c = g.cards[c].clone();
             match &mut c.cost {
                 CardCost::Cost {
                     base_cost: _,
@@ -236,16 +421,16 @@ mod tests {
         let mut g = GameBuilder::default().build_combat();
 
         let c = g.new_card(CardClass::DarkEmbrace);
-        c.borrow_mut().set_cost(3, None);
-        g.hand.push(c);
+        g.update_card(c, |c| c.set_cost(3, None));
+        g.hand.push(c, &g.cards);
 
         let c = g.new_card(CardClass::DarkEmbrace);
-        c.borrow_mut().set_cost(0, None);
-        g.hand.push(c);
+        g.update_card(c, |c| c.set_cost(0, None));
+        g.hand.push(c, &g.cards);
 
         g.throw_potion(Potion::Forge, None);
 
-        assert_eq!(g.hand[0].borrow().get_base_cost(), 1);
-        assert_eq!(g.hand[1].borrow().get_base_cost(), 0);
+        assert_eq!(g.cards[g.hand[0]].get_base_cost(), 1);
+        assert_eq!(g.cards[g.hand[1]].get_base_cost(), 0);
     }
 }
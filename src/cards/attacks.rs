@@ -16,7 +16,7 @@ use crate::{
         shuffle_card_into_draw::ShuffleCardIntoDrawAction,
         vampire::VampireAction,
     },
-    card::{CardPlayInfo, CardRef},
+    card::{CardArena, CardPlayInfo, CardRef},
     cards::{CardClass, skills::push_block},
     game::{CreatureRef, Game},
     relic::RelicClass,
@@ -143,14 +143,14 @@ pub fn sword_boomerang_behavior(game: &mut Game, info: &CardPlayInfo) {
     }
 }
 
-fn count_strikes<'a, T: Iterator<Item = &'a CardRef>>(cards: T) -> i32 {
-    cards.filter(|c| c.borrow().class.is_strike()).count() as i32
+fn count_strikes<'a, T: Iterator<Item = &'a CardRef>>(arena: &CardArena, cards: T) -> i32 {
+    cards.filter(|c| arena[*c].class.is_strike()).count() as i32
 }
 
 pub fn perfected_strike_behavior(game: &mut Game, info: &CardPlayInfo) {
-    let num_strikes = count_strikes(game.hand.iter())
-        + count_strikes(game.discard_pile.iter())
-        + count_strikes(game.draw_pile.get_all().into_iter());
+    let num_strikes = count_strikes(&game.cards, game.hand.iter())
+        + count_strikes(&game.cards, game.discard_pile.iter())
+        + count_strikes(&game.cards, game.draw_pile.get_all().into_iter());
     let base = 6 + num_strikes * if info.upgraded { 3 } else { 2 };
     push_damage(game, info, base, base);
 }
@@ -491,7 +491,10 @@ mod tests {
         g.play_card(CardClass::WildStrike, Some(CreatureRef::monster(0)));
         assert_eq!(g.monsters[0].creature.cur_hp, hp0 - 12);
         assert_eq!(g.draw_pile.len(), 1);
-        assert_eq!(g.draw_pile.pop(&mut g.rng).borrow().class, CardClass::Wound);
+        assert_eq!(
+            g.cards[g.draw_pile.pop(&mut g.rng, &g.cards)].class,
+            CardClass::Wound
+        );
     }
 
     #[test]
@@ -518,11 +521,11 @@ mod tests {
         g.step_test(PlaceCardInDiscardOnTopOfDrawStep { discard_index: 1 });
         assert_eq!(g.draw_pile.len(), 2);
         assert_eq!(
-            g.draw_pile.pop(&mut g.rng).borrow().class,
+            g.cards[g.draw_pile.pop(&mut g.rng, &g.cards)].class,
             CardClass::Strike
         );
         assert_eq!(
-            g.draw_pile.pop(&mut g.rng).borrow().class,
+            g.cards[g.draw_pile.pop(&mut g.rng, &g.cards)].class,
             CardClass::Headbutt
         );
     }
@@ -640,33 +643,35 @@ mod tests {
         let mut g = GameBuilder::default().build_combat();
         g.play_card(CardClass::Anger, Some(CreatureRef::monster(0)));
         assert_eq!(g.discard_pile.len(), 2);
-        assert_eq!(g.discard_pile[0].borrow().class, CardClass::Anger);
-        assert_eq!(g.discard_pile[1].borrow().class, CardClass::Anger);
+        assert_eq!(g.cards[g.discard_pile[0]].class, CardClass::Anger);
+        assert_eq!(g.cards[g.discard_pile[1]].class, CardClass::Anger);
 
         let c = g.new_card(CardClass::Anger);
-        c.borrow_mut().cost = CardCost::Cost {
-            base_cost: 2,
-            temporary_cost: Some(1),
-            free_to_play_once: false,
-        };
-        g.hand.push(c);
+        g.update_card(c, |c| {
+            c.cost = CardCost::Cost {
+                base_cost: 2,
+                temporary_cost: Some(1),
+                free_to_play_once: false,
+            }
+        });
+        g.hand.push(c, &g.cards);
         g.step_test(PlayCardStep {
             hand_index: 0,
             target: Some(0),
         });
         assert_eq!(g.discard_pile.len(), 4);
-        assert_eq!(g.discard_pile[2].borrow().class, CardClass::Anger);
+        assert_eq!(g.cards[g.discard_pile[2]].class, CardClass::Anger);
         assert_matches!(
-            g.discard_pile[2].borrow().cost,
+            g.cards[g.discard_pile[2]].cost,
             CardCost::Cost {
                 base_cost: 2,
                 temporary_cost: None,
                 free_to_play_once: false
             }
         );
-        assert_eq!(g.discard_pile[3].borrow().class, CardClass::Anger);
+        assert_eq!(g.cards[g.discard_pile[3]].class, CardClass::Anger);
         assert_matches!(
-            g.discard_pile[3].borrow().cost,
+            g.cards[g.discard_pile[3]].cost,
             CardCost::Cost {
                 base_cost: 2,
                 temporary_cost: None,
@@ -702,7 +707,7 @@ mod tests {
                 .add_card(CardClass::SearingBlow)
                 .build_combat();
             for _ in 0..upgrade_count {
-                g.hand[0].borrow_mut().upgrade();
+                g.update_card(g.hand[0], |c| c.upgrade());
             }
             let hp = g.monsters[0].creature.cur_hp;
             g.step_test(PlayCardStep {
@@ -750,16 +755,16 @@ mod tests {
         });
         assert_eq!(g.monsters[0].creature.cur_hp, hp0 - 8);
 
-        let c = g.discard_pile.pop().unwrap();
-        g.hand.push(c);
+        let c = g.discard_pile.pop(&g.cards).unwrap();
+        g.hand.push(c, &g.cards);
         g.step_test(PlayCardStep {
             hand_index: 0,
             target: Some(0),
         });
         assert_eq!(g.monsters[0].creature.cur_hp, hp0 - 8 - 13);
 
-        let c = g.discard_pile.pop().unwrap();
-        g.hand.push(c);
+        let c = g.discard_pile.pop(&g.cards).unwrap();
+        g.hand.push(c, &g.cards);
         g.step_test(PlayCardStep {
             hand_index: 0,
             target: Some(0),
@@ -780,16 +785,16 @@ mod tests {
         });
         assert_eq!(g.monsters[0].creature.cur_hp, hp0 - 8);
 
-        let c = g.discard_pile.pop().unwrap();
-        g.hand.push(c);
+        let c = g.discard_pile.pop(&g.cards).unwrap();
+        g.hand.push(c, &g.cards);
         g.step_test(PlayCardStep {
             hand_index: 0,
             target: Some(0),
         });
         assert_eq!(g.monsters[0].creature.cur_hp, hp0 - 8 - 16);
 
-        let c = g.discard_pile.pop().unwrap();
-        g.hand.push(c);
+        let c = g.discard_pile.pop(&g.cards).unwrap();
+        g.hand.push(c, &g.cards);
         g.step_test(PlayCardStep {
             hand_index: 0,
             target: Some(0),
@@ -813,17 +818,17 @@ mod tests {
         });
         assert_eq!(g.monsters[0].creature.cur_hp, hp0 - 8);
 
-        let c = g.discard_pile.pop().unwrap();
-        c.borrow_mut().upgrade();
-        g.hand.push(c);
+        let c = g.discard_pile.pop(&g.cards).unwrap();
+        g.update_card(c, |c| c.upgrade());
+        g.hand.push(c, &g.cards);
         g.step_test(PlayCardStep {
             hand_index: 1,
             target: Some(0),
         });
         assert_eq!(g.monsters[0].creature.cur_hp, hp0 - 8 - 13);
 
-        let c = g.discard_pile.pop().unwrap();
-        g.hand.push(c);
+        let c = g.discard_pile.pop(&g.cards).unwrap();
+        g.hand.push(c, &g.cards);
         g.step_test(PlayCardStep {
             hand_index: 1,
             target: Some(0),
@@ -846,11 +851,11 @@ mod tests {
         g.add_card_to_hand(CardClass::AscendersBane);
         g.play_card(CardClass::SeverSoul, Some(CreatureRef::monster(0)));
         assert_eq!(g.hand.len(), 1);
-        assert_eq!(g.hand[0].borrow().class, CardClass::Strike);
+        assert_eq!(g.cards[g.hand[0]].class, CardClass::Strike);
         assert_eq!(g.exhaust_pile.len(), 3);
-        assert_eq!(g.exhaust_pile[0].borrow().class, CardClass::Defend);
-        assert_eq!(g.exhaust_pile[1].borrow().class, CardClass::Wound);
-        assert_eq!(g.exhaust_pile[2].borrow().class, CardClass::AscendersBane);
+        assert_eq!(g.cards[g.exhaust_pile[0]].class, CardClass::Defend);
+        assert_eq!(g.cards[g.exhaust_pile[1]].class, CardClass::Wound);
+        assert_eq!(g.cards[g.exhaust_pile[2]].class, CardClass::AscendersBane);
     }
 
     #[test]
@@ -896,7 +901,7 @@ mod tests {
                 let double_tap_card_index = g
                     .hand
                     .iter()
-                    .position(|c| c.borrow().class == CardClass::DoubleTap);
+                    .position(|c| g.cards[c].class == CardClass::DoubleTap);
                 if let Some(i) = double_tap_card_index {
                     g.step_test(PlayCardStep {
                         hand_index: i,
@@ -918,18 +923,18 @@ mod tests {
         g.add_card_to_discard_pile(CardClass::BloodForBlood);
         g.add_card_to_hand(CardClass::BloodForBlood);
         g.add_card_to_exhaust_pile(CardClass::BloodForBlood);
-        assert_eq!(g.discard_pile[0].borrow().get_base_cost(), 4);
-        assert_eq!(g.hand[0].borrow().get_base_cost(), 4);
-        assert_eq!(g.exhaust_pile[0].borrow().get_base_cost(), 4);
+        assert_eq!(g.cards[g.discard_pile[0]].get_base_cost(), 4);
+        assert_eq!(g.cards[g.hand[0]].get_base_cost(), 4);
+        assert_eq!(g.cards[g.exhaust_pile[0]].get_base_cost(), 4);
         g.play_card(CardClass::Bloodletting, None);
         g.add_card_to_draw_pile(CardClass::BloodForBlood);
-        assert_eq!(g.draw_pile.get(0).borrow().get_base_cost(), 3);
-        assert_eq!(g.discard_pile[0].borrow().get_base_cost(), 3);
-        assert_eq!(g.hand[0].borrow().get_base_cost(), 3);
-        assert_eq!(g.exhaust_pile[0].borrow().get_base_cost(), 4);
+        assert_eq!(g.cards[g.draw_pile.get(0)].get_base_cost(), 3);
+        assert_eq!(g.cards[g.discard_pile[0]].get_base_cost(), 3);
+        assert_eq!(g.cards[g.hand[0]].get_base_cost(), 3);
+        assert_eq!(g.cards[g.exhaust_pile[0]].get_base_cost(), 4);
 
         let cost_sum =
-            |g: &Game| -> i32 { g.hand.iter().map(|c| c.borrow().get_base_cost()).sum() };
+            |g: &Game| -> i32 { g.hand.iter().map(|c| g.cards[c].get_base_cost()).sum() };
 
         g.player.block = 2;
         g.step_test(EndTurnStep);
@@ -944,7 +949,7 @@ mod tests {
         g.play_card(CardClass::Bloodletting, None);
         assert_eq!(cost_sum(&g), 0);
 
-        assert_eq!(g.exhaust_pile[0].borrow().get_base_cost(), 4);
+        assert_eq!(g.cards[g.exhaust_pile[0]].get_base_cost(), 4);
     }
 
     #[test]
@@ -957,12 +962,12 @@ mod tests {
         g.play_card(CardClass::Bloodletting, None);
         g.play_card(CardClass::Bloodletting, None);
         g.add_card_to_hand(CardClass::BloodForBlood);
-        assert_eq!(g.hand[0].borrow().get_base_cost(), 2);
+        assert_eq!(g.cards[g.hand[0]].get_base_cost(), 2);
         g.play_card(CardClass::DebugKillAll, None);
         g.step_test(RewardExitStep);
         g.step_test(AscendStep::new(0, 1));
         g.add_card_to_hand(CardClass::BloodForBlood);
-        assert_eq!(g.hand[0].borrow().get_base_cost(), 4);
+        assert_eq!(g.cards[g.hand[0]].get_base_cost(), 4);
     }
 
     #[test]
@@ -1078,11 +1083,11 @@ mod tests {
         assert_eq!(g.monsters[0].creature.cur_hp, 100 - 7 * 4);
         assert_eq!(g.hand.len(), 0);
         assert_eq!(g.exhaust_pile.len(), 5);
-        assert_eq!(g.exhaust_pile[0].borrow().class, CardClass::Defend);
-        assert_eq!(g.exhaust_pile[1].borrow().class, CardClass::Strike);
-        assert_eq!(g.exhaust_pile[2].borrow().class, CardClass::Wound);
-        assert_eq!(g.exhaust_pile[3].borrow().class, CardClass::AscendersBane);
-        assert_eq!(g.exhaust_pile[4].borrow().class, CardClass::FiendFire);
+        assert_eq!(g.cards[g.exhaust_pile[0]].class, CardClass::Defend);
+        assert_eq!(g.cards[g.exhaust_pile[1]].class, CardClass::Strike);
+        assert_eq!(g.cards[g.exhaust_pile[2]].class, CardClass::Wound);
+        assert_eq!(g.cards[g.exhaust_pile[3]].class, CardClass::AscendersBane);
+        assert_eq!(g.cards[g.exhaust_pile[4]].class, CardClass::FiendFire);
     }
 
     #[test]
@@ -1113,28 +1118,28 @@ mod tests {
             target: Some(0),
         });
         assert_eq!(g.monsters[0].creature.cur_hp, hp0 - 15);
-        assert_eq!(g.master_deck[0].borrow().base_increase, 0);
-        assert_eq!(g.master_deck[1].borrow().base_increase, 0);
+        assert_eq!(g.cards[g.master_deck[0]].base_increase, 0);
+        assert_eq!(g.cards[g.master_deck[1]].base_increase, 0);
 
         g.monsters[0].creature.cur_hp = 10;
         g.step_test(PlayCardStep {
             hand_index: 0,
             target: Some(0),
         });
-        assert_eq!(g.exhaust_pile[0].borrow().base_increase, 0);
-        assert_eq!(g.exhaust_pile[1].borrow().base_increase, 3);
+        assert_eq!(g.cards[g.exhaust_pile[0]].base_increase, 0);
+        assert_eq!(g.cards[g.exhaust_pile[1]].base_increase, 3);
         assert!(
-            g.master_deck[0].borrow().base_increase == 0
-                || g.master_deck[1].borrow().base_increase == 0
+            g.cards[g.master_deck[0]].base_increase == 0
+                || g.cards[g.master_deck[1]].base_increase == 0
         );
         assert!(
-            g.master_deck[0].borrow().base_increase == 3
-                || g.master_deck[1].borrow().base_increase == 3
+            g.cards[g.master_deck[0]].base_increase == 3
+                || g.cards[g.master_deck[1]].base_increase == 3
         );
 
         let hp1 = g.monsters[1].creature.cur_hp;
-        let c = g.exhaust_pile.pop().unwrap();
-        g.hand.push(c);
+        let c = g.exhaust_pile.pop(&g.cards).unwrap();
+        g.hand.push(c, &g.cards);
         g.step_test(PlayCardStep {
             hand_index: 0,
             target: Some(1),
@@ -1142,21 +1147,21 @@ mod tests {
         assert_eq!(g.monsters[1].creature.cur_hp, hp1 - 18);
 
         g.monsters[1].creature.cur_hp = 17;
-        let c = g.exhaust_pile.pop().unwrap();
-        c.borrow_mut().upgrade();
-        g.hand.push(c);
+        let c = g.exhaust_pile.pop(&g.cards).unwrap();
+        g.update_card(c, |c| c.upgrade());
+        g.hand.push(c, &g.cards);
         g.step_test(PlayCardStep {
             hand_index: 0,
             target: Some(1),
         });
 
         assert!(
-            g.master_deck[0].borrow().base_increase == 0
-                || g.master_deck[1].borrow().base_increase == 0
+            g.cards[g.master_deck[0]].base_increase == 0
+                || g.cards[g.master_deck[1]].base_increase == 0
         );
         assert!(
-            g.master_deck[0].borrow().base_increase == 8
-                || g.master_deck[1].borrow().base_increase == 8
+            g.cards[g.master_deck[0]].base_increase == 8
+                || g.cards[g.master_deck[1]].base_increase == 8
         );
     }
 
@@ -1199,7 +1204,7 @@ mod tests {
             .build_combat();
 
         for _ in 0..50 {
-            let target = if g.hand[0].borrow().class == CardClass::Finesse {
+            let target = if g.cards[g.hand[0]].class == CardClass::Finesse {
                 None
             } else {
                 Some(0)
@@ -1216,7 +1221,6 @@ mod tests {
     fn test_upgrade_crash() {
         let mut g = GameBuilder::default().build_combat();
         let c = g.new_card_upgraded(CardClass::Strike);
-        let mut c = c.borrow_mut();
-        c.upgrade();
+        g.update_card(c, |c| c.upgrade());
     }
 }
@@ -159,7 +159,7 @@ mod tests {
             target: None,
         });
         assert_eq!(g.valid_steps().len(), 1);
-        g.hand.pop();
+        g.hand.pop(&g.cards);
         assert_ne!(g.valid_steps().len(), 1);
         g.step_test(EndTurnStep);
         g.energy = 10;
@@ -189,9 +189,9 @@ mod tests {
         g.add_card_to_hand(CardClass::Necronomicurse);
         g.play_card(CardClass::TrueGrit, None);
         assert_eq!(g.hand.len(), 1);
-        assert_eq!(g.hand[0].borrow().class, CardClass::Necronomicurse);
+        assert_eq!(g.cards[g.hand[0]].class, CardClass::Necronomicurse);
         assert_eq!(g.exhaust_pile.len(), 1);
-        assert_eq!(g.exhaust_pile[0].borrow().class, CardClass::Necronomicurse);
+        assert_eq!(g.cards[g.exhaust_pile[0]].class, CardClass::Necronomicurse);
     }
 
     #[test]
@@ -205,8 +205,8 @@ mod tests {
         g.play_card(CardClass::Necronomicurse, None);
 
         assert_eq!(g.hand.len(), 3);
-        assert_eq!(g.hand[1].borrow().class, CardClass::Strike);
-        assert_eq!(g.hand[2].borrow().class, CardClass::Necronomicurse);
+        assert_eq!(g.cards[g.hand[1]].class, CardClass::Strike);
+        assert_eq!(g.cards[g.hand[2]].class, CardClass::Necronomicurse);
     }
 
     #[test]
@@ -12,7 +12,7 @@ use crate::{
     rng::rand_slice,
 };
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
 pub enum CardType {
     Attack,
     Skill,
@@ -41,7 +41,7 @@ pub enum CardColor {
 
 macro_rules! c {
     ($($name:ident => ($rarity:expr, $ty:expr, $color:expr, $cost:expr, $behavior:expr, $exhausts:expr)),+,) => {
-        #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
         pub enum CardClass {
             $(
                 $name,
@@ -259,7 +259,7 @@ c!(
 pub type CardBehavior = fn(&mut Game, &CardPlayInfo);
 pub type CardEndOfTurnBehavior = fn(&mut Game);
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub enum CardCost {
     Cost {
         base_cost: i32,
@@ -489,7 +489,7 @@ mod tests {
 
     #[test]
     fn test_transformed() {
-        let mut rng = Rand::default();
+        let mut rng: Rand = rand::make_rng();
         for _ in 0..100 {
             {
                 let t = transformed(CardClass::Strike, &mut rng);
@@ -543,19 +543,19 @@ mod tests {
         assert!(
             g.hand
                 .iter()
-                .any(|c| c.borrow().class == CardClass::MindBlast)
+                .any(|c| g.cards[c].class == CardClass::MindBlast)
         );
         assert!(
             g.hand
                 .iter()
-                .any(|c| c.borrow().class == CardClass::Brutality)
+                .any(|c| g.cards[c].class == CardClass::Brutality)
         );
         assert!(
             g.hand
                 .iter()
-                .any(|c| c.borrow().class == CardClass::DramaticEntrance)
+                .any(|c| g.cards[c].class == CardClass::DramaticEntrance)
         );
-        assert!(g.hand.iter().any(|c| c.borrow().class == CardClass::Writhe));
+        assert!(g.hand.iter().any(|c| g.cards[c].class == CardClass::Writhe));
     }
 
     #[test]
@@ -184,11 +184,11 @@ mod tests {
             .add_cards(CardClass::Strike, 10)
             .build_combat();
         g.play_card(CardClass::FeelNoPain, None);
-        let card = g.hand.pop().unwrap();
+        let card = g.hand.pop(&g.cards).unwrap();
         g.run_action(ExhaustCardAction(card));
         assert_eq!(g.player.block, 3);
         g.play_card(CardClass::FeelNoPain, None);
-        let card = g.hand.pop().unwrap();
+        let card = g.hand.pop(&g.cards).unwrap();
         g.run_action(ExhaustCardAction(card));
         assert_eq!(g.player.block, 9);
     }
@@ -200,13 +200,13 @@ mod tests {
             .build_combat();
         assert_eq!(g.hand.len(), 5);
         g.play_card_upgraded(CardClass::DarkEmbrace, None);
-        let card = g.hand.pop().unwrap();
+        let card = g.hand.pop(&g.cards).unwrap();
         assert_eq!(g.hand.len(), 4);
         g.run_action(ExhaustCardAction(card));
         assert_eq!(g.hand.len(), 5);
         g.play_card(CardClass::DarkEmbrace, None);
         assert_eq!(g.hand.len(), 5);
-        let card = g.hand.pop().unwrap();
+        let card = g.hand.pop(&g.cards).unwrap();
         assert_eq!(g.hand.len(), 4);
         g.run_action(ExhaustCardAction(card));
         assert_eq!(g.hand.len(), 6);
@@ -264,7 +264,7 @@ mod tests {
                 .add_card(CardClass::Brutality)
                 .add_cards(CardClass::Strike, 1000)
                 .build_combat();
-            if g.hand.iter().all(|c| c.borrow().class == CardClass::Strike) {
+            if g.hand.iter().all(|c| g.cards[c].class == CardClass::Strike) {
                 return;
             }
         }
@@ -483,9 +483,11 @@ pub fn transmutation_behavior(game: &mut Game, info: &CardPlayInfo) {
         } else {
             game.new_card(class)
         };
-        if let CardCost::Cost { base_cost, .. } = &mut c.borrow_mut().cost {
-            *base_cost = 0;
-        }
+        game.update_card(c, |c| {
+            if let CardCost::Cost { base_cost, .. } = &mut c.cost {
+                *base_cost = 0;
+            }
+        });
         game.action_queue.push_bot(PlaceCardInHandAction(c));
     }
 }
@@ -570,9 +572,9 @@ mod tests {
             );
 
             g.step_test(ArmamentsStep { hand_index: 0 });
-            assert_eq!(g.hand[0].borrow().upgrade_count, 1);
-            assert_eq!(g.hand[1].borrow().upgrade_count, 1);
-            assert_eq!(g.hand[2].borrow().upgrade_count, 0);
+            assert_eq!(g.cards[g.hand[0]].upgrade_count, 1);
+            assert_eq!(g.cards[g.hand[1]].upgrade_count, 1);
+            assert_eq!(g.cards[g.hand[2]].upgrade_count, 0);
         }
 
         {
@@ -594,8 +596,8 @@ mod tests {
                 hand_index: 0,
                 target: None,
             });
-            for c in g.hand {
-                assert!(!c.borrow().can_upgrade());
+            for &c in &g.hand {
+                assert!(!g.cards[c].can_upgrade());
             }
         }
 
@@ -609,8 +611,8 @@ mod tests {
                 hand_index: 0,
                 target: None,
             });
-            for c in g.hand {
-                assert!(!c.borrow().can_upgrade());
+            for &c in &g.hand {
+                assert!(!g.cards[c].can_upgrade());
             }
         }
     }
@@ -622,9 +624,9 @@ mod tests {
         g.add_card_to_hand_upgraded(CardClass::Defend);
         g.add_card_to_hand_upgraded(CardClass::SearingBlow);
         g.play_card_upgraded(CardClass::Armaments, None);
-        assert!(g.hand[0].borrow().upgrade_count == 1);
-        assert!(g.hand[1].borrow().upgrade_count == 1);
-        assert!(g.hand[2].borrow().upgrade_count == 2);
+        assert!(g.cards[g.hand[0]].upgrade_count == 1);
+        assert!(g.cards[g.hand[1]].upgrade_count == 1);
+        assert!(g.cards[g.hand[2]].upgrade_count == 2);
     }
 
     #[test]
@@ -640,7 +642,7 @@ mod tests {
             g.add_card_to_hand(CardClass::Defend);
             g.play_card(CardClass::TrueGrit, None);
             assert_eq!(g.exhaust_pile.len(), 1);
-            if g.hand[0].borrow().class == CardClass::Strike {
+            if g.cards[g.hand[0]].class == CardClass::Strike {
                 found_strike = true;
             } else {
                 found_defend = true;
@@ -758,11 +760,11 @@ mod tests {
         g.play_card(CardClass::Warcry, None);
         assert_eq!(g.draw_pile.len(), 2);
         assert_eq!(
-            g.draw_pile.pop(&mut g.rng).borrow().class,
+            g.cards[g.draw_pile.pop(&mut g.rng, &g.cards)].class,
             CardClass::Defend
         );
         assert_eq!(
-            g.draw_pile.pop(&mut g.rng).borrow().class,
+            g.cards[g.draw_pile.pop(&mut g.rng, &g.cards)].class,
             CardClass::Strike
         );
         assert_eq!(g.hand.len(), 0);
@@ -785,11 +787,11 @@ mod tests {
         g.step_test(PlaceCardInHandOnTopOfDrawStep { hand_index: 0 });
         assert_eq!(g.draw_pile.len(), 1);
         assert_eq!(
-            g.draw_pile.top(&mut g.rng).borrow().class,
+            g.cards[g.draw_pile.top(&mut g.rng)].class,
             CardClass::Defend
         );
         assert_eq!(g.hand.len(), 1);
-        assert_eq!(g.hand[0].borrow().class, CardClass::Strike);
+        assert_eq!(g.cards[g.hand[0]].class, CardClass::Strike);
     }
 
     #[test]
@@ -820,7 +822,7 @@ mod tests {
             .add_cards(CardClass::Sentinel, 2)
             .build_combat();
         assert_eq!(g.energy, 3);
-        let c = g.hand.pop().unwrap();
+        let c = g.hand.pop(&g.cards).unwrap();
         g.run_action(ExhaustCardAction(c));
         assert_eq!(g.energy, 5);
         g.step_test(PlayCardStep {
@@ -836,7 +838,7 @@ mod tests {
             .add_cards_upgraded(CardClass::Sentinel, 2)
             .build_combat();
         assert_eq!(g.energy, 3);
-        let c = g.hand.pop().unwrap();
+        let c = g.hand.pop(&g.cards).unwrap();
         g.run_action(ExhaustCardAction(c));
         assert_eq!(g.energy, 6);
         g.step_test(PlayCardStep {
@@ -873,16 +875,16 @@ mod tests {
         g.add_card_to_hand(CardClass::Strike);
         g.play_card(CardClass::DualWield, None);
         assert_eq!(g.hand.len(), 2);
-        assert_eq!(g.hand[0].borrow().class, CardClass::Strike);
-        assert_eq!(g.hand[1].borrow().class, CardClass::Strike);
+        assert_eq!(g.cards[g.hand[0]].class, CardClass::Strike);
+        assert_eq!(g.cards[g.hand[1]].class, CardClass::Strike);
 
         g.hand.clear();
         g.add_card_to_hand(CardClass::Inflame);
         g.play_card_upgraded(CardClass::DualWield, None);
         assert_eq!(g.hand.len(), 3);
-        assert_eq!(g.hand[0].borrow().class, CardClass::Inflame);
-        assert_eq!(g.hand[1].borrow().class, CardClass::Inflame);
-        assert_eq!(g.hand[2].borrow().class, CardClass::Inflame);
+        assert_eq!(g.cards[g.hand[0]].class, CardClass::Inflame);
+        assert_eq!(g.cards[g.hand[1]].class, CardClass::Inflame);
+        assert_eq!(g.cards[g.hand[2]].class, CardClass::Inflame);
 
         g.hand.clear();
         g.add_card_to_hand(CardClass::Strike);
@@ -907,33 +909,33 @@ mod tests {
             amount: 1,
         });
         assert_eq!(g.hand.len(), 4);
-        assert_eq!(g.hand[0].borrow().class, CardClass::Inflame);
-        assert_eq!(g.hand[1].borrow().class, CardClass::Defend);
-        assert_eq!(g.hand[2].borrow().class, CardClass::Strike);
-        assert_eq!(g.hand[3].borrow().class, CardClass::Strike);
+        assert_eq!(g.cards[g.hand[0]].class, CardClass::Inflame);
+        assert_eq!(g.cards[g.hand[1]].class, CardClass::Defend);
+        assert_eq!(g.cards[g.hand[2]].class, CardClass::Strike);
+        assert_eq!(g.cards[g.hand[3]].class, CardClass::Strike);
 
         g.hand.clear();
         g.add_card_to_hand(CardClass::RitualDagger);
-        let id = g.hand[0].borrow().id;
+        let id = g.cards[g.hand[0]].id;
         g.play_card(CardClass::DualWield, None);
-        assert_eq!(g.hand[0].borrow().class, CardClass::RitualDagger);
-        assert_eq!(g.hand[0].borrow().id, id);
-        assert_eq!(g.hand[1].borrow().class, CardClass::RitualDagger);
-        assert_ne!(g.hand[1].borrow().id, id);
+        assert_eq!(g.cards[g.hand[0]].class, CardClass::RitualDagger);
+        assert_eq!(g.cards[g.hand[0]].id, id);
+        assert_eq!(g.cards[g.hand[1]].class, CardClass::RitualDagger);
+        assert_ne!(g.cards[g.hand[1]].id, id);
 
         g.hand.clear();
         g.add_card_to_hand(CardClass::Strike);
         g.add_card_to_hand(CardClass::RitualDagger);
-        let id = g.hand[1].borrow().id;
+        let id = g.cards[g.hand[1]].id;
         g.play_card(CardClass::DualWield, None);
         g.step_test(DualWieldStep {
             hand_index: 1,
             amount: 1,
         });
-        assert_eq!(g.hand[1].borrow().class, CardClass::RitualDagger);
-        assert_ne!(g.hand[1].borrow().id, id);
-        assert_eq!(g.hand[2].borrow().class, CardClass::RitualDagger);
-        assert_ne!(g.hand[2].borrow().id, id);
+        assert_eq!(g.cards[g.hand[1]].class, CardClass::RitualDagger);
+        assert_ne!(g.cards[g.hand[1]].id, id);
+        assert_eq!(g.cards[g.hand[2]].class, CardClass::RitualDagger);
+        assert_ne!(g.cards[g.hand[2]].id, id);
 
         g.hand.clear();
         g.discard_pile.clear();
@@ -943,9 +945,9 @@ mod tests {
         g.add_card_to_hand(CardClass::Strike);
         g.play_card_upgraded(CardClass::DualWield, None);
         assert_eq!(g.hand.len(), 10);
-        assert_eq!(g.discard_pile[0].borrow().class, CardClass::Strike);
-        assert_eq!(g.discard_pile[1].borrow().class, CardClass::Strike);
-        assert_eq!(g.discard_pile[2].borrow().class, CardClass::DualWield);
+        assert_eq!(g.cards[g.discard_pile[0]].class, CardClass::Strike);
+        assert_eq!(g.cards[g.discard_pile[1]].class, CardClass::Strike);
+        assert_eq!(g.cards[g.discard_pile[2]].class, CardClass::DualWield);
     }
 
     #[test]
@@ -996,11 +998,11 @@ mod tests {
         g.add_card_to_hand(CardClass::AscendersBane);
         g.play_card(CardClass::SecondWind, None);
         assert_eq!(g.hand.len(), 1);
-        assert_eq!(g.hand[0].borrow().class, CardClass::Strike);
+        assert_eq!(g.cards[g.hand[0]].class, CardClass::Strike);
         assert_eq!(g.exhaust_pile.len(), 3);
-        assert_eq!(g.exhaust_pile[0].borrow().class, CardClass::Defend);
-        assert_eq!(g.exhaust_pile[1].borrow().class, CardClass::Wound);
-        assert_eq!(g.exhaust_pile[2].borrow().class, CardClass::AscendersBane);
+        assert_eq!(g.cards[g.exhaust_pile[0]].class, CardClass::Defend);
+        assert_eq!(g.cards[g.exhaust_pile[1]].class, CardClass::Wound);
+        assert_eq!(g.cards[g.exhaust_pile[2]].class, CardClass::AscendersBane);
         assert_eq!(g.player.block, 15);
     }
 
@@ -1009,8 +1011,8 @@ mod tests {
         let mut g = GameBuilder::default().build_combat();
         g.play_card(CardClass::PowerThrough, None);
         assert_eq!(g.hand.len(), 2);
-        assert_eq!(g.hand[0].borrow().class, CardClass::Wound);
-        assert_eq!(g.hand[1].borrow().class, CardClass::Wound);
+        assert_eq!(g.cards[g.hand[0]].class, CardClass::Wound);
+        assert_eq!(g.cards[g.hand[1]].class, CardClass::Wound);
     }
 
     #[test]
@@ -1035,10 +1037,7 @@ mod tests {
         g.add_card_to_hand(CardClass::Defend);
         g.add_card_to_draw_pile(CardClass::TwinStrike);
         g.play_card(CardClass::BurningPact, None);
-        assert_eq!(
-            g.cur_card.clone().unwrap().borrow().class,
-            CardClass::BurningPact
-        );
+        assert_eq!(g.cards[g.cur_card.unwrap()].class, CardClass::BurningPact);
         assert_eq!(
             g.valid_steps(),
             vec![
@@ -1048,7 +1047,7 @@ mod tests {
         );
         g.step_test(ExhaustOneCardInHandStep { hand_index: 1 });
         assert_eq!(g.exhaust_pile.len(), 1);
-        assert_eq!(g.exhaust_pile[0].borrow().class, CardClass::Defend);
+        assert_eq!(g.cards[g.exhaust_pile[0]].class, CardClass::Defend);
         assert_eq!(g.hand.len(), 2);
     }
 
@@ -1058,7 +1057,7 @@ mod tests {
         for _ in 0..100 {
             g.hand.clear();
             g.play_card_upgraded(CardClass::InfernalBlade, None);
-            let c = g.hand[0].borrow();
+            let c = &g.cards[g.hand[0]];
             if let CardCost::Cost { temporary_cost, .. } = c.cost {
                 assert_eq!(temporary_cost, Some(0))
             }
@@ -1091,7 +1090,7 @@ mod tests {
         g.add_card_to_exhaust_pile(CardClass::Strike);
         g.play_card_upgraded(CardClass::Exhume, None);
         assert_eq!(g.hand.len(), 1);
-        assert_eq!(g.hand[0].borrow().class, CardClass::Strike);
+        assert_eq!(g.cards[g.hand[0]].class, CardClass::Strike);
         assert_eq!(g.exhaust_pile.len(), 1);
 
         g.hand.clear();
@@ -1107,7 +1106,7 @@ mod tests {
         g.add_card_to_exhaust_pile(CardClass::Exhume);
         g.play_card_upgraded(CardClass::Exhume, None);
         assert_eq!(g.hand.len(), 1);
-        assert_eq!(g.hand[0].borrow().class, CardClass::Strike);
+        assert_eq!(g.cards[g.hand[0]].class, CardClass::Strike);
         assert_eq!(g.exhaust_pile.len(), 2);
 
         g.hand.clear();
@@ -1125,7 +1124,7 @@ mod tests {
         );
         g.step_test(ExhumeStep { exhaust_index: 2 });
         assert_eq!(g.hand.len(), 1);
-        assert_eq!(g.hand[0].borrow().class, CardClass::Defend);
+        assert_eq!(g.cards[g.hand[0]].class, CardClass::Defend);
         assert_eq!(g.exhaust_pile.len(), 3);
     }
 
@@ -1179,7 +1178,7 @@ mod tests {
             target: Some(0),
         });
         assert_eq!(g.energy, 2);
-        g.hand.push(g.discard_pile.pop().unwrap());
+        g.hand.push(g.discard_pile.pop(&g.cards).unwrap(), &g.cards);
         g.step_test(PlayCardStep {
             hand_index: 0,
             target: Some(0),
@@ -1197,9 +1196,9 @@ mod tests {
             hand_index: 0,
             target: None,
         });
-        let b = g.hand.pop().unwrap();
+        let b = g.hand.pop(&g.cards).unwrap();
         g.run_action(ExhaustCardAction(b));
-        g.hand.push(g.exhaust_pile.pop().unwrap());
+        g.hand.push(g.exhaust_pile.pop(&g.cards).unwrap(), &g.cards);
         g.step_test(PlayCardStep {
             hand_index: 0,
             target: Some(0),
@@ -1217,7 +1216,7 @@ mod tests {
             hand_index: 0,
             target: None,
         });
-        g.discard_pile.pop();
+        g.discard_pile.pop(&g.cards);
         g.step_test(EndTurnStep);
         g.step_test(PlayCardStep {
             hand_index: 0,
@@ -1243,7 +1242,7 @@ mod tests {
             target: Some(0),
         });
         assert_eq!(g.energy, 2);
-        g.hand.push(g.discard_pile.pop().unwrap());
+        g.hand.push(g.discard_pile.pop(&g.cards).unwrap(), &g.cards);
         g.step_test(PlayCardStep {
             hand_index: 0,
             target: Some(0),
@@ -1257,7 +1256,7 @@ mod tests {
         g.add_card_to_hand(CardClass::Bash);
         assert_eq!(g.energy, 3);
         g.play_card_upgraded(CardClass::Enlightenment, None);
-        g.discard_pile.pop();
+        g.discard_pile.pop(&g.cards);
         g.step_test(EndTurnStep);
         g.step_test(PlayCardStep {
             hand_index: 0,
@@ -1296,12 +1295,12 @@ mod tests {
         let mut g = GameBuilder::default().build_combat();
         g.play_card(CardClass::JackOfAllTrades, None);
         assert_eq!(g.hand.len(), 1);
-        assert_eq!(g.hand[0].borrow().class.color(), CardColor::Colorless);
+        assert_eq!(g.cards[g.hand[0]].class.color(), CardColor::Colorless);
         g.play_card_upgraded(CardClass::JackOfAllTrades, None);
         assert_eq!(g.hand.len(), 3);
-        assert_eq!(g.hand[0].borrow().class.color(), CardColor::Colorless);
-        assert_eq!(g.hand[1].borrow().class.color(), CardColor::Colorless);
-        assert_eq!(g.hand[2].borrow().class.color(), CardColor::Colorless);
+        assert_eq!(g.cards[g.hand[0]].class.color(), CardColor::Colorless);
+        assert_eq!(g.cards[g.hand[1]].class.color(), CardColor::Colorless);
+        assert_eq!(g.cards[g.hand[2]].class.color(), CardColor::Colorless);
     }
 
     #[test]
@@ -1364,14 +1363,20 @@ mod tests {
         g.add_card_to_draw_pile(CardClass::Strike);
         g.add_card_to_draw_pile(CardClass::Wound);
         g.play_card(CardClass::Apotheosis, None);
-        assert_eq!(g.hand[0].borrow().upgrade_count, 1);
-        assert_eq!(g.hand[1].borrow().upgrade_count, 1);
-        assert_eq!(g.hand[2].borrow().upgrade_count, 2);
-        assert_eq!(g.discard_pile[0].borrow().upgrade_count, 1);
-        assert_eq!(g.discard_pile[1].borrow().upgrade_count, 0);
-        assert_eq!(g.draw_pile.pop(&mut g.rng).borrow().upgrade_count, 0);
-        assert_eq!(g.draw_pile.pop(&mut g.rng).borrow().upgrade_count, 1);
-        assert_eq!(g.exhaust_pile[0].borrow().upgrade_count, 1);
+        assert_eq!(g.cards[g.hand[0]].upgrade_count, 1);
+        assert_eq!(g.cards[g.hand[1]].upgrade_count, 1);
+        assert_eq!(g.cards[g.hand[2]].upgrade_count, 2);
+        assert_eq!(g.cards[g.discard_pile[0]].upgrade_count, 1);
+        assert_eq!(g.cards[g.discard_pile[1]].upgrade_count, 0);
+        assert_eq!(
+            g.cards[g.draw_pile.pop(&mut g.rng, &g.cards)].upgrade_count,
+            0
+        );
+        assert_eq!(
+            g.cards[g.draw_pile.pop(&mut g.rng, &g.cards)].upgrade_count,
+            1
+        );
+        assert_eq!(g.cards[g.exhaust_pile[0]].upgrade_count, 1);
     }
 
     #[test]
@@ -1381,7 +1386,7 @@ mod tests {
         g.add_card_to_hand(CardClass::Bloodletting);
         g.play_card_upgraded(CardClass::Madness, None);
         assert_eq!(
-            g.hand[0].borrow().cost,
+            g.cards[g.hand[0]].cost,
             CardCost::Cost {
                 base_cost: 0,
                 temporary_cost: None,
@@ -1393,7 +1398,7 @@ mod tests {
         g.add_card_to_hand(CardClass::Strike);
         g.play_card_upgraded(CardClass::Madness, None);
         assert_eq!(
-            g.hand[0].borrow().cost,
+            g.cards[g.hand[0]].cost,
             CardCost::Cost {
                 base_cost: 0,
                 temporary_cost: None,
@@ -1403,12 +1408,12 @@ mod tests {
 
         g.hand.clear();
         let c = g.new_card(CardClass::Strike);
-        c.borrow_mut().set_cost(1, Some(0));
-        g.hand.push(c);
+        g.update_card(c, |c| c.set_cost(1, Some(0)));
+        g.hand.push(c, &g.cards);
         g.add_card_to_hand(CardClass::Strike);
         g.play_card_upgraded(CardClass::Madness, None);
         assert_eq!(
-            g.hand[0].borrow().cost,
+            g.cards[g.hand[0]].cost,
             CardCost::Cost {
                 base_cost: 1,
                 temporary_cost: Some(0),
@@ -1416,7 +1421,7 @@ mod tests {
             }
         );
         assert_eq!(
-            g.hand[1].borrow().cost,
+            g.cards[g.hand[1]].cost,
             CardCost::Cost {
                 base_cost: 0,
                 temporary_cost: None,
@@ -1431,8 +1436,8 @@ mod tests {
             g.add_card_to_hand(CardClass::Strike);
             g.add_card_to_hand(CardClass::Bash);
             g.play_card_upgraded(CardClass::Madness, None);
-            found_0 |= g.hand[0].borrow().get_base_cost() == 0;
-            found_1 |= g.hand[1].borrow().get_base_cost() == 0;
+            found_0 |= g.cards[g.hand[0]].get_base_cost() == 0;
+            found_1 |= g.cards[g.hand[1]].get_base_cost() == 0;
         }
         assert!(found_0);
         assert!(found_1);
@@ -1614,13 +1619,13 @@ mod tests {
         g.step_test(ChooseExhaustCardsInHandEndStep);
         assert_eq!(g.hand.len(), 5);
         assert_eq!(g.exhaust_pile.len(), 1 + 1 + 1 + 3);
-        assert_eq!(g.hand[0].borrow().class, CardClass::Strike);
-        assert_eq!(g.hand[1].borrow().class, CardClass::Strike);
-        assert_eq!(g.hand[2].borrow().class, CardClass::Strike);
-        assert_eq!(g.hand[3].borrow().class, CardClass::Defend);
-        assert_eq!(g.hand[4].borrow().class, CardClass::Defend);
+        assert_eq!(g.cards[g.hand[0]].class, CardClass::Strike);
+        assert_eq!(g.cards[g.hand[1]].class, CardClass::Strike);
+        assert_eq!(g.cards[g.hand[2]].class, CardClass::Strike);
+        assert_eq!(g.cards[g.hand[3]].class, CardClass::Defend);
+        assert_eq!(g.cards[g.hand[4]].class, CardClass::Defend);
 
-        g.hand.pop();
+        g.hand.pop(&g.cards);
         g.play_card_upgraded(CardClass::Purity, None);
         g.step_test(ChooseExhaustCardsInHandStep {
             hand_index: 0,
@@ -1663,7 +1668,7 @@ mod tests {
         g.play_card(CardClass::SecretWeapon, None);
         assert_eq!(g.draw_pile.len(), 1);
         assert_eq!(g.hand.len(), 1);
-        assert_eq!(g.hand[0].borrow().class, CardClass::Strike);
+        assert_eq!(g.cards[g.hand[0]].class, CardClass::Strike);
 
         g.draw_pile.clear();
         g.hand.clear();
@@ -1681,7 +1686,7 @@ mod tests {
         g.step_test(FetchFromDrawStep { draw_index: 2 });
         assert_eq!(g.draw_pile.len(), 2);
         assert_eq!(g.hand.len(), 1);
-        assert_eq!(g.hand[0].borrow().class, CardClass::TwinStrike);
+        assert_eq!(g.cards[g.hand[0]].class, CardClass::TwinStrike);
 
         g.draw_pile.clear();
         g.hand.clear();
@@ -1692,7 +1697,7 @@ mod tests {
         g.play_card(CardClass::SecretWeapon, None);
         assert_eq!(g.hand.len(), 10);
         assert_eq!(g.discard_pile.len(), 1);
-        assert_eq!(g.discard_pile[0].borrow().class, CardClass::Strike);
+        assert_eq!(g.cards[g.discard_pile[0]].class, CardClass::Strike);
     }
 
     #[test]
@@ -1711,7 +1716,7 @@ mod tests {
         g.play_card(CardClass::SecretTechnique, None);
         assert_eq!(g.draw_pile.len(), 1);
         assert_eq!(g.hand.len(), 1);
-        assert_eq!(g.hand[0].borrow().class, CardClass::Defend);
+        assert_eq!(g.cards[g.hand[0]].class, CardClass::Defend);
 
         g.draw_pile.clear();
         g.hand.clear();
@@ -1729,7 +1734,7 @@ mod tests {
         g.step_test(FetchFromDrawStep { draw_index: 2 });
         assert_eq!(g.draw_pile.len(), 2);
         assert_eq!(g.hand.len(), 1);
-        assert_eq!(g.hand[0].borrow().class, CardClass::FlameBarrier);
+        assert_eq!(g.cards[g.hand[0]].class, CardClass::FlameBarrier);
 
         g.draw_pile.clear();
         g.hand.clear();
@@ -1740,7 +1745,7 @@ mod tests {
         g.play_card(CardClass::SecretTechnique, None);
         assert_eq!(g.hand.len(), 10);
         assert_eq!(g.discard_pile.len(), 1);
-        assert_eq!(g.discard_pile[0].borrow().class, CardClass::Defend);
+        assert_eq!(g.cards[g.discard_pile[0]].class, CardClass::Defend);
     }
 
     #[test]
@@ -1753,13 +1758,13 @@ mod tests {
             assert_eq!(g.draw_pile.len(), 3);
 
             for c in g.draw_pile.get_all() {
-                assert_eq!(c.borrow().class.ty(), CardType::Attack);
-                assert_eq!(c.borrow().class.color(), CardColor::Red);
-                assert_ne!(c.borrow().class, CardClass::Reaper);
-                assert_ne!(c.borrow().class, CardClass::Feed);
-                if c.borrow().class != CardClass::Whirlwind {
+                assert_eq!(g.cards[c].class.ty(), CardType::Attack);
+                assert_eq!(g.cards[c].class.color(), CardColor::Red);
+                assert_ne!(g.cards[c].class, CardClass::Reaper);
+                assert_ne!(g.cards[c].class, CardClass::Feed);
+                if g.cards[c].class != CardClass::Whirlwind {
                     assert_matches!(
-                        c.borrow().cost,
+                        g.cards[c].cost,
                         CardCost::Cost {
                             base_cost: 0,
                             temporary_cost: None,
@@ -1781,12 +1786,12 @@ mod tests {
             assert_eq!(g.draw_pile.len(), 3);
 
             for c in g.draw_pile.get_all() {
-                assert_eq!(c.borrow().class.ty(), CardType::Skill);
-                assert_eq!(c.borrow().class.color(), CardColor::Red);
-                assert_ne!(c.borrow().class, CardClass::Reaper);
-                assert_ne!(c.borrow().class, CardClass::Feed);
+                assert_eq!(g.cards[c].class.ty(), CardType::Skill);
+                assert_eq!(g.cards[c].class.color(), CardColor::Red);
+                assert_ne!(g.cards[c].class, CardClass::Reaper);
+                assert_ne!(g.cards[c].class, CardClass::Feed);
                 assert_matches!(
-                    c.borrow().cost,
+                    g.cards[c].cost,
                     CardCost::Cost {
                         base_cost: 0,
                         temporary_cost: None,
@@ -1812,13 +1817,13 @@ mod tests {
             assert_eq!(g.energy, 0);
             assert_eq!(g.hand.len(), 2);
             for c in &g.hand {
-                assert_eq!(c.borrow().class.color(), CardColor::Colorless);
-                assert_eq!(c.borrow().upgrade_count, 0);
+                assert_eq!(g.cards[c].class.color(), CardColor::Colorless);
+                assert_eq!(g.cards[c].upgrade_count, 0);
                 if let CardCost::Cost {
                     base_cost,
                     temporary_cost,
                     free_to_play_once,
-                } = c.borrow().cost
+                } = g.cards[c].cost
                 {
                     assert_eq!(base_cost, 0);
                     assert_eq!(temporary_cost, None);
@@ -1832,13 +1837,13 @@ mod tests {
             assert_eq!(g.energy, 0);
             assert_eq!(g.hand.len(), 2);
             for c in &g.hand {
-                assert_eq!(c.borrow().class.color(), CardColor::Colorless);
-                assert_eq!(c.borrow().upgrade_count, 1);
+                assert_eq!(g.cards[c].class.color(), CardColor::Colorless);
+                assert_eq!(g.cards[c].upgrade_count, 1);
                 if let CardCost::Cost {
                     base_cost,
                     temporary_cost,
                     free_to_play_once,
-                } = c.borrow().cost
+                } = g.cards[c].cost
                 {
                     assert_eq!(base_cost, 0);
                     assert_eq!(temporary_cost, None);
@@ -1859,9 +1864,9 @@ mod tests {
         g.play_card(CardClass::Forethought, None);
         assert_eq!(g.draw_pile.len(), 2);
         {
-            let c = g.draw_pile.pop(&mut g.rng);
-            assert_eq!(c.borrow().class, CardClass::Defend);
-            match c.borrow().cost {
+            let c = g.draw_pile.pop(&mut g.rng, &g.cards);
+            assert_eq!(g.cards[c].class, CardClass::Defend);
+            match g.cards[c].cost {
                 CardCost::Cost {
                     free_to_play_once, ..
                 } => assert!(!free_to_play_once),
@@ -1869,9 +1874,9 @@ mod tests {
             }
         }
         {
-            let c = g.draw_pile.pop(&mut g.rng);
-            assert_eq!(c.borrow().class, CardClass::Strike);
-            match c.borrow().cost {
+            let c = g.draw_pile.pop(&mut g.rng, &g.cards);
+            assert_eq!(g.cards[c].class, CardClass::Strike);
+            match g.cards[c].cost {
                 CardCost::Cost {
                     free_to_play_once, ..
                 } => assert!(free_to_play_once),
@@ -1907,11 +1912,11 @@ mod tests {
         g.step_test(ForethoughtAnyEndStep);
         assert_eq!(g.hand.len(), 1);
         assert_eq!(g.draw_pile.len(), 2);
-        g.draw_pile.pop(&mut g.rng);
+        g.draw_pile.pop(&mut g.rng, &g.cards);
         {
-            let c = g.draw_pile.pop(&mut g.rng);
-            assert_eq!(c.borrow().class, CardClass::Strike);
-            match c.borrow().cost {
+            let c = g.draw_pile.pop(&mut g.rng, &g.cards);
+            assert_eq!(g.cards[c].class, CardClass::Strike);
+            match g.cards[c].cost {
                 CardCost::Cost {
                     free_to_play_once, ..
                 } => assert!(free_to_play_once),
@@ -1931,13 +1936,13 @@ mod tests {
         g.step_test(ForethoughtAnyStep { hand_index: 0 });
         g.step_test(ForethoughtAnyStep { hand_index: 0 });
         g.step_test(ForethoughtAnyEndStep);
-        g.draw_pile.pop(&mut g.rng);
+        g.draw_pile.pop(&mut g.rng, &g.cards);
         assert_eq!(
-            g.draw_pile.pop(&mut g.rng).borrow().class,
+            g.cards[g.draw_pile.pop(&mut g.rng, &g.cards)].class,
             CardClass::TwinStrike
         );
         assert_eq!(
-            g.draw_pile.pop(&mut g.rng).borrow().class,
+            g.cards[g.draw_pile.pop(&mut g.rng, &g.cards)].class,
             CardClass::Strike
         );
     }
@@ -1950,8 +1955,8 @@ mod tests {
             g.play_card(CardClass::Discovery, None);
             assert_eq!(g.valid_steps().len(), 3);
             g.step(0);
-            assert_ne!(g.hand[0].borrow().class, CardClass::Reaper);
-            let target = if g.hand[0].borrow().has_target() {
+            assert_ne!(g.cards[g.hand[0]].class, CardClass::Reaper);
+            let target = if g.cards[g.hand[0]].has_target() {
                 Some(0)
             } else {
                 None
@@ -1969,7 +1974,7 @@ mod tests {
         g.add_card_to_draw_pile(CardClass::Strike);
         g.play_card(CardClass::DeepBreath, None);
         assert_eq!(g.hand.len(), 1);
-        assert_eq!(g.hand[0].borrow().class, CardClass::Strike);
+        assert_eq!(g.cards[g.hand[0]].class, CardClass::Strike);
 
         let mut found_anger = false;
         let mut found_shrug = false;
@@ -1983,7 +1988,7 @@ mod tests {
             g.add_card_to_draw_pile(CardClass::Strike);
             g.play_card_upgraded(CardClass::DeepBreath, None);
             assert_eq!(g.draw_pile.len(), 1);
-            match g.draw_pile.top(&mut g.rng).borrow().class {
+            match g.cards[g.draw_pile.top(&mut g.rng)].class {
                 CardClass::Anger => found_anger = true,
                 CardClass::ShrugItOff => found_shrug = true,
                 CardClass::Strike => found_strike = true,
@@ -2065,7 +2070,7 @@ mod tests {
             g.add_card_to_draw_pile(CardClass::Strike);
             g.add_card_to_draw_pile(CardClass::TwinStrike);
             g.play_card(CardClass::Violence, None);
-            match g.hand[0].borrow().class {
+            match g.cards[g.hand[0]].class {
                 CardClass::Strike => found_strike = true,
                 CardClass::TwinStrike => found_twin_strike = true,
                 _ => panic!(),
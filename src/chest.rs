@@ -17,15 +17,14 @@ pub enum ChestSize {
     Large,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ClosedChestGameState;
 
 impl GameState for ClosedChestGameState {
-    fn valid_steps(&self, _: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
+    fn valid_steps(&self, _: &Game, steps: &mut Steps) -> bool {
         steps.push(OpenChestStep);
         steps.push(SkipChestStep);
-        Some(steps)
+        true
     }
 }
 
@@ -162,9 +161,9 @@ mod tests {
             assert_eq!(g.master_deck.len(), 0);
             g.step_test(OpenChestStep);
             assert_eq!(g.master_deck.len(), 1);
-            assert_eq!(g.master_deck[0].borrow().class.ty(), CardType::Curse);
+            assert_eq!(g.cards[g.master_deck[0]].class.ty(), CardType::Curse);
             assert_not_matches!(
-                g.master_deck[0].borrow().class,
+                g.cards[g.master_deck[0]].class,
                 CardClass::Necronomicurse | CardClass::AscendersBane | CardClass::CurseOfTheBell
             );
         }
@@ -6,8 +6,8 @@ use crate::{
         end_of_turn_discard::EndOfTurnDiscardAction, play_card::PlayCardAction,
         start_of_turn_energy::StartOfTurnEnergyAction,
     },
+    card::CardDrawPile,
     creature::CreatureState,
-    draw_pile::DrawPile,
     game::{CombatType, CreatureRef, Game, RareCardBaseChance, RunActionsGameState, UsePotionStep},
     map::RoomType,
     monster::Monster,
@@ -20,7 +20,7 @@ use crate::{
     step::Step,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct RollCombatGameState;
 
 impl GameState for RollCombatGameState {
@@ -89,7 +89,7 @@ impl GameState for RollCombatGameState {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct RollEliteCombatGameState;
 
 impl GameState for RollEliteCombatGameState {
@@ -109,7 +109,7 @@ impl GameState for RollEliteCombatGameState {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct RollBossCombatGameState;
 
 impl GameState for RollBossCombatGameState {
@@ -121,7 +121,24 @@ impl GameState for RollBossCombatGameState {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FixedCombatGameState(pub Combat);
+
+impl GameState for FixedCombatGameState {
+    fn run(&self, game: &mut Game) {
+        let (room, reward) = match self.0.ty() {
+            CombatType::Elite => (RoomType::Elite, RewardType::Elite),
+            CombatType::Boss => (RoomType::Boss, RewardType::Boss),
+            _ => (RoomType::Monster, RewardType::Monster),
+        };
+        game.cur_room = Some(room);
+        game.monsters = self.0.monsters(game);
+        game.state
+            .push_state(CombatBeginGameState(self.0.ty(), reward));
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct PlayerTurnEndGameState;
 
 impl GameState for PlayerTurnEndGameState {
@@ -139,7 +156,7 @@ impl GameState for PlayerTurnEndGameState {
         let mut indexes_to_discard = Vec::new();
         let mut actions = vec![];
         for (i, c) in game.hand.iter().enumerate() {
-            if let Some(a) = c.borrow().class.end_of_turn_in_hand_behavior() {
+            if let Some(a) = game.cards[c].class.end_of_turn_in_hand_behavior() {
                 indexes_to_discard.push(i);
                 actions.push(a);
             }
@@ -149,7 +166,7 @@ impl GameState for PlayerTurnEndGameState {
         }
         for i in indexes_to_discard.into_iter().rev() {
             game.action_queue
-                .push_top(DiscardCardAction(game.hand.remove(i)));
+                .push_top(DiscardCardAction(game.hand.remove(i, &game.cards)));
         }
 
         game.action_queue.push_bot(EndOfTurnDiscardAction());
@@ -158,7 +175,7 @@ impl GameState for PlayerTurnEndGameState {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct MonsterTurnGameState;
 
 impl GameState for MonsterTurnGameState {
@@ -176,16 +193,18 @@ impl GameState for MonsterTurnGameState {
             game.monsters[i]
                 .creature
                 .trigger_statuses_turn_begin(CreatureRef::monster(i), &mut game.action_queue);
+            let m = &mut game.monsters[i];
+            m.update_behavior(|b, c| b.on_turn_begin(CreatureRef::monster(i), c));
         }
 
-        game.monster_turn_queue_active = game.monster_turn_queue_all.clone();
+        game.monster_turn_queue_active = game.monster_turn_queue_all.iter().copied().collect();
 
         game.state.push_state(EndOfRoundGameState);
         game.state.push_state(RunActionsGameState);
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct EndOfRoundGameState;
 
 impl GameState for EndOfRoundGameState {
@@ -215,7 +234,7 @@ impl GameState for EndOfRoundGameState {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct CombatEndGameState;
 
 impl GameState for CombatEndGameState {
@@ -225,7 +244,7 @@ impl GameState for CombatEndGameState {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct ResetCombatGameState;
 
 impl GameState for ResetCombatGameState {
@@ -246,7 +265,7 @@ impl GameState for ResetCombatGameState {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct RollCombatRewardsGameState(RewardType);
 
 impl GameState for RollCombatRewardsGameState {
@@ -352,20 +371,21 @@ impl GameState for RollCombatRewardsGameState {
 fn setup_combat_draw_pile(game: &mut Game) {
     let mut non_innate = Vec::new();
     let mut innate = Vec::new();
-    for c in &game.master_deck {
-        let c = game.clone_card_ref_same_id(c);
-        if c.borrow().is_innate() || c.borrow().is_bottled {
+    for c in game.master_deck.clone() {
+        let c = game.clone_card_ref_same_id(&c);
+        if game.cards[c].is_innate() || game.cards[c].is_bottled {
             innate.push(c);
         } else {
             non_innate.push(c);
         }
     }
     let num_innate = innate.len() as i32;
-    game.draw_pile = DrawPile::new(
+    game.draw_pile = CardDrawPile::new(
         game.has_relic(RelicClass::FrozenEye),
         innate,
         non_innate,
         &mut game.rng,
+        &game.cards,
     );
     let extra_draw = num_innate - game.draw_per_turn;
     if extra_draw > 0 {
@@ -373,7 +393,7 @@ fn setup_combat_draw_pile(game: &mut Game) {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CombatBeginGameState(pub CombatType, pub RewardType);
 
 impl GameState for CombatBeginGameState {
@@ -409,7 +429,7 @@ impl GameState for CombatBeginGameState {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct PlayerTurnBeginGameState;
 
 impl GameState for PlayerTurnBeginGameState {
@@ -421,12 +441,11 @@ impl GameState for PlayerTurnBeginGameState {
 
         let info = game.calculate_monster_info();
         for i in 0..game.monsters.len() {
-            if !game.monsters[i].creature.is_actionable() {
+            if !game.monsters[i].creature.takes_turns() {
                 continue;
             }
-            game.monsters[i]
-                .behavior
-                .roll_next_action(&mut game.rng, &info);
+            let rng = &mut game.rng;
+            game.monsters[i].update_behavior(|b, _| b.roll_next_action(rng, &info));
         }
 
         game.num_cards_played_this_turn = 0;
@@ -458,7 +477,7 @@ impl GameState for PlayerTurnBeginGameState {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct PlayerTurnGameState;
 
 impl GameState for PlayerTurnGameState {
@@ -467,17 +486,16 @@ impl GameState for PlayerTurnGameState {
             game.state.push_state(CombatEndGameState);
         }
     }
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
+    fn valid_steps(&self, game: &Game, moves: &mut Steps) -> bool {
         if game.combat_finished() {
-            return None;
+            return false;
         }
-        let mut moves = Steps::default();
         moves.push(EndTurnStep);
         for (ci, c) in game.hand.iter().enumerate() {
-            if !game.can_play_card(&PlayCardAction::new(c.clone(), None, game)) {
+            if !game.can_play_card(&PlayCardAction::new(*c, None, game)) {
                 continue;
             }
-            let c = c.borrow();
+            let c = &game.cards[c];
             if c.has_target() {
                 for (mi, m) in game.monsters.iter().enumerate() {
                     if !m.creature.is_actionable() {
@@ -519,7 +537,7 @@ impl GameState for PlayerTurnGameState {
                 }
             }
         }
-        Some(moves)
+        true
     }
 }
 
@@ -552,18 +570,17 @@ impl Step for PlayCardStep {
     }
 
     fn run(&self, game: &mut Game) {
-        let c = game.hand.remove(self.hand_index);
+        let c = game.hand.remove(self.hand_index, &game.cards);
         let action = PlayCardAction::new(c, self.target.map(CreatureRef::monster), game);
         assert!(game.can_play_card(&action));
-        game.card_queue.push(action);
+        game.card_queue.push_back(action);
         game.state.push_state(RunActionsGameState);
     }
 
     fn description(&self, game: &Game) -> String {
         let mut s = format!(
             "play card {} ({:?})",
-            self.hand_index,
-            game.hand[self.hand_index].borrow()
+            self.hand_index, &game.cards[game.hand[self.hand_index]]
         );
         if let Some(t) = self.target {
             s += &format!(
@@ -675,7 +692,7 @@ mod tests {
             .add_player_status(Status::Entangled, 1)
             .build_combat();
         let c = g.new_card(CardClass::Thunderclap);
-        g.card_queue.push(PlayCardAction::new(c, None, &g));
+        g.card_queue.push_back(PlayCardAction::new(c, None, &g));
         g.run_all_actions();
         assert_eq!(g.discard_pile.len(), 1);
         assert_eq!(g.energy, 3);
@@ -685,20 +702,20 @@ mod tests {
     fn test_free_to_play() {
         let mut g = GameBuilder::default().build_combat();
         let c = g.new_card(CardClass::Defend);
-        match &mut c.borrow_mut().cost {
+        g.update_card(c, |c| match &mut c.cost {
             CardCost::Cost {
                 free_to_play_once, ..
             } => *free_to_play_once = true,
             _ => panic!(),
-        }
-        g.hand.push(c);
+        });
+        g.hand.push(c, &g.cards);
         assert_eq!(g.energy, 3);
         g.step_test(PlayCardStep {
             hand_index: 0,
             target: None,
         });
         assert_eq!(g.energy, 3);
-        g.hand.push(g.discard_pile.pop().unwrap());
+        g.hand.push(g.discard_pile.pop(&g.cards).unwrap(), &g.cards);
         g.step_test(PlayCardStep {
             hand_index: 0,
             target: None,
@@ -707,12 +724,12 @@ mod tests {
 
         g.step_test(EndTurnStep);
         assert_eq!(g.energy, 3);
-        match &mut g.hand[0].borrow_mut().cost {
+        g.update_card(g.hand[0], |c| match &mut c.cost {
             CardCost::Cost {
                 free_to_play_once, ..
             } => *free_to_play_once = true,
             _ => panic!(),
-        }
+        });
         g.step_test(EndTurnStep);
         g.step_test(PlayCardStep {
             hand_index: 0,
@@ -897,4 +914,176 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_act_2_easy_combats() {
+        for (combat, names) in [
+            (Combat::SphericGuardian, &["spheric guardian"][..]),
+            (Combat::Chosen, &["chosen"]),
+            (Combat::ShellParasite, &["shelled parasite"]),
+            (Combat::ThreeByrds, &["byrd", "byrd", "byrd"]),
+            (Combat::TwoThieves, &["looter", "mugger"]),
+            (Combat::MaskedBandits, &["pointy", "romeo", "bear"]),
+        ] {
+            let mut g = GameBuilder::default().build_fixed_combat(combat);
+            assert_eq!(
+                g.monsters
+                    .iter()
+                    .map(|m| m.behavior.name())
+                    .collect::<Vec<_>>(),
+                names
+            );
+            // the thieves can escape on the fourth turn
+            for _ in 0..3 {
+                g.player.cur_hp = g.player.max_hp;
+                g.step_test(EndTurnStep);
+            }
+        }
+    }
+
+    #[test]
+    fn test_act_2_hard_combats() {
+        for (combat, names) in [
+            (Combat::ChosenAndByrd, &["byrd", "chosen"][..]),
+            (Combat::ChosenAndCultist, &["cultist", "chosen"]),
+            (
+                Combat::SentryAndSphericGuardian,
+                &["sentry", "spheric guardian"],
+            ),
+            (Combat::SnakePlant, &["snake plant"]),
+            (Combat::Snecko, &["snecko"]),
+            (Combat::ThreeCultists, &["cultist", "cultist", "cultist"]),
+            (
+                Combat::ShelledParasiteAndFungiBeast,
+                &["shelled parasite", "fungi beast"],
+            ),
+            (Combat::CenturionAndMystic, &["centurion", "mystic"]),
+        ] {
+            let mut g = GameBuilder::default().build_fixed_combat(combat);
+            assert_eq!(
+                g.monsters
+                    .iter()
+                    .map(|m| m.behavior.name())
+                    .collect::<Vec<_>>(),
+                names
+            );
+            g.player.max_hp = 999;
+            for _ in 0..5 {
+                g.player.cur_hp = g.player.max_hp;
+                g.step_test(EndTurnStep);
+            }
+        }
+    }
+
+    #[test]
+    fn test_act_3_shapes_and_darkling_combats() {
+        for (combat, len) in [
+            (Combat::OrbWalker, 1),
+            (Combat::ThreeShapes, 3),
+            (Combat::ThreeDarklings, 3),
+            (Combat::FourShapes, 4),
+            (Combat::SphereAndTwoShapes, 3),
+        ] {
+            let mut g = GameBuilder::default().build_fixed_combat(combat);
+            assert_eq!(g.monsters.len(), len);
+            g.player.max_hp = 999;
+            for _ in 0..5 {
+                g.player.cur_hp = g.player.max_hp;
+                g.step_test(EndTurnStep);
+            }
+        }
+    }
+
+    #[test]
+    fn test_act_3_hard_combats() {
+        for (combat, names) in [
+            (Combat::SpireGrowth, &["spire growth"][..]),
+            (Combat::Transient, &["transient"]),
+            (Combat::Maw, &["maw"]),
+            (Combat::JawWormHorde, &["jawworm", "jawworm", "jawworm"]),
+            (Combat::WrithingMass, &["writhing mass"]),
+        ] {
+            let mut g = GameBuilder::default().build_fixed_combat(combat);
+            assert_eq!(
+                g.monsters
+                    .iter()
+                    .map(|m| m.behavior.name())
+                    .collect::<Vec<_>>(),
+                names
+            );
+            g.player.max_hp = 999;
+            for _ in 0..5 {
+                g.player.cur_hp = g.player.max_hp;
+                g.step_test(EndTurnStep);
+            }
+        }
+    }
+
+    #[test]
+    fn test_jaw_worm_horde_starts_bellowed() {
+        let g = GameBuilder::default().build_fixed_combat(Combat::JawWormHorde);
+        for m in &g.monsters {
+            assert_eq!(m.creature.get_status(Status::Strength), Some(5));
+            assert_eq!(m.creature.block, 9);
+        }
+    }
+
+    #[test]
+    fn test_act_2_elite_combats() {
+        for (combat, len) in [
+            (Combat::GremlinLeader, 3),
+            (Combat::Slavers, 3),
+            (Combat::BookOfStabbing, 1),
+        ] {
+            assert_eq!(combat.ty(), CombatType::Elite);
+            let mut g = GameBuilder::default().build_fixed_combat(combat);
+            assert_eq!(g.monsters.len(), len);
+            // elites can hit harder than the starting max hp
+            g.player.max_hp = 999;
+            for _ in 0..5 {
+                g.player.cur_hp = g.player.max_hp;
+                g.step_test(EndTurnStep);
+            }
+        }
+    }
+
+    #[test]
+    fn test_act_3_elites() {
+        for (combat, len) in [
+            (Combat::GiantHead, 1),
+            (Combat::Nemesis, 1),
+            (Combat::Reptomancer, 5),
+        ] {
+            let mut g = GameBuilder::default().build_with_rooms(&[RoomType::Elite]);
+            g.elites = vec![combat];
+            g.step_test(AscendStep::new(0, 0));
+            assert_eq!(g.in_combat, CombatType::Elite);
+            assert_eq!(g.monsters.len(), len);
+            g.player.max_hp = 999;
+            for _ in 0..5 {
+                g.player.cur_hp = g.player.max_hp;
+                g.step_test(EndTurnStep);
+            }
+            // nemesis can't be killed outright while intangible
+            for m in &mut g.monsters {
+                m.creature.remove_status(Status::Intangible);
+            }
+            g.play_card(CardClass::DebugKillAll, None);
+            assert_eq!(g.rewards.relics.len(), 1);
+        }
+    }
+
+    #[test]
+    fn test_automaton_boss_rewards() {
+        let mut g =
+            GameBuilder::default().build_with_rooms(&[RoomType::Boss, RoomType::BossTreasure]);
+        g.boss = Some(Combat::Automaton);
+        g.step_test(AscendStep::new(0, 0));
+        assert_eq!(g.in_combat, CombatType::Boss);
+        assert_eq!(g.monsters[0].behavior.name(), "bronze automaton");
+        g.play_card(CardClass::DebugKillAll, None);
+        g.step_test(RewardExitStep);
+        g.step_test(AscendStep::new(0, 1));
+        assert_eq!(g.boss_rewards.len(), 3);
+    }
 }
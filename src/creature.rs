@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::VecDeque;
 
 use crate::{
     actions::{
@@ -6,23 +6,82 @@ use crate::{
         draw::DrawAction, gain_energy::GainEnergyAction, gain_status::GainStatusAction,
         heal::HealAction, magnetism::MagnetismAction, mayhem::MayhemAction,
         play_card::PlayCardAction, reduce_status::ReduceStatusAction,
-        remove_status::RemoveStatusAction,
+        remove_status::RemoveStatusAction, shuffle_card_into_draw::ShuffleCardIntoDrawAction,
+        suicide::SuicideAction,
     },
-    cards::CardType,
+    cards::{CardClass, CardType},
     game::CreatureRef,
-    queue::ActionQueue,
+    queue::{ActionQueue, Cause},
     status::Status,
+    transposition,
 };
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum CreatureState {
     #[default]
     Alive,
     Dead,
     Escaped,
+    // dead, but will come back unless the rest of its group dies first
+    Reviving,
 }
 
-#[derive(Default)]
+// amount of every status, indexed by the status, with the order they were
+// gained in, which is the order they trigger in, and a hash of them that is
+// kept up to date as they change
+#[derive(Clone)]
+struct Statuses {
+    amounts: [Option<i32>; Status::COUNT],
+    order: Vec<Status>,
+    hash: u64,
+}
+
+impl Default for Statuses {
+    fn default() -> Self {
+        Self {
+            amounts: [None; Status::COUNT],
+            order: Vec::new(),
+            hash: 0,
+        }
+    }
+}
+
+impl Statuses {
+    fn key(status: Status, amount: i32) -> u64 {
+        transposition::mix(((status as u64) << 32) | amount as u32 as u64)
+    }
+    fn get(&self, status: Status) -> Option<i32> {
+        self.amounts[status as usize]
+    }
+    fn iter(&self) -> impl Iterator<Item = (Status, i32)> + '_ {
+        self.order.iter().map(|&s| (s, self.get(s).unwrap()))
+    }
+    fn set(&mut self, status: Status, amount: i32) {
+        match self.amounts[status as usize].replace(amount) {
+            Some(old) => self.hash ^= Self::key(status, old),
+            None => self.order.push(status),
+        }
+        self.hash ^= Self::key(status, amount);
+    }
+    fn remove(&mut self, status: Status) {
+        if let Some(old) = self.amounts[status as usize].take() {
+            self.hash ^= Self::key(status, old);
+            self.order.retain(|&s| s != status);
+        }
+    }
+    // `new` takes the place of `old`, which must be there and `new` not
+    fn replace(&mut self, old: Status, new: Status, amount: i32) {
+        let i = self.order.iter().position(|&s| s == old).unwrap();
+        let old_amount = self.amounts[old as usize].take().unwrap();
+        self.hash ^= Self::key(old, old_amount) ^ Self::key(new, amount);
+        self.amounts[new as usize] = Some(amount);
+        self.order[i] = new;
+    }
+}
+
+// `hash` covers hp, block and state, so whatever changes those calls
+// `rehash` after
+#[derive(Default, Clone)]
 pub struct Creature {
     pub name: &'static str,
     pub max_hp: i32,
@@ -30,12 +89,15 @@ pub struct Creature {
     pub block: i32,
     pub last_damage_taken: i32,
     pub state: CreatureState,
-    statuses: HashMap<Status, i32>,
+    statuses: Statuses,
+    // how much Malleable was gained, which it goes back to every round
+    malleable_base: i32,
+    hash: u64,
 }
 
 impl Creature {
     pub fn new(name: &'static str, max_hp: i32) -> Self {
-        Self {
+        let mut c = Self {
             name,
             max_hp,
             cur_hp: max_hp,
@@ -43,13 +105,33 @@ impl Creature {
             statuses: Default::default(),
             state: Default::default(),
             last_damage_taken: 0,
-        }
+            malleable_base: 0,
+            hash: 0,
+        };
+        c.rehash();
+        c
+    }
+
+    // must be called after changing hp, block or state
+    pub fn rehash(&mut self) {
+        self.hash = transposition::key(&(
+            self.name,
+            self.max_hp,
+            self.cur_hp,
+            self.block,
+            self.state,
+            self.malleable_base,
+        ));
     }
 
     pub fn is_actionable(&self) -> bool {
         matches!(self.state, CreatureState::Alive)
     }
 
+    pub fn takes_turns(&self) -> bool {
+        matches!(self.state, CreatureState::Alive | CreatureState::Reviving)
+    }
+
     pub fn is_bloodied(&self) -> bool {
         self.cur_hp <= (self.max_hp + 1) / 2
     }
@@ -59,45 +141,97 @@ impl Creature {
         if self.cur_hp > self.max_hp {
             self.cur_hp = self.max_hp;
         }
+        self.rehash();
     }
 
     pub fn increase_max_hp(&mut self, amount: i32) {
         self.max_hp += amount;
+        self.rehash();
     }
 
     pub fn decrease_max_hp(&mut self, amount: i32) {
         self.max_hp -= amount;
         self.cur_hp = self.cur_hp.min(self.max_hp);
+        self.rehash();
     }
 
     pub fn has_any_status(&self) -> bool {
-        !self.statuses.is_empty()
+        !self.statuses.order.is_empty()
     }
 
     pub fn has_status(&self, status: Status) -> bool {
-        self.statuses.contains_key(&status)
+        self.get_status(status).is_some()
     }
 
     pub fn get_status(&self, status: Status) -> Option<i32> {
-        self.statuses.get(&status).copied()
+        self.statuses.get(status)
     }
 
     pub fn remove_status(&mut self, status: Status) {
-        self.statuses.remove(&status);
+        self.statuses.remove(status);
+        if status == Status::Malleable {
+            self.malleable_base = 0;
+            self.rehash();
+        }
     }
 
     pub fn clear_all_status(&mut self) {
-        self.statuses.clear();
+        self.statuses = Default::default();
+        self.malleable_base = 0;
+        self.rehash();
+    }
+
+    // only gaining Malleable raises what it resets to, not the hits on it
+    pub fn gain_malleable(&mut self, amount: i32) {
+        self.malleable_base += amount;
+        self.rehash();
     }
 
+    // a status that is already there keeps its place in the trigger order
     pub fn set_status(&mut self, status: Status, amount: i32) {
-        self.statuses.insert(status, amount);
+        self.statuses.set(status, amount);
     }
 
-    pub fn all_statuses(&self) -> std::collections::hash_map::Iter<'_, Status, i32> {
+    // for statuses that count down by becoming another status, like bombs,
+    // which still trigger where the old status did
+    fn replace_status(&mut self, old: Status, new: Status, amount: i32) {
+        if self.has_status(new) {
+            self.set_status(new, amount);
+            self.remove_status(old);
+        } else if self.has_status(old) {
+            self.statuses.replace(old, new, amount);
+        }
+    }
+
+    pub fn all_statuses(&self) -> impl Iterator<Item = (Status, i32)> + '_ {
         self.statuses.iter()
     }
 
+    // what matters about the creature for the rest of the combat, see
+    // `Game::state_hash`
+    pub fn state_hash(&self) -> u64 {
+        self.hash ^ self.statuses.hash
+    }
+
+    // `state_hash` worked out from scratch, which it should always match
+    #[cfg(test)]
+    pub fn recomputed_state_hash(&self) -> u64 {
+        let mut fresh = self.clone();
+        fresh.rehash();
+        let statuses = self.statuses.iter();
+        statuses.fold(fresh.hash, |h, (s, v)| h ^ Statuses::key(s, v))
+    }
+
+    pub fn state_eq(&self, other: &Creature) -> bool {
+        self.name == other.name
+            && self.max_hp == other.max_hp
+            && self.cur_hp == other.cur_hp
+            && self.block == other.block
+            && self.state == other.state
+            && self.malleable_base == other.malleable_base
+            && self.statuses.iter().eq(other.statuses.iter())
+    }
+
     pub fn start_of_turn_lose_block(&mut self, has_calipers: bool) {
         if self.has_status(Status::Barricade) {
             return;
@@ -107,12 +241,13 @@ impl Creature {
         } else {
             self.block = 0;
         }
+        self.rehash();
     }
 
     pub fn trigger_statuses_on_card_played(
         &mut self,
         queue: &mut ActionQueue,
-        card_queue: &mut Vec<PlayCardAction>,
+        card_queue: &mut VecDeque<PlayCardAction>,
         play: &PlayCardAction,
     ) {
         for (p, p_next) in [
@@ -123,14 +258,23 @@ impl Creature {
             (Status::Panache1, Status::Panache5),
         ] {
             if let Some(v) = self.get_status(p) {
-                self.set_status(p_next, v);
-                self.remove_status(p);
+                self.replace_status(p, p_next, v);
                 if p == Status::Panache1 {
                     queue.push_bot(DamageAllMonstersAction::thorns(v));
                 }
                 break;
             }
         }
+        if let Some(v) = self.get_status(Status::Hex)
+            && play.class.ty() != CardType::Attack
+        {
+            for _ in 0..v {
+                queue.push_bot(ShuffleCardIntoDrawAction {
+                    class: CardClass::Dazed,
+                    is_free: false,
+                });
+            }
+        }
         if !play.is_duplicated {
             if self.has_status(Status::Duplication) {
                 queue.push_bot(ReduceStatusAction {
@@ -138,19 +282,17 @@ impl Creature {
                     amount: 1,
                     target: CreatureRef::player(),
                 });
-                card_queue.push(PlayCardAction::duplicated(play));
+                card_queue.push_back(PlayCardAction::duplicated(play, queue.current_parent()));
             }
-            if self.has_status(Status::DoubleTap)
-                && play.card.borrow().class.ty() == CardType::Attack
-            {
+            if self.has_status(Status::DoubleTap) && play.class.ty() == CardType::Attack {
                 queue.push_bot(ReduceStatusAction {
                     status: Status::DoubleTap,
                     amount: 1,
                     target: CreatureRef::player(),
                 });
-                card_queue.push(PlayCardAction::duplicated(play));
+                card_queue.push_back(PlayCardAction::duplicated(play, queue.current_parent()));
             }
-            if play.card.borrow().class.ty() == CardType::Attack {
+            if play.class.ty() == CardType::Attack {
                 if self.has_status(Status::PenNib) {
                     queue.push_bot(RemoveStatusAction {
                         status: Status::PenNib,
@@ -165,7 +307,7 @@ impl Creature {
                 }
             }
             if let Some(v) = self.get_status(Status::Rage)
-                && play.card.borrow().class.ty() == CardType::Attack
+                && play.class.ty() == CardType::Attack
             {
                 queue.push_bot(BlockAction::player_flat_amount(v));
             }
@@ -173,33 +315,49 @@ impl Creature {
     }
 
     pub fn trigger_statuses_turn_begin(&mut self, this: CreatureRef, queue: &mut ActionQueue) {
-        if let Some(v) = self.get_status(Status::Magnetism) {
-            for _ in 0..v {
-                queue.push_bot(MagnetismAction());
-            }
-        }
-        if let Some(v) = self.get_status(Status::Mayhem) {
-            for _ in 0..v {
-                queue.push_bot(MayhemAction());
-            }
-        }
-        if let Some(v) = self.get_status(Status::Berserk) {
-            queue.push_bot(GainEnergyAction(v));
-        }
-        if let Some(v) = self.get_status(Status::NextTurnBlock) {
-            queue.push_bot(BlockAction::player_flat_amount(v));
-            queue.push_bot(RemoveStatusAction {
-                status: Status::NextTurnBlock,
-                target: this,
-            });
-        }
-        for status in [Status::NextTurnBlock, Status::FlameBarrier] {
-            if self.has_status(status) {
-                queue.push_bot(RemoveStatusAction {
+        for (status, v) in self.all_statuses().collect::<Vec<_>>() {
+            queue.with_cause(Cause::Status(this, status), |queue| match status {
+                Status::Magnetism => {
+                    for _ in 0..v {
+                        queue.push_bot(MagnetismAction());
+                    }
+                }
+                Status::Mayhem => {
+                    for _ in 0..v {
+                        queue.push_bot(MayhemAction());
+                    }
+                }
+                Status::Berserk => queue.push_bot(GainEnergyAction(v)),
+                Status::NextTurnBlock => {
+                    queue.push_bot(BlockAction::player_flat_amount(v));
+                    queue.push_bot(RemoveStatusAction {
+                        status,
+                        target: this,
+                    });
+                }
+                Status::FlameBarrier => queue.push_bot(RemoveStatusAction {
                     status,
                     target: this,
-                });
-            }
+                }),
+                Status::Explosive | Status::Fading => {
+                    if v == 1 {
+                        if status == Status::Explosive {
+                            queue.push_bot(DamageAction::thorns_no_rupture(
+                                30,
+                                CreatureRef::player(),
+                            ));
+                        }
+                        queue.push_bot(SuicideAction(this));
+                    } else {
+                        queue.push_bot(ReduceStatusAction {
+                            status,
+                            amount: 1,
+                            target: this,
+                        });
+                    }
+                }
+                _ => {}
+            });
         }
     }
 
@@ -208,136 +366,127 @@ impl Creature {
         this: CreatureRef,
         queue: &mut ActionQueue,
     ) {
-        if let Some(v) = self.get_status(Status::DemonForm) {
-            queue.push_bot(GainStatusAction {
-                status: Status::Strength,
-                amount: v,
-                target: this,
+        for (status, v) in self.all_statuses().collect::<Vec<_>>() {
+            queue.with_cause(Cause::Status(this, status), |queue| match status {
+                Status::DemonForm => queue.push_bot(GainStatusAction {
+                    status: Status::Strength,
+                    amount: v,
+                    target: this,
+                }),
+                Status::Brutality => {
+                    queue.push_bot(DrawAction(v));
+                    queue.push_bot(DamageAction::lose_hp(v, this));
+                }
+                _ => {}
             });
         }
-        if let Some(v) = self.get_status(Status::Brutality) {
-            queue.push_bot(DrawAction(v));
-            queue.push_bot(DamageAction::lose_hp(v, this));
-        }
     }
 
     pub fn trigger_statuses_turn_end(&mut self, this: CreatureRef, queue: &mut ActionQueue) {
-        if let Some(v) = self.get_status(Status::LoseDexterity) {
-            queue.push_bot(GainStatusAction {
-                status: Status::Dexterity,
-                amount: -v,
-                target: this,
-            });
-            queue.push_bot(RemoveStatusAction {
-                status: Status::LoseDexterity,
-                target: this,
-            });
-        }
-        if let Some(v) = self.get_status(Status::LoseStrength) {
-            queue.push_bot(GainStatusAction {
-                status: Status::Strength,
-                amount: -v,
-                target: this,
-            });
-            queue.push_bot(RemoveStatusAction {
-                status: Status::LoseStrength,
-                target: this,
-            });
-        }
-        if let Some(v) = self.get_status(Status::Ritual) {
-            queue.push_bot(GainStatusAction {
-                status: Status::Strength,
-                amount: v,
-                target: this,
-            });
-        }
-        if let Some(v) = self.get_status(Status::GainStrength) {
-            queue.push_bot(GainStatusAction {
-                status: Status::Strength,
-                amount: v,
-                target: this,
-            });
-            queue.push_bot(RemoveStatusAction {
-                status: Status::GainStrength,
-                target: this,
-            });
-        }
-        if let Some(v) = self.get_status(Status::Metallicize) {
-            queue.push_bot(BlockAction::monster(this, v));
-        }
-        if let Some(v) = self.get_status(Status::PlatedArmor) {
-            queue.push_bot(BlockAction::monster(this, v));
-        }
-        if let Some(v) = self.get_status(Status::CombustHPLoss) {
-            queue.push_bot(DamageAction::lose_hp(v, this));
-        }
-        if let Some(v) = self.get_status(Status::CombustDamage) {
-            queue.push_bot(DamageAllMonstersAction::thorns(v));
-        }
-        if let Some(b) = self.get_status(Status::Bomb1) {
-            queue.push_bot(DamageAllMonstersAction::thorns(b));
-            self.remove_status(Status::Bomb1);
-        }
-        if let Some(b) = self.get_status(Status::Bomb2) {
-            self.set_status(Status::Bomb1, b);
-            self.remove_status(Status::Bomb2);
-        }
-        if let Some(b) = self.get_status(Status::Bomb3) {
-            self.set_status(Status::Bomb2, b);
-            self.remove_status(Status::Bomb3);
-        }
-        if let Some(v) = self.get_status(Status::RegenPlayer) {
-            // yes, this is push_top
-            queue.push_top(HealAction {
-                target: this,
-                amount: v,
-            });
-            queue.push_top(ReduceStatusAction {
-                status: Status::RegenPlayer,
-                amount: 1,
-                target: this,
-            });
-        }
-        if let Some(v) = self.get_status(Status::RegenMonster) {
-            queue.push_bot(HealAction {
-                target: this,
-                amount: v,
-            });
-        }
-        for p in [
-            Status::Panache4,
-            Status::Panache3,
-            Status::Panache2,
-            Status::Panache1,
-        ] {
-            if let Some(v) = self.get_status(p) {
-                self.set_status(Status::Panache5, v);
-                self.remove_status(p);
-                break;
-            }
-        }
-        if self.has_status(Status::Rage) {
-            queue.push_bot(RemoveStatusAction {
-                status: Status::Rage,
-                target: this,
+        let mut bombs_counted_down = false;
+        for (status, v) in self.all_statuses().collect::<Vec<_>>() {
+            queue.with_cause(Cause::Status(this, status), |queue| match status {
+                Status::LoseDexterity | Status::LoseStrength => {
+                    queue.push_bot(GainStatusAction {
+                        status: if status == Status::LoseDexterity {
+                            Status::Dexterity
+                        } else {
+                            Status::Strength
+                        },
+                        amount: -v,
+                        target: this,
+                    });
+                    queue.push_bot(RemoveStatusAction {
+                        status,
+                        target: this,
+                    });
+                }
+                Status::Ritual | Status::StrengthUp => queue.push_bot(GainStatusAction {
+                    status: Status::Strength,
+                    amount: v,
+                    target: this,
+                }),
+                Status::GainStrength => {
+                    queue.push_bot(GainStatusAction {
+                        status: Status::Strength,
+                        amount: v,
+                        target: this,
+                    });
+                    queue.push_bot(RemoveStatusAction {
+                        status,
+                        target: this,
+                    });
+                }
+                Status::Metallicize | Status::PlatedArmor => {
+                    queue.push_bot(BlockAction::monster(this, v))
+                }
+                Status::Slow => self.set_status(status, 0),
+                Status::CombustHPLoss => queue.push_bot(DamageAction::lose_hp(v, this)),
+                Status::Constricted => queue.push_bot(DamageAction::thorns_no_rupture(v, this)),
+                Status::CombustDamage => queue.push_bot(DamageAllMonstersAction::thorns(v)),
+                // bombs share one countdown so a bomb never moves onto one
+                // that hasn't counted down yet
+                Status::Bomb1 | Status::Bomb2 | Status::Bomb3 if !bombs_counted_down => {
+                    bombs_counted_down = true;
+                    if let Some(b) = self.get_status(Status::Bomb1) {
+                        queue.push_bot(DamageAllMonstersAction::thorns(b));
+                        self.remove_status(Status::Bomb1);
+                    }
+                    if let Some(b) = self.get_status(Status::Bomb2) {
+                        self.replace_status(Status::Bomb2, Status::Bomb1, b);
+                    }
+                    if let Some(b) = self.get_status(Status::Bomb3) {
+                        self.replace_status(Status::Bomb3, Status::Bomb2, b);
+                    }
+                }
+                Status::RegenPlayer => {
+                    // yes, this is push_top
+                    queue.push_top(HealAction {
+                        target: this,
+                        amount: v,
+                    });
+                    queue.push_top(ReduceStatusAction {
+                        status,
+                        amount: 1,
+                        target: this,
+                    });
+                }
+                Status::RegenMonster => queue.push_bot(HealAction {
+                    target: this,
+                    amount: v,
+                }),
+                Status::Panache4 | Status::Panache3 | Status::Panache2 | Status::Panache1 => {
+                    self.replace_status(status, Status::Panache5, v)
+                }
+                Status::Rage => queue.push_bot(RemoveStatusAction {
+                    status,
+                    target: this,
+                }),
+                _ => {}
             });
         }
     }
+
     pub fn trigger_statuses_round_end(&mut self, this: CreatureRef, queue: &mut ActionQueue) {
-        for s in self.statuses.keys() {
-            if s.decays() {
-                queue.push_bot(ReduceStatusAction {
-                    status: *s,
-                    amount: 1,
-                    target: this,
-                });
-            }
-            if s.disappears_end_of_turn() {
-                queue.push_bot(RemoveStatusAction {
-                    status: *s,
-                    target: this,
-                });
-            }
+        if self.has_status(Status::Malleable) {
+            self.set_status(Status::Malleable, self.malleable_base);
+        }
+        for (s, _) in self.all_statuses() {
+            queue.with_cause(Cause::Status(this, s), |queue| {
+                if s.decays() {
+                    queue.push_bot(ReduceStatusAction {
+                        status: s,
+                        amount: 1,
+                        target: this,
+                    });
+                }
+                if s.disappears_end_of_turn() {
+                    queue.push_bot(RemoveStatusAction {
+                        status: s,
+                        target: this,
+                    });
+                }
+            });
         }
     }
 
@@ -361,3 +510,79 @@ impl Creature {
         s
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Creature;
+    use crate::{game::CreatureRef, queue::ActionQueue, status::Status};
+
+    fn turn_end_actions(c: &mut Creature) -> Vec<String> {
+        let mut queue = ActionQueue::default();
+        c.trigger_statuses_turn_end(CreatureRef::player(), &mut queue);
+        std::iter::from_fn(|| queue.pop())
+            .map(|a| format!("{a:?}"))
+            .collect()
+    }
+
+    #[test]
+    fn test_status_order() {
+        let mut c = Creature::new("test", 10);
+        c.set_status(Status::Weak, 1);
+        c.set_status(Status::Strength, 2);
+        c.set_status(Status::Artifact, 3);
+        c.set_status(Status::Weak, 4);
+        c.remove_status(Status::Strength);
+        c.set_status(Status::Strength, 5);
+        assert_eq!(
+            c.all_statuses().collect::<Vec<_>>(),
+            vec![
+                (Status::Weak, 4),
+                (Status::Artifact, 3),
+                (Status::Strength, 5)
+            ]
+        );
+        assert_eq!(
+            c.str(),
+            "test: 10/10, 0 block, statuses: Weak (4), Artifact (3), Strength (5)"
+        );
+    }
+
+    #[test]
+    fn test_turn_end_order() {
+        let mut c = Creature::new("test", 10);
+        c.set_status(Status::CombustHPLoss, 1);
+        c.set_status(Status::Metallicize, 3);
+        let combust_first = turn_end_actions(&mut c);
+        c.clear_all_status();
+        c.set_status(Status::Metallicize, 3);
+        c.set_status(Status::CombustHPLoss, 1);
+        let metallicize_first = turn_end_actions(&mut c);
+        assert_eq!(combust_first.len(), 2);
+        assert_eq!(combust_first[0], metallicize_first[1]);
+        assert_eq!(combust_first[1], metallicize_first[0]);
+        assert!(combust_first[0].starts_with("damage"));
+    }
+
+    #[test]
+    fn test_bomb_keeps_place() {
+        let mut c = Creature::new("test", 10);
+        c.set_status(Status::Bomb3, 40);
+        c.set_status(Status::Metallicize, 3);
+        c.set_status(Status::Bomb3, 50);
+        turn_end_actions(&mut c);
+        assert_eq!(
+            c.all_statuses().collect::<Vec<_>>(),
+            vec![(Status::Bomb2, 50), (Status::Metallicize, 3)]
+        );
+        c.set_status(Status::Bomb3, 10);
+        turn_end_actions(&mut c);
+        assert_eq!(
+            c.all_statuses().collect::<Vec<_>>(),
+            vec![
+                (Status::Bomb1, 50),
+                (Status::Metallicize, 3),
+                (Status::Bomb2, 10)
+            ]
+        );
+    }
+}
@@ -0,0 +1,351 @@
+use std::fmt;
+
+use crate::{
+    actions::damage::DamageType,
+    game::{CreatureRef, Game},
+    relic::RelicClass,
+    status::Status,
+};
+
+// What changed an amount of damage on its way from the card or intent to the
+// target's hp.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DamageSource {
+    Status(Status),
+    Relic(RelicClass),
+    Block,
+    // damage beyond the target's hp doesn't count
+    RemainingHp,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DamageOp {
+    Add(i32),
+    Multiply(f32),
+    // at most this much
+    Cap(i32),
+    // exactly this much
+    Set(i32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DamageStep {
+    pub source: DamageSource,
+    pub op: DamageOp,
+    // the damage after this step
+    pub value: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DamageBreakdown {
+    pub base: i32,
+    // the attacker's and target's statuses, only for attacks
+    pub modifiers: Vec<DamageStep>,
+    // after `modifiers`, rounded down, as shown on intents
+    pub calculated: i32,
+    // block, Intangible, Buffer and relics, in the order they apply
+    pub reductions: Vec<DamageStep>,
+    pub hp_lost: i32,
+}
+
+impl fmt::Display for DamageSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DamageSource::Status(s) => write!(f, "{s:?}"),
+            DamageSource::Relic(r) => write!(f, "{r:?}"),
+            DamageSource::Block => f.write_str("block"),
+            DamageSource::RemainingHp => f.write_str("hp"),
+        }
+    }
+}
+
+impl fmt::Display for DamageOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DamageOp::Add(v) => write!(f, "{v:+}"),
+            DamageOp::Multiply(v) => write!(f, "x{v}"),
+            DamageOp::Cap(v) => write!(f, "max {v}"),
+            DamageOp::Set(v) => write!(f, "={v}"),
+        }
+    }
+}
+
+// e.g. "6 base +3 Strength x1.5 Vulnerable = 13, -5 block = 8"
+impl fmt::Display for DamageBreakdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} base", self.base)?;
+        for s in &self.modifiers {
+            write!(f, " {} {}", s.op, s.source)?;
+        }
+        write!(f, " = {}", self.calculated)?;
+        if !self.reductions.is_empty() {
+            for (i, s) in self.reductions.iter().enumerate() {
+                let sep = if i == 0 { "," } else { "" };
+                write!(f, "{sep} {} {}", s.op, s.source)?;
+            }
+            write!(f, " = {}", self.hp_lost)?;
+        }
+        Ok(())
+    }
+}
+
+// How damage that got through is dealt with by `Game::damage`.
+pub(crate) struct DamageTaken {
+    pub blocked: i32,
+    // Buffer prevented it
+    pub buffered: bool,
+    pub hp_lost: i32,
+}
+
+impl Game {
+    pub(crate) fn calculate_damage_with<F: FnMut(DamageStep)>(
+        &self,
+        amount: i32,
+        source_ref: CreatureRef,
+        target_ref: CreatureRef,
+        mut note: F,
+    ) -> i32 {
+        let mut amount_f = amount as f32;
+        let mut apply = |source, op| {
+            match op {
+                DamageOp::Add(v) => amount_f += v as f32,
+                DamageOp::Multiply(v) => amount_f *= v,
+                DamageOp::Cap(_) | DamageOp::Set(_) => unreachable!(),
+            }
+            note(DamageStep {
+                source,
+                op,
+                value: amount_f,
+            });
+        };
+        let source = self.get_creature(source_ref);
+        let target = self.get_creature(target_ref);
+        if let Some(s) = source.get_status(Status::Strength) {
+            apply(DamageSource::Status(Status::Strength), DamageOp::Add(s));
+        }
+        if let Some(s) = source.get_status(Status::Vigor) {
+            apply(DamageSource::Status(Status::Vigor), DamageOp::Add(s));
+        }
+        if source.has_status(Status::Weak) {
+            apply(DamageSource::Status(Status::Weak), DamageOp::Multiply(0.75));
+        }
+        if source.has_status(Status::PenNib) {
+            apply(
+                DamageSource::Status(Status::PenNib),
+                DamageOp::Multiply(2.0),
+            );
+        }
+        if target.has_status(Status::Vulnerable) {
+            let m = if target_ref.is_player() && self.has_relic(RelicClass::OddMushroom) {
+                1.25
+            } else if !target_ref.is_player() && self.has_relic(RelicClass::PaperPhrog) {
+                1.75
+            } else {
+                1.5
+            };
+            apply(
+                DamageSource::Status(Status::Vulnerable),
+                DamageOp::Multiply(m),
+            );
+        }
+        if let Some(v) = target.get_status(Status::Slow) {
+            apply(
+                DamageSource::Status(Status::Slow),
+                DamageOp::Multiply(1.0 + 0.1 * v as f32),
+            );
+        }
+        if target.has_status(Status::Flight) {
+            apply(
+                DamageSource::Status(Status::Flight),
+                DamageOp::Multiply(0.5),
+            );
+        }
+        0.max(amount_f as i32)
+    }
+
+    // The target's side of `Game::damage`, without changing anything.
+    pub(crate) fn damage_taken_with<F: FnMut(DamageStep)>(
+        &self,
+        target: CreatureRef,
+        mut amount: i32,
+        ty: DamageType,
+        mut note: F,
+    ) -> DamageTaken {
+        let mut step = |amount: i32, source, op| {
+            note(DamageStep {
+                source,
+                op,
+                value: amount as f32,
+            })
+        };
+        let c = self.get_creature(target);
+        let mut blocked = 0;
+        let mut buffered = false;
+        if c.has_status(Status::Intangible) {
+            amount = amount.min(1);
+            step(
+                amount,
+                DamageSource::Status(Status::Intangible),
+                DamageOp::Cap(1),
+            );
+        }
+        if ty != DamageType::HPLoss && c.block != 0 {
+            blocked = c.block.min(amount);
+            amount -= blocked;
+            step(amount, DamageSource::Block, DamageOp::Add(-blocked));
+        }
+        if amount > c.cur_hp {
+            amount = c.cur_hp;
+            step(amount, DamageSource::RemainingHp, DamageOp::Cap(c.cur_hp));
+        }
+        if amount != 0 && c.has_status(Status::Buffer) {
+            amount = 0;
+            buffered = true;
+            step(
+                amount,
+                DamageSource::Status(Status::Buffer),
+                DamageOp::Set(0),
+            );
+        }
+        if target.is_player()
+            && (1..=5).contains(&amount)
+            && matches!(ty, DamageType::Attack { .. })
+            && self.has_relic(RelicClass::Torii)
+        {
+            amount = 1;
+            step(
+                amount,
+                DamageSource::Relic(RelicClass::Torii),
+                DamageOp::Set(1),
+            );
+        }
+        if target.is_player() && amount > 0 && self.has_relic(RelicClass::TungstenRod) {
+            amount -= 1;
+            step(
+                amount,
+                DamageSource::Relic(RelicClass::TungstenRod),
+                DamageOp::Add(-1),
+            );
+        }
+        if !target.is_player() && amount > 0 && amount < 5 && self.has_relic(RelicClass::Boot) {
+            amount = 5;
+            step(
+                amount,
+                DamageSource::Relic(RelicClass::Boot),
+                DamageOp::Set(5),
+            );
+        }
+        DamageTaken {
+            blocked,
+            buffered,
+            hp_lost: amount,
+        }
+    }
+
+    // How `amount` damage of type `ty` would hurt `target` right now, step by
+    // step.
+    pub fn damage_breakdown(
+        &self,
+        target: CreatureRef,
+        amount: i32,
+        ty: DamageType,
+    ) -> DamageBreakdown {
+        let mut modifiers = Vec::new();
+        let calculated = match ty {
+            DamageType::Attack { source, .. } => {
+                self.calculate_damage_with(amount, source, target, |s| modifiers.push(s))
+            }
+            _ => amount,
+        };
+        let mut reductions = Vec::new();
+        let taken = self.damage_taken_with(target, calculated, ty, |s| reductions.push(s));
+        DamageBreakdown {
+            base: amount,
+            modifiers,
+            calculated,
+            reductions,
+            hp_lost: taken.hp_lost,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{actions::damage::DamageAction, game::GameBuilder, monsters::test::NoopMonster};
+
+    fn attack() -> DamageType {
+        DamageType::Attack {
+            source: CreatureRef::player(),
+            on_fatal: None,
+        }
+    }
+
+    #[test]
+    fn test_modifiers() {
+        let mut g = GameBuilder::default()
+            .add_player_status(Status::Strength, 3)
+            .add_player_status(Status::Weak, 1)
+            .build_combat_with_monster(NoopMonster::new());
+        g.monsters[0].creature.set_status(Status::Vulnerable, 1);
+        g.monsters[0].creature.block = 5;
+        let b = g.damage_breakdown(CreatureRef::monster(0), 6, attack());
+        assert_eq!(
+            b.modifiers,
+            vec![
+                DamageStep {
+                    source: DamageSource::Status(Status::Strength),
+                    op: DamageOp::Add(3),
+                    value: 9.0,
+                },
+                DamageStep {
+                    source: DamageSource::Status(Status::Weak),
+                    op: DamageOp::Multiply(0.75),
+                    value: 6.75,
+                },
+                DamageStep {
+                    source: DamageSource::Status(Status::Vulnerable),
+                    op: DamageOp::Multiply(1.5),
+                    value: 10.125,
+                },
+            ]
+        );
+        assert_eq!(b.calculated, 10);
+        assert_eq!(
+            b.calculated,
+            g.calculate_damage(6, CreatureRef::player(), CreatureRef::monster(0))
+        );
+        assert_eq!(b.hp_lost, 5);
+        assert_eq!(
+            b.to_string(),
+            "6 base +3 Strength x0.75 Weak x1.5 Vulnerable = 10, -5 block = 5"
+        );
+    }
+
+    #[test]
+    fn test_reductions() {
+        let mut g = GameBuilder::default()
+            .add_relic(RelicClass::Torii)
+            .add_relic(RelicClass::TungstenRod)
+            .build_combat_with_monster(NoopMonster::new());
+        g.player.block = 4;
+        let monster_attack = DamageType::Attack {
+            source: CreatureRef::monster(0),
+            on_fatal: None,
+        };
+        let b = g.damage_breakdown(CreatureRef::player(), 8, monster_attack);
+        assert!(b.modifiers.is_empty());
+        assert_eq!(
+            b.to_string(),
+            "8 base = 8, -4 block =1 Torii -1 TungstenRod = 0"
+        );
+        let hp = g.player.cur_hp;
+        g.run_action(DamageAction::from_monster(8, CreatureRef::monster(0)));
+        assert_eq!(g.player.cur_hp, hp - b.hp_lost);
+
+        let b = g.damage_breakdown(CreatureRef::player(), 100, DamageType::HPLoss);
+        assert_eq!(b.reductions.len(), 2);
+        assert_eq!(b.reductions[0].source, DamageSource::RemainingHp);
+        assert_eq!(b.hp_lost, g.player.cur_hp - 1);
+    }
+}
@@ -1,21 +1,28 @@
 use crate::{game::Rand, rng::rand_slice};
 
-use petgraph::visit::EdgeRef;
-
 use rand::{RngExt, seq::SliceRandom};
 
+use std::collections::BTreeMap;
 use std::fmt::Debug;
 
+#[derive(Clone)]
 pub struct DrawPile<T: Debug>(DrawPileEnum<T>);
 
+#[derive(Clone)]
 enum DrawPileEnum<T: Debug> {
     Normal(DrawPileImpl<T>),
     FrozenEye(FrozenEyeDrawPileImpl<T>),
 }
 
+impl<T: Debug> Default for DrawPileEnum<T> {
+    fn default() -> Self {
+        Self::Normal(Default::default())
+    }
+}
+
 impl<T: Debug> Default for DrawPile<T> {
     fn default() -> Self {
-        Self(DrawPileEnum::Normal(Default::default()))
+        Self(Default::default())
     }
 }
 
@@ -106,8 +113,166 @@ impl<T: Debug> DrawPile<T> {
             DrawPileEnum::FrozenEye(d) => d.pop(),
         }
     }
+    // A random order the whole pile could be drawn in, as indexes into
+    // get_all() with the next card first. Orders are as likely as popping the
+    // cards one by one would produce them.
+    pub fn sample_order(&self, rng: &mut Rand) -> Vec<usize> {
+        match &self.0 {
+            DrawPileEnum::Normal(d) => d.sample_order(rng),
+            DrawPileEnum::FrozenEye(d) => (0..d.len()).rev().collect(),
+        }
+    }
+    // Fixes the draw order (as returned by sample_order()). A pile with a known
+    // order behaves exactly like one with frozen eye from then on.
+    pub fn install_order(&mut self, order: &[usize]) {
+        assert_eq!(order.len(), self.len());
+        let values = match std::mem::take(&mut self.0) {
+            DrawPileEnum::Normal(d) => d.into_values(),
+            DrawPileEnum::FrozenEye(d) => d.0,
+        };
+        let mut values = values.into_iter().map(Some).collect::<Vec<_>>();
+        let ordered = order
+            .iter()
+            .rev()
+            .map(|&i| values[i].take().unwrap())
+            .collect();
+        self.0 = DrawPileEnum::FrozenEye(FrozenEyeDrawPileImpl(ordered));
+    }
+    // The pile as runs of cards that can be swapped with each other without
+    // changing how the pile can be drawn, with the keys in each run sorted.
+    // Piles with equal runs can be drawn the same ways.
+    pub fn interchangeable_runs<K: Ord, F: Fn(&T) -> K>(&self, key: F) -> Vec<DrawPileRun<K>> {
+        match &self.0 {
+            DrawPileEnum::Normal(d) => d.interchangeable_runs(key),
+            DrawPileEnum::FrozenEye(d) => {
+                d.0.iter()
+                    .map(|t| DrawPileRun {
+                        kind: None,
+                        keys: vec![key(t)],
+                    })
+                    .collect()
+            }
+        }
+    }
+    fn constraint_view<K: Ord + Clone, F: Fn(&T) -> K>(&self, key: F) -> ConstraintView<K> {
+        match &self.0 {
+            DrawPileEnum::Normal(d) => d.constraint_view(key),
+            DrawPileEnum::FrozenEye(d) => d.constraint_view(key),
+        }
+    }
+    // Distribution of the keys of the next n cards drawn from this pile (at
+    // most the whole pile), as (count per key, probability) pairs.
+    pub fn draw_distribution<K: Ord + Clone, F: Fn(&T) -> K>(
+        &self,
+        n: usize,
+        key: F,
+    ) -> Vec<(BTreeMap<K, usize>, f64)> {
+        self.constraint_view(key)
+            .key_distribution(n.min(self.len()))
+    }
+    // Probability of drawing exactly k matching cards in the next n draws, for
+    // each k.
+    pub fn count_distribution<F: Fn(&T) -> bool>(&self, n: usize, pred: F) -> Vec<f64> {
+        let n = n.min(self.len());
+        let mut ret = vec![0.0; n + 1];
+        for (counts, p) in self.constraint_view(pred).key_distribution(n) {
+            ret[counts.get(&true).copied().unwrap_or(0)] += p;
+        }
+        ret
+    }
+    pub fn probability_any<F: Fn(&T) -> bool>(&self, n: usize, pred: F) -> f64 {
+        1.0 - self.count_distribution(n, pred)[0]
+    }
+    pub fn expected_count<F: Fn(&T) -> bool>(&self, n: usize, pred: F) -> f64 {
+        self.count_distribution(n, pred)
+            .iter()
+            .enumerate()
+            .map(|(k, p)| k as f64 * p)
+            .sum()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct DrawPileRun<K> {
+    // None for a card in a pile with a known order
+    kind: Option<(Placement, bool)>,
+    pub keys: Vec<K>,
 }
 
+// The draw pile grouped into classes of cards with the same key, draw flag
+// and constraints. Members of a class are interchangeable, so the state of
+// drawing is just how many cards have been drawn from each class.
+struct ConstraintView<K> {
+    classes: Vec<ConstraintClass<K>>,
+}
+
+struct ConstraintClass<K> {
+    key: K,
+    size: usize,
+    can_draw: bool,
+    // drawing a card from any of these classes lets this class be drawn
+    unlocked_by: Vec<usize>,
+    // classes that must be completely drawn before this one
+    after: Vec<usize>,
+}
+
+impl<K: Ord + Clone> ConstraintView<K> {
+    fn can_draw(&self, c: usize, drawn: &[usize]) -> bool {
+        let class = &self.classes[c];
+        drawn[c] < class.size
+            && (class.can_draw || class.unlocked_by.iter().any(|&s| drawn[s] > 0))
+            && class
+                .after
+                .iter()
+                .all(|&a| drawn[a] == self.classes[a].size)
+    }
+
+    // probability of each number drawn per class after n draws
+    fn draws(&self, n: usize) -> BTreeMap<Vec<usize>, f64> {
+        let mut states = BTreeMap::new();
+        states.insert(vec![0; self.classes.len()], 1.0);
+        for _ in 0..n {
+            let mut next = BTreeMap::new();
+            for (drawn, p) in states {
+                let drawable = (0..self.classes.len())
+                    .filter(|&c| self.can_draw(c, &drawn))
+                    .collect::<Vec<_>>();
+                let total = drawable
+                    .iter()
+                    .map(|&c| self.classes[c].size - drawn[c])
+                    .sum::<usize>();
+                if total == 0 {
+                    *next.entry(drawn).or_insert(0.0) += p;
+                    continue;
+                }
+                for c in drawable {
+                    let remaining = self.classes[c].size - drawn[c];
+                    let mut d = drawn.clone();
+                    d[c] += 1;
+                    *next.entry(d).or_insert(0.0) += p * remaining as f64 / total as f64;
+                }
+            }
+            states = next;
+        }
+        states
+    }
+
+    fn key_distribution(&self, n: usize) -> Vec<(BTreeMap<K, usize>, f64)> {
+        let mut dist = BTreeMap::<BTreeMap<K, usize>, f64>::new();
+        for (drawn, p) in self.draws(n) {
+            let mut counts = BTreeMap::new();
+            for (c, &d) in drawn.iter().enumerate() {
+                if d > 0 {
+                    *counts.entry(self.classes[c].key.clone()).or_insert(0) += d;
+                }
+            }
+            *dist.entry(counts).or_insert(0.0) += p;
+        }
+        dist.into_iter().collect()
+    }
+}
+
+#[derive(Clone)]
 struct FrozenEyeDrawPileImpl<T: Debug>(Vec<T>);
 
 impl<T: Debug> Default for FrozenEyeDrawPileImpl<T> {
@@ -164,171 +329,262 @@ impl<T: Debug> FrozenEyeDrawPileImpl<T> {
     pub fn pop(&mut self) -> T {
         self.0.pop().unwrap()
     }
+    // every card is its own class, each drawn after the one above it
+    fn constraint_view<K: Ord + Clone, F: Fn(&T) -> K>(&self, key: F) -> ConstraintView<K> {
+        let len = self.0.len();
+        ConstraintView {
+            classes: self
+                .0
+                .iter()
+                .enumerate()
+                .map(|(i, t)| ConstraintClass {
+                    key: key(t),
+                    size: 1,
+                    can_draw: true,
+                    unlocked_by: vec![],
+                    after: if i + 1 < len { vec![i + 1] } else { vec![] },
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Placement {
+    Initial,  // from new(), drawn after every priority card
+    Priority, // from new()
+    Top,      // drawn before every card that was in the pile when it was added
+    Bottom,   // drawn after every card that was in the pile when it was added
+    Shuffled, // anywhere, once it can be drawn
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Node<T: Debug> {
     value: T,
+    placement: Placement,
     can_draw: bool,
 }
 
-#[derive(Debug)]
-enum Edge {
-    Unlock,  // drawing the source card lets the target card be drawn if it couldn't be
-    Ordered, // the source card must be drawn after the target card
-}
-
+// Cards in the order they were added to the pile. Every constraint relates a
+// card to the cards that were already there when it was added, so they all
+// follow from positions in this list:
+// - nothing below the last top card can be drawn
+// - a bottom card can only be drawn when it is the oldest card
+// - a card shuffled in can't be drawn until an older card that could be
+//   drawn is drawn
+#[derive(Clone)]
 struct DrawPileImpl<T: Debug> {
-    graph: petgraph::graph::DiGraph<Node<T>, Edge>,
+    nodes: Vec<Node<T>>,
 }
 
 impl<T: Debug> Default for DrawPileImpl<T> {
     fn default() -> Self {
         Self {
-            graph: Default::default(),
+            nodes: Default::default(),
         }
     }
 }
 
 impl<T: Debug> DrawPileImpl<T> {
     fn new(priority: Vec<T>, normal: Vec<T>) -> Self {
-        let mut ret = Self::default();
-        let mut normal_nodes = Vec::new();
-        let mut priority_nodes = Vec::new();
-        for t in normal {
-            let n = ret.graph.add_node(Node {
-                value: t,
+        let nodes = normal
+            .into_iter()
+            .map(|t| (t, Placement::Initial))
+            .chain(priority.into_iter().map(|t| (t, Placement::Priority)))
+            .map(|(value, placement)| Node {
+                value,
+                placement,
                 can_draw: true,
-            });
-            normal_nodes.push(n);
-        }
-        for t in priority {
-            let n = ret.graph.add_node(Node {
-                value: t,
-                can_draw: true,
-            });
-            priority_nodes.push(n);
-        }
-        for target in priority_nodes {
-            for &source in &normal_nodes {
-                ret.graph.add_edge(source, target, Edge::Ordered);
-            }
-        }
-        ret
+            })
+            .collect();
+        Self { nodes }
+    }
+    fn push(&mut self, value: T, placement: Placement, can_draw: bool) {
+        self.nodes.push(Node {
+            value,
+            placement,
+            can_draw,
+        });
     }
     fn push_top(&mut self, t: T) {
-        let all = self.graph.node_indices();
-        let n = self.graph.add_node(Node {
-            value: t,
-            can_draw: true,
-        });
-        for source in all {
-            self.graph.add_edge(source, n, Edge::Ordered);
-        }
+        self.push(t, Placement::Top, true);
     }
     fn push_bottom(&mut self, t: T) {
-        let all = self.graph.node_indices();
-        let n = self.graph.add_node(Node {
-            value: t,
-            can_draw: true,
-        });
-        for target in all {
-            self.graph.add_edge(n, target, Edge::Ordered);
-        }
+        self.push(t, Placement::Bottom, true);
     }
     fn shuffle_in_one(&mut self, t: T) {
-        if self.is_empty() {
-            self.graph.add_node(Node {
-                value: t,
-                can_draw: true,
-            });
-        } else {
-            let all = self.graph.node_indices();
-            let n = self.graph.add_node(Node {
-                value: t,
-                can_draw: false,
-            });
-            for source in all {
-                self.graph.add_edge(source, n, Edge::Unlock);
-            }
-        }
+        let can_draw = self.is_empty();
+        self.push(t, Placement::Shuffled, can_draw);
     }
     fn shuffle_all(&mut self) {
-        self.graph.clear_edges();
-        for n in self.graph.node_indices() {
-            self.graph[n].can_draw = true;
+        for n in &mut self.nodes {
+            n.placement = Placement::Shuffled;
+            n.can_draw = true;
         }
     }
     fn take(&mut self, i: usize) -> T {
-        for (ni, n) in self.graph.node_indices().enumerate() {
-            if ni == i {
-                if self.graph[n].can_draw {
-                    let set_can_draw_nodes = self
-                        .graph
-                        .edges(n)
-                        .filter(|e| matches!(e.weight(), Edge::Unlock))
-                        .map(|e| e.target())
-                        .collect::<Vec<_>>();
-
-                    for n in set_can_draw_nodes {
-                        self.graph[n].can_draw = true;
-                    }
-                }
-                let node = self.graph.remove_node(n).unwrap();
-                return node.value;
+        if self.nodes[i].can_draw {
+            for n in &mut self.nodes[i + 1..] {
+                n.can_draw = true;
             }
         }
-        panic!()
+        self.nodes.remove(i).value
     }
     fn get(&self, i: usize) -> &T {
-        self.get_all()[i]
+        &self.nodes[i].value
     }
     #[cfg(test)]
     fn top(&self, rng: &mut Rand) -> &T {
         let possible = self.possible_indexes_to_draw();
-        let c = rand_slice(rng, &possible);
-        self.get(possible[c])
+        self.get(rand_slice(rng, &possible))
     }
     fn get_all(&self) -> Vec<&T> {
-        self.graph
-            .node_indices()
-            .map(|i| &self.graph[i].value)
-            .collect()
+        self.nodes.iter().map(|n| &n.value).collect()
     }
     fn clear(&mut self) {
-        self.graph.clear();
+        self.nodes.clear();
     }
     fn len(&self) -> usize {
-        self.graph.node_count()
+        self.nodes.len()
     }
     fn is_empty(&self) -> bool {
-        self.len() == 0
+        self.nodes.is_empty()
     }
     fn pop(&mut self, rng: &mut Rand) -> T {
         let possible = self.possible_indexes_to_draw();
         let c = rand_slice(rng, &possible);
-        for (ni, n) in self.graph.node_indices().enumerate() {
-            if ni == c {
-                assert!(self.graph[n].can_draw);
-                break;
-            }
-        }
         self.take(c)
     }
     fn possible_indexes_to_draw(&self) -> Vec<usize> {
         assert!(!self.is_empty());
-        let mut ret = Vec::new();
-        for (i, n) in self.graph.node_indices().enumerate() {
-            if self.graph[n].can_draw
-                && !self
-                    .graph
-                    .edges(n)
-                    .any(|e| matches!(e.weight(), Edge::Ordered))
-            {
-                ret.push(i);
+        let has_priority = self
+            .nodes
+            .iter()
+            .any(|n| n.placement == Placement::Priority);
+        let last_top = self
+            .nodes
+            .iter()
+            .rposition(|n| n.placement == Placement::Top)
+            .unwrap_or(0);
+        (last_top..self.len())
+            .filter(|&i| {
+                let n = &self.nodes[i];
+                n.can_draw
+                    && match n.placement {
+                        Placement::Initial => !has_priority,
+                        Placement::Bottom => i == 0,
+                        _ => true,
+                    }
+            })
+            .collect()
+    }
+    fn into_values(self) -> Vec<T> {
+        self.nodes.into_iter().map(|n| n.value).collect()
+    }
+    // same process as popping every card, but on indexes
+    fn sample_order(&self, rng: &mut Rand) -> Vec<usize> {
+        let mut indexes = DrawPileImpl {
+            nodes: self
+                .nodes
+                .iter()
+                .enumerate()
+                .map(|(i, n)| Node {
+                    value: i,
+                    placement: n.placement,
+                    can_draw: n.can_draw,
+                })
+                .collect(),
+        };
+        let mut order = Vec::with_capacity(self.len());
+        while !indexes.is_empty() {
+            let possible = indexes.possible_indexes_to_draw();
+            if possible.is_empty() {
+                break;
             }
+            let c = rand_slice(rng, &possible);
+            order.push(indexes.take(c));
+        }
+        // cards that can never be drawn go on the bottom
+        order.extend(indexes.into_values());
+        order
+    }
+    // Neighbouring cards with the same placement and draw flag relate to every
+    // other card the same way, except top and bottom cards, which are ordered
+    // among themselves.
+    fn interchangeable_runs<K: Ord, F: Fn(&T) -> K>(&self, key: F) -> Vec<DrawPileRun<K>> {
+        let mut runs: Vec<DrawPileRun<K>> = Vec::new();
+        for n in &self.nodes {
+            let kind = Some((n.placement, n.can_draw));
+            let swappable = !matches!(n.placement, Placement::Top | Placement::Bottom);
+            match runs.last_mut() {
+                Some(r) if swappable && r.kind == kind => r.keys.push(key(&n.value)),
+                _ => runs.push(DrawPileRun {
+                    kind,
+                    keys: vec![key(&n.value)],
+                }),
+            }
+        }
+        for r in &mut runs {
+            r.keys.sort();
+        }
+        runs
+    }
+    // whether card i must be drawn after card j
+    fn is_after(&self, i: usize, j: usize) -> bool {
+        match (self.nodes[i].placement, self.nodes[j].placement) {
+            (_, Placement::Top) if j > i => true,
+            (Placement::Bottom, _) if j < i => true,
+            (Placement::Initial, Placement::Priority) => true,
+            _ => false,
+        }
+    }
+    fn constraint_view<K: Ord + Clone, F: Fn(&T) -> K>(&self, key: F) -> ConstraintView<K> {
+        let len = self.len();
+        let others = |pred: &dyn Fn(usize) -> bool| (0..len).filter(|&j| pred(j)).collect();
+        // cards with identical keys, flags and constraints are interchangeable
+        let mut class_by_signature = BTreeMap::new();
+        let mut signatures = Vec::<(bool, K, [Vec<usize>; 4])>::new();
+        let mut sizes = Vec::new();
+        let mut class_of = Vec::with_capacity(len);
+        for i in 0..len {
+            let locked = |j: usize| !self.nodes[j].can_draw;
+            let sig = (
+                self.nodes[i].can_draw,
+                key(&self.nodes[i].value),
+                [
+                    others(&|j| self.is_after(i, j)),
+                    others(&|j| j > i && locked(j)),
+                    others(&|j| self.is_after(j, i)),
+                    if locked(i) { (0..i).collect() } else { vec![] },
+                ],
+            );
+            let c = *class_by_signature.entry(sig.clone()).or_insert_with(|| {
+                signatures.push(sig);
+                sizes.push(0);
+                signatures.len() - 1
+            });
+            sizes[c] += 1;
+            class_of.push(c);
+        }
+        let to_classes = |nodes: &[usize]| {
+            let mut v = nodes.iter().map(|&n| class_of[n]).collect::<Vec<_>>();
+            v.sort();
+            v.dedup();
+            v
+        };
+        ConstraintView {
+            classes: signatures
+                .into_iter()
+                .zip(sizes)
+                .map(|((can_draw, key, edges), size)| ConstraintClass {
+                    key,
+                    size,
+                    can_draw,
+                    unlocked_by: to_classes(&edges[3]),
+                    after: to_classes(&edges[0]),
+                })
+                .collect(),
         }
-        ret
     }
     #[cfg(test)]
     fn possible_values_to_draw(&self) -> Vec<&T> {
@@ -410,7 +666,7 @@ mod tests {
 
     #[test]
     fn test_shuffled_basic() {
-        let mut rng = Rand::default();
+        let mut rng: Rand = rand::make_rng();
         let mut seen = Seen::new(&[1, 2, 3]);
         for _ in 0..100 {
             let mut d = DrawPileImpl::<i32>::new(vec![], vec![1, 2, 3]);
@@ -440,14 +696,14 @@ mod tests {
     #[test]
     #[should_panic]
     fn test_empty() {
-        let mut rng = Rand::default();
+        let mut rng: Rand = rand::make_rng();
         let mut d = DrawPileImpl::<i32>::default();
         d.pop(&mut rng);
     }
 
     #[test]
     fn test_priority() {
-        let mut rng = Rand::default();
+        let mut rng: Rand = rand::make_rng();
 
         for _ in 0..10 {
             let mut d = DrawPileImpl::<i32>::new(vec![4, 5], vec![1, 2, 3]);
@@ -460,7 +716,7 @@ mod tests {
 
     #[test]
     fn test_top() {
-        let mut rng = Rand::default();
+        let mut rng: Rand = rand::make_rng();
         let mut d = DrawPileImpl::<i32>::default();
         d.push_top(1);
         d.push_top(2);
@@ -472,7 +728,7 @@ mod tests {
 
     #[test]
     fn test_bottom() {
-        let mut rng = Rand::default();
+        let mut rng: Rand = rand::make_rng();
         let mut d = DrawPileImpl::<i32>::default();
         d.push_bottom(1);
         d.push_bottom(2);
@@ -484,7 +740,7 @@ mod tests {
 
     #[test]
     fn test_top_bottom() {
-        let mut rng = Rand::default();
+        let mut rng: Rand = rand::make_rng();
         let mut d = DrawPileImpl::<i32>::default();
         d.push_bottom(1);
         d.push_top(2);
@@ -498,7 +754,7 @@ mod tests {
 
     #[test]
     fn test_top_shuffle() {
-        let mut rng = Rand::default();
+        let mut rng: Rand = rand::make_rng();
         let mut d = DrawPileImpl::<i32>::new(vec![], vec![1, 2]);
         d.push_top(3);
         assert_eq!(d.pop(&mut rng), 3);
@@ -506,7 +762,7 @@ mod tests {
 
     #[test]
     fn test_shuffled_in_1() {
-        let mut rng = Rand::default();
+        let mut rng: Rand = rand::make_rng();
         let mut d = DrawPileImpl::<i32>::new(vec![], vec![1]);
         d.shuffle_in_one(2);
         assert_eq!(d.pop(&mut rng), 1);
@@ -515,7 +771,7 @@ mod tests {
 
     #[test]
     fn test_shuffled_in_2() {
-        let mut rng = Rand::default();
+        let mut rng: Rand = rand::make_rng();
         let mut d = DrawPileImpl::<i32>::new(vec![], vec![1, 1]);
         d.shuffle_in_one(2);
         assert_eq!(d.pop(&mut rng), 1);
@@ -524,7 +780,7 @@ mod tests {
 
     #[test]
     fn test_shuffled_in_3() {
-        let mut rng = Rand::default();
+        let mut rng: Rand = rand::make_rng();
         let mut d = DrawPileImpl::<i32>::default();
         d.shuffle_in_one(1);
         assert_eq!(d.pop(&mut rng), 1);
@@ -532,7 +788,7 @@ mod tests {
 
     #[test]
     fn test_shuffled_in_4() {
-        let mut rng = Rand::default();
+        let mut rng: Rand = rand::make_rng();
         let mut d = DrawPileImpl::<i32>::default();
         d.shuffle_in_one(1);
         d.shuffle_in_one(2);
@@ -542,7 +798,7 @@ mod tests {
 
     #[test]
     fn test_shuffled_in_5() {
-        let mut rng = Rand::default();
+        let mut rng: Rand = rand::make_rng();
         let mut d = DrawPileImpl::<i32>::default();
         d.shuffle_in_one(1);
         d.shuffle_in_one(2);
@@ -552,7 +808,7 @@ mod tests {
 
     #[test]
     fn test_shuffled_in_6() {
-        let mut rng = Rand::default();
+        let mut rng: Rand = rand::make_rng();
         let mut d = DrawPileImpl::<i32>::default();
         d.shuffle_in_one(0);
         d.shuffle_in_one(1);
@@ -565,7 +821,7 @@ mod tests {
 
     #[test]
     fn test_shuffled_in_bottom() {
-        let mut rng = Rand::default();
+        let mut rng: Rand = rand::make_rng();
         let mut d = DrawPileImpl::<i32>::default();
         d.push_bottom(0);
         d.shuffle_in_one(1);
@@ -596,7 +852,7 @@ mod tests {
     #[test]
     fn fuzz() {
         let mut drng = rand::rngs::SmallRng::seed_from_u64(1);
-        let mut rng = Rand::default();
+        let mut rng: Rand = rand::make_rng();
         for _ in 0..100 {
             let mut dp = DrawPileImpl::<i32>::default();
             let mut test_pile = FrozenEyeDrawPileImpl::<i32>::new(vec![], vec![], &mut rng);
@@ -631,7 +887,7 @@ mod tests {
 
     #[test]
     fn test_frozen_eye_1() {
-        let mut rng = Rand::default();
+        let mut rng: Rand = rand::make_rng();
         let mut dp = FrozenEyeDrawPileImpl::<i32>::new(vec![0], vec![1], &mut rng);
         assert_eq!(dp.pop(), 0);
         assert_eq!(dp.pop(), 1);
@@ -650,7 +906,7 @@ mod tests {
         let mut found_0_first = false;
         let mut found_1_first = false;
         for _ in 0..30 {
-            let mut rng = Rand::default();
+            let mut rng: Rand = rand::make_rng();
             let mut dp = FrozenEyeDrawPileImpl::<i32>::new(vec![0], vec![1], &mut rng);
             dp.shuffle_all(&mut rng);
             match dp.pop() {
@@ -670,7 +926,7 @@ mod tests {
         let mut found_0_first = false;
         let mut found_1_first = false;
         for _ in 0..30 {
-            let mut rng = Rand::default();
+            let mut rng: Rand = rand::make_rng();
             let mut dp = FrozenEyeDrawPileImpl::<i32>::default();
             dp.push_top(1);
             dp.push_top(2);
@@ -687,4 +943,462 @@ mod tests {
         }
         assert!(found_0_first && found_1_first);
     }
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{a} != {b}");
+    }
+
+    #[test]
+    fn test_probability_shuffled() {
+        let mut rng: Rand = rand::make_rng();
+        let d = DrawPile::new(false, vec![], vec![1, 2, 3, 4], &mut rng);
+        assert_close(d.probability_any(1, |x| *x == 1), 0.25);
+        assert_close(d.probability_any(2, |x| *x == 1), 0.5);
+        assert_close(d.probability_any(10, |x| *x == 1), 1.0);
+        assert_close(d.expected_count(2, |x| *x <= 2), 1.0);
+        let counts = d.count_distribution(2, |x| *x <= 2);
+        assert_eq!(counts.len(), 3);
+        assert_close(counts[0], 1.0 / 6.0);
+        assert_close(counts[1], 4.0 / 6.0);
+        assert_close(counts[2], 1.0 / 6.0);
+        let dist = d.draw_distribution(4, |x| *x);
+        assert_eq!(dist.len(), 1);
+        assert_close(dist[0].1, 1.0);
+    }
+
+    #[test]
+    fn test_probability_top_bottom() {
+        let mut rng: Rand = rand::make_rng();
+        let mut d = DrawPile::new(false, vec![], vec![1, 2, 3], &mut rng);
+        d.push_top(9);
+        d.push_bottom(8);
+        assert_close(d.probability_any(1, |x| *x == 9), 1.0);
+        assert_close(d.probability_any(4, |x| *x == 8), 0.0);
+        assert_close(d.probability_any(5, |x| *x == 8), 1.0);
+        assert_close(d.probability_any(2, |x| *x == 1), 1.0 / 3.0);
+
+        let d = DrawPile::new(false, vec![7], vec![1, 2], &mut rng);
+        assert_close(d.probability_any(1, |x| *x == 7), 1.0);
+    }
+
+    #[test]
+    fn test_probability_shuffle_in() {
+        let mut rng: Rand = rand::make_rng();
+        let mut d = DrawPile::new(false, vec![], vec![1, 2], &mut rng);
+        d.shuffle_in_one(5, &mut rng);
+        assert_close(d.probability_any(1, |x| *x == 5), 0.0);
+        assert_close(d.probability_any(2, |x| *x == 5), 0.5);
+        assert_close(d.probability_any(3, |x| *x == 5), 1.0);
+    }
+
+    #[test]
+    fn test_probability_frozen_eye() {
+        let mut rng: Rand = rand::make_rng();
+        let mut d = DrawPile::new(true, vec![1], vec![2, 3, 4], &mut rng);
+        d.shuffle_in_one(5, &mut rng);
+        let order = d.get_all().into_iter().rev().copied().collect::<Vec<_>>();
+        assert_eq!(order[0], 1);
+        for (i, &v) in order.iter().enumerate() {
+            assert_close(d.probability_any(i, |x| *x == v), 0.0);
+            assert_close(d.probability_any(i + 1, |x| *x == v), 1.0);
+        }
+        let dist = d.draw_distribution(2, |x| *x);
+        assert_eq!(dist.len(), 1);
+        assert_eq!(dist[0].0, BTreeMap::from([(order[0], 1), (order[1], 1)]));
+    }
+
+    #[test]
+    fn test_probability_matches_sampling() {
+        let build = |rng: &mut Rand| {
+            let mut d = DrawPile::new(false, vec![], vec![1, 1, 2, 3, 3, 3], rng);
+            d.push_top(4);
+            d.shuffle_in_one(5, rng);
+            d.push_bottom(6);
+            d.shuffle_in_one(1, rng);
+            d
+        };
+        let mut rng: Rand = rand::make_rng();
+        let dist = build(&mut rng).draw_distribution(4, |x| *x);
+        assert_close(dist.iter().map(|(_, p)| p).sum(), 1.0);
+
+        let trials = 20000;
+        let mut counts = BTreeMap::<BTreeMap<i32, usize>, usize>::new();
+        for _ in 0..trials {
+            let mut d = build(&mut rng);
+            let mut drawn = BTreeMap::new();
+            for _ in 0..4 {
+                *drawn.entry(d.pop(&mut rng)).or_insert(0) += 1;
+            }
+            *counts.entry(drawn).or_insert(0) += 1;
+        }
+        for (k, p) in &dist {
+            let freq = counts.get(k).copied().unwrap_or(0) as f64 / trials as f64;
+            assert!(
+                (freq - p).abs() < 0.02,
+                "{k:?}: sampled {freq}, expected {p}"
+            );
+        }
+        for k in counts.keys() {
+            assert!(dist.iter().any(|(d, _)| d == k), "unexpected draw {k:?}");
+        }
+    }
+
+    fn sampled_values(d: &DrawPile<i32>, rng: &mut Rand) -> Vec<i32> {
+        let all = d.get_all();
+        d.sample_order(rng).iter().map(|&i| *all[i]).collect()
+    }
+
+    #[test]
+    fn test_sample_order_shuffled() {
+        let mut rng: Rand = rand::make_rng();
+        let d = DrawPile::new(false, vec![], vec![1, 2, 3], &mut rng);
+        let mut seen = Seen::new(&[
+            [1, 2, 3],
+            [1, 3, 2],
+            [2, 1, 3],
+            [2, 3, 1],
+            [3, 1, 2],
+            [3, 2, 1],
+        ]);
+        for _ in 0..500 {
+            let v = sampled_values(&d, &mut rng);
+            seen.add([v[0], v[1], v[2]]);
+            if seen.done() {
+                break;
+            }
+        }
+        seen.assert_done();
+    }
+
+    #[test]
+    fn test_sample_order_constraints() {
+        let mut rng: Rand = rand::make_rng();
+        let mut d = DrawPile::new(false, vec![7], vec![1, 2, 3], &mut rng);
+        d.push_top(9);
+        d.push_bottom(8);
+        d.shuffle_in_one(5, &mut rng);
+        let mut seen_5 = Seen::new(&[1, 2, 3, 4, 5, 6]);
+        let mut seen_after_7 = Seen::new(&[1, 2, 3]);
+        for _ in 0..500 {
+            let v = sampled_values(&d, &mut rng);
+            assert_eq!(v.len(), 6 + 1);
+            let pos = |x| v.iter().position(|y| *y == x).unwrap();
+            assert_eq!(v[0], 9);
+            // 8 is below everything except the locked 5
+            assert!(pos(8) >= 5);
+            seen_5.add(pos(5));
+            let after_9 = v.iter().filter(|x| **x != 5).nth(1).copied().unwrap();
+            assert_eq!(after_9, 7);
+            seen_after_7.add(v.iter().filter(|x| **x != 5).nth(2).copied().unwrap());
+            if seen_5.done() && seen_after_7.done() {
+                break;
+            }
+        }
+        seen_5.assert_done();
+        seen_after_7.assert_done();
+    }
+
+    #[test]
+    fn test_sample_order_matches_probability() {
+        let mut rng: Rand = rand::make_rng();
+        let mut d = DrawPile::new(false, vec![], vec![1, 1, 2, 3, 3], &mut rng);
+        d.shuffle_in_one(4, &mut rng);
+        d.push_top(5);
+        d.shuffle_in_one(1, &mut rng);
+        let dist = d.draw_distribution(3, |x| *x);
+        let trials = 20000;
+        let mut counts = BTreeMap::<BTreeMap<i32, usize>, usize>::new();
+        for _ in 0..trials {
+            let mut drawn = BTreeMap::new();
+            for x in sampled_values(&d, &mut rng).into_iter().take(3) {
+                *drawn.entry(x).or_insert(0) += 1;
+            }
+            *counts.entry(drawn).or_insert(0) += 1;
+        }
+        for (k, p) in &dist {
+            let freq = counts.get(k).copied().unwrap_or(0) as f64 / trials as f64;
+            assert!(
+                (freq - p).abs() < 0.02,
+                "{k:?}: sampled {freq}, expected {p}"
+            );
+        }
+        assert_eq!(counts.len(), dist.len());
+    }
+
+    #[test]
+    fn test_sample_order_frozen_eye() {
+        let mut rng: Rand = rand::make_rng();
+        let d = DrawPile::new(true, vec![], vec![1, 2, 3, 4], &mut rng);
+        let expected = d.get_all().into_iter().rev().copied().collect::<Vec<_>>();
+        assert_eq!(sampled_values(&d, &mut rng), expected);
+    }
+
+    #[test]
+    fn test_install_order() {
+        let mut rng: Rand = rand::make_rng();
+        let mut d = DrawPile::new(false, vec![], vec![1, 2, 3, 4], &mut rng);
+        d.shuffle_in_one(5, &mut rng);
+        let order = d.sample_order(&mut rng);
+        let expected = order.iter().map(|&i| *d.get(i)).collect::<Vec<_>>();
+        d.install_order(&order);
+        assert_eq!(d.len(), 5);
+        assert_close(d.probability_any(1, |x| *x == expected[0]), 1.0);
+        d.push_top(9);
+        assert_eq!(d.pop(&mut rng), 9);
+        for e in expected {
+            assert_eq!(d.pop(&mut rng), e);
+        }
+        assert!(d.is_empty());
+    }
+
+    fn sorted(v: Vec<&i32>) -> Vec<i32> {
+        let mut v = v.into_iter().copied().collect::<Vec<_>>();
+        v.sort();
+        v
+    }
+
+    #[test]
+    fn test_matches_graph() {
+        let mut rng = rand::rngs::SmallRng::seed_from_u64(2);
+        for _ in 0..300 {
+            let priority = (100..100 + rng.random_range(0..3)).collect::<Vec<_>>();
+            let normal = (200..200 + rng.random_range(0..4)).collect::<Vec<_>>();
+            let mut d = DrawPileImpl::new(priority.clone(), normal.clone());
+            let mut g = graph::GraphDrawPileImpl::new(priority, normal);
+            for x in 0..30 {
+                match rng.random_range(0..7) {
+                    0 => {
+                        d.push_top(x);
+                        g.push_top(x);
+                    }
+                    1 => {
+                        d.push_bottom(x);
+                        g.push_bottom(x);
+                    }
+                    2 | 3 => {
+                        d.shuffle_in_one(x);
+                        g.shuffle_in_one(x);
+                    }
+                    4 if rng.random_range(0..4) == 0 => {
+                        d.shuffle_all();
+                        g.shuffle_all();
+                    }
+                    _ if !d.is_empty() => {
+                        // take any card, drawable or not
+                        let all = d.get_all();
+                        let v = *all[rng.random_range(0..all.len())];
+                        let i = all.iter().position(|e| **e == v).unwrap();
+                        let j = g.get_all().iter().position(|e| **e == v).unwrap();
+                        assert_eq!(d.take(i), g.take(j));
+                    }
+                    _ => {}
+                }
+                assert_eq!(sorted(d.get_all()), sorted(g.get_all()));
+                if !d.is_empty() {
+                    assert_eq!(
+                        sorted(d.possible_values_to_draw()),
+                        sorted(g.possible_values_to_draw())
+                    );
+                }
+            }
+            while !d.is_empty() {
+                let possible = d.possible_values_to_draw();
+                if possible.is_empty() {
+                    break;
+                }
+                let v = *possible[rng.random_range(0..possible.len())];
+                let i = d.get_all().iter().position(|e| **e == v).unwrap();
+                let j = g.get_all().iter().position(|e| **e == v).unwrap();
+                assert_eq!(d.take(i), g.take(j));
+                assert_eq!(sorted(d.get_all()), sorted(g.get_all()));
+                if !d.is_empty() {
+                    assert_eq!(
+                        sorted(d.possible_values_to_draw()),
+                        sorted(g.possible_values_to_draw())
+                    );
+                }
+            }
+        }
+    }
+
+    // cargo test --release bench_large_pile -- --ignored --nocapture
+    #[test]
+    #[ignore]
+    fn bench_large_pile() {
+        use std::time::Instant;
+
+        for n in [100, 500, 2000] {
+            let mut rng = rand::rngs::SmallRng::seed_from_u64(3);
+            let ops = (0..n).map(|_| rng.random_range(0..4)).collect::<Vec<_>>();
+
+            let start = Instant::now();
+            let mut d = DrawPileImpl::new(vec![], (0..n).collect());
+            for (x, op) in ops.iter().enumerate() {
+                match op {
+                    0 => d.push_top(x as i32),
+                    1 => d.push_bottom(x as i32),
+                    _ => d.shuffle_in_one(x as i32),
+                }
+            }
+            while !d.is_empty() {
+                d.pop(&mut rng);
+            }
+            let positional = start.elapsed();
+
+            let start = Instant::now();
+            let mut g = graph::GraphDrawPileImpl::new(vec![], (0..n).collect());
+            for (x, op) in ops.iter().enumerate() {
+                match op {
+                    0 => g.push_top(x as i32),
+                    1 => g.push_bottom(x as i32),
+                    _ => g.shuffle_in_one(x as i32),
+                }
+            }
+            while !g.is_empty() {
+                let v = *g.possible_values_to_draw()[0];
+                let i = g.get_all().iter().position(|e| **e == v).unwrap();
+                g.take(i);
+            }
+            let graph = start.elapsed();
+
+            println!(
+                "{} cards: positional {positional:?}, graph {graph:?}",
+                2 * n
+            );
+        }
+    }
+}
+
+// The original graph based pile, kept to check the positional one against.
+#[cfg(test)]
+mod graph {
+    use petgraph::visit::EdgeRef;
+
+    use std::fmt::Debug;
+
+    #[derive(Debug)]
+    struct Node<T: Debug> {
+        value: T,
+        can_draw: bool,
+    }
+
+    #[derive(Debug)]
+    enum Edge {
+        Unlock,  // drawing the source card lets the target card be drawn if it couldn't be
+        Ordered, // the source card must be drawn after the target card
+    }
+
+    pub struct GraphDrawPileImpl<T: Debug> {
+        graph: petgraph::graph::DiGraph<Node<T>, Edge>,
+    }
+
+    impl<T: Debug> GraphDrawPileImpl<T> {
+        pub fn new(priority: Vec<T>, normal: Vec<T>) -> Self {
+            let mut ret = Self {
+                graph: Default::default(),
+            };
+            let mut normal_nodes = Vec::new();
+            let mut priority_nodes = Vec::new();
+            for t in normal {
+                let n = ret.graph.add_node(Node {
+                    value: t,
+                    can_draw: true,
+                });
+                normal_nodes.push(n);
+            }
+            for t in priority {
+                let n = ret.graph.add_node(Node {
+                    value: t,
+                    can_draw: true,
+                });
+                priority_nodes.push(n);
+            }
+            for target in priority_nodes {
+                for &source in &normal_nodes {
+                    ret.graph.add_edge(source, target, Edge::Ordered);
+                }
+            }
+            ret
+        }
+        pub fn push_top(&mut self, t: T) {
+            let all = self.graph.node_indices();
+            let n = self.graph.add_node(Node {
+                value: t,
+                can_draw: true,
+            });
+            for source in all {
+                self.graph.add_edge(source, n, Edge::Ordered);
+            }
+        }
+        pub fn push_bottom(&mut self, t: T) {
+            let all = self.graph.node_indices();
+            let n = self.graph.add_node(Node {
+                value: t,
+                can_draw: true,
+            });
+            for target in all {
+                self.graph.add_edge(n, target, Edge::Ordered);
+            }
+        }
+        pub fn shuffle_in_one(&mut self, t: T) {
+            if self.is_empty() {
+                self.graph.add_node(Node {
+                    value: t,
+                    can_draw: true,
+                });
+            } else {
+                let all = self.graph.node_indices();
+                let n = self.graph.add_node(Node {
+                    value: t,
+                    can_draw: false,
+                });
+                for source in all {
+                    self.graph.add_edge(source, n, Edge::Unlock);
+                }
+            }
+        }
+        pub fn shuffle_all(&mut self) {
+            self.graph.clear_edges();
+            for n in self.graph.node_indices() {
+                self.graph[n].can_draw = true;
+            }
+        }
+        pub fn take(&mut self, i: usize) -> T {
+            let n = self.graph.node_indices().nth(i).unwrap();
+            if self.graph[n].can_draw {
+                let set_can_draw_nodes = self
+                    .graph
+                    .edges(n)
+                    .filter(|e| matches!(e.weight(), Edge::Unlock))
+                    .map(|e| e.target())
+                    .collect::<Vec<_>>();
+                for n in set_can_draw_nodes {
+                    self.graph[n].can_draw = true;
+                }
+            }
+            self.graph.remove_node(n).unwrap().value
+        }
+        pub fn get_all(&self) -> Vec<&T> {
+            self.graph
+                .node_indices()
+                .map(|i| &self.graph[i].value)
+                .collect()
+        }
+        pub fn is_empty(&self) -> bool {
+            self.graph.node_count() == 0
+        }
+        pub fn possible_values_to_draw(&self) -> Vec<&T> {
+            self.graph
+                .node_indices()
+                .filter(|&n| {
+                    self.graph[n].can_draw
+                        && !self
+                            .graph
+                            .edges(n)
+                            .any(|e| matches!(e.weight(), Edge::Ordered))
+                })
+                .map(|n| &self.graph[n].value)
+                .collect()
+        }
+    }
 }
@@ -0,0 +1,442 @@
+use std::any::Any;
+
+use lazy_static::lazy_static;
+
+use crate::{
+    card::{CardArena, CardRef},
+    cards::CardClass,
+    combat::{EndTurnStep, PlayCardStep},
+    creature::Creature,
+    game::{CombatType, CreatureRef, DiscardPotionStep, Game, GameBuilder, UsePotionStep},
+    monster::Intent,
+    potion::Potion,
+    relic::RelicClass,
+    status::Status,
+    step::Step,
+};
+
+// bump whenever the meaning of an existing feature or action index changes
+// without the layout itself changing
+pub const ENCODING_VERSION: u32 = 1;
+
+pub const MAX_MONSTERS: usize = 5;
+pub const MAX_POTIONS: usize = 5;
+pub const MAX_HAND: usize = Game::MAX_HAND_SIZE as usize;
+
+const NUM_INTENTS: usize = 14;
+const COMBAT_TYPES: [CombatType; 4] = [
+    CombatType::None,
+    CombatType::Normal,
+    CombatType::Elite,
+    CombatType::Boss,
+];
+
+fn intent_index(i: &Intent) -> usize {
+    use Intent::*;
+    match i {
+        Attack(..) => 0,
+        AttackBuff(..) => 1,
+        AttackDebuff(..) => 2,
+        AttackDefend(..) => 3,
+        Buff => 4,
+        Debuff => 5,
+        StrongDebuff => 6,
+        Defend => 7,
+        DefendBuff => 8,
+        DefendDebuff => 9,
+        Escape => 10,
+        Sleep => 11,
+        Stun => 12,
+        Unknown => 13,
+    }
+}
+
+struct Encoder {
+    values: Vec<f32>,
+    names: Option<Vec<String>>,
+}
+
+impl Encoder {
+    fn push<F: FnOnce() -> String>(&mut self, name: F, value: f32) {
+        if let Some(names) = &mut self.names {
+            names.push(name());
+        }
+        self.values.push(value);
+    }
+
+    fn push_i32<F: FnOnce() -> String>(&mut self, name: F, value: i32) {
+        self.push(name, value as f32);
+    }
+
+    fn push_bool<F: FnOnce() -> String>(&mut self, name: F, value: bool) {
+        self.push(name, if value { 1.0 } else { 0.0 });
+    }
+
+    fn encode_statuses(&mut self, prefix: &str, c: Option<&Creature>) {
+        for s in Status::all() {
+            let v = c.and_then(|c| c.get_status(s)).unwrap_or(0);
+            self.push_i32(|| format!("{prefix}.status.{s:?}"), v);
+        }
+    }
+
+    fn encode_pile<'a, I: Iterator<Item = &'a CardRef>>(
+        &mut self,
+        prefix: &str,
+        arena: &CardArena,
+        cards: I,
+    ) {
+        let num_classes = CardClass::all().len();
+        let mut counts = vec![[0; 2]; num_classes];
+        for c in cards {
+            let c = &arena[c];
+            counts[c.class as usize][(c.upgrade_count > 0) as usize] += 1;
+        }
+        for class in CardClass::all() {
+            for (upgraded, suffix) in ["", "+"].iter().enumerate() {
+                self.push_i32(
+                    || format!("{prefix}.{class:?}{suffix}"),
+                    counts[class as usize][upgraded],
+                );
+            }
+        }
+    }
+
+    fn encode(&mut self, game: &Game) {
+        let in_combat = game.in_combat != CombatType::None;
+
+        self.push_i32(|| "floor".to_string(), game.floor);
+        let act = (1..=4).find(|&a| game.is_in_act(a)).unwrap();
+        self.push_i32(|| "act".to_string(), act);
+        self.push_i32(|| "gold".to_string(), game.gold);
+        for ty in COMBAT_TYPES {
+            self.push_bool(|| format!("combat.{ty:?}"), game.in_combat == ty);
+        }
+        self.push_i32(|| "turn".to_string(), game.turn);
+        self.push_i32(|| "energy".to_string(), game.energy);
+
+        self.push_i32(|| "player.hp".to_string(), game.player.cur_hp);
+        self.push_i32(|| "player.max_hp".to_string(), game.player.max_hp);
+        self.push_i32(|| "player.block".to_string(), game.player.block);
+        self.encode_statuses("player", Some(&game.player));
+
+        self.encode_pile("hand", &game.cards, game.hand.iter());
+        self.encode_pile("draw", &game.cards, game.draw_pile.get_all().into_iter());
+        self.encode_pile("discard", &game.cards, game.discard_pile.iter());
+        self.encode_pile("exhaust", &game.cards, game.exhaust_pile.iter());
+        self.encode_pile("deck", &game.cards, game.master_deck.iter());
+
+        assert!(game.monsters.len() <= MAX_MONSTERS);
+        for mi in 0..MAX_MONSTERS {
+            let monster = game
+                .monsters
+                .get(mi)
+                .filter(|m| in_combat && m.creature.is_actionable());
+            let c = monster.map(|m| &m.creature);
+            let prefix = format!("monster{mi}");
+            self.push_bool(|| format!("{prefix}.present"), c.is_some());
+            self.push_i32(|| format!("{prefix}.hp"), c.map_or(0, |c| c.cur_hp));
+            self.push_i32(|| format!("{prefix}.max_hp"), c.map_or(0, |c| c.max_hp));
+            self.push_i32(|| format!("{prefix}.block"), c.map_or(0, |c| c.block));
+            self.encode_statuses(&prefix, c);
+            let intent = monster.map(|m| {
+                let mut i = m.behavior.get_intent();
+                i.modify_damage(CreatureRef::monster(mi), game);
+                i
+            });
+            let idx = intent.as_ref().map(intent_index);
+            for i in 0..NUM_INTENTS {
+                self.push_bool(|| format!("{prefix}.intent{i}"), idx == Some(i));
+            }
+            let (damage, hits) = intent.and_then(|i| i.damage()).unwrap_or((0, 0));
+            self.push_i32(|| format!("{prefix}.intent_damage"), damage);
+            self.push_i32(|| format!("{prefix}.intent_hits"), hits);
+        }
+
+        for r in RelicClass::all() {
+            let relic = game.relics.iter().find(|rel| rel.get_class() == r);
+            self.push_bool(|| format!("relic.{r:?}"), relic.is_some());
+            self.push_i32(
+                || format!("relic.{r:?}.value"),
+                relic.map_or(0, |rel| rel.get_value()),
+            );
+        }
+
+        assert!(game.potions.len() <= MAX_POTIONS);
+        for pi in 0..MAX_POTIONS {
+            let slot = game.potions.get(pi);
+            self.push_bool(|| format!("potion{pi}.slot"), slot.is_some());
+            let potion = slot.copied().flatten();
+            for p in Potion::all() {
+                self.push_bool(|| format!("potion{pi}.{p:?}"), potion == Some(p));
+            }
+        }
+    }
+}
+
+pub fn encode(game: &Game) -> Vec<f32> {
+    let mut e = Encoder {
+        values: Vec::with_capacity(num_features()),
+        names: None,
+    };
+    e.encode(game);
+    debug_assert_eq!(e.values.len(), num_features());
+    e.values
+}
+
+fn compute_feature_names() -> Vec<String> {
+    let game = GameBuilder::default().seed(0).build();
+    let mut e = Encoder {
+        values: Vec::new(),
+        names: Some(Vec::new()),
+    };
+    e.encode(&game);
+    e.names.unwrap()
+}
+
+// FNV-1a over the feature and action names, so a model can check that it
+// was trained against the same layout
+fn compute_layout_hash() -> u64 {
+    let mut h: u64 = 0xcbf29ce484222325;
+    let mut add = |s: &str| {
+        for b in s.bytes().chain(std::iter::once(0)) {
+            h ^= b as u64;
+            h = h.wrapping_mul(0x100000001b3);
+        }
+    };
+    add(&ENCODING_VERSION.to_string());
+    for n in feature_names() {
+        add(n);
+    }
+    for a in 0..ACTION_SPACE_SIZE {
+        add(&action_name(a));
+    }
+    h
+}
+
+lazy_static! {
+    static ref FEATURE_NAMES: Vec<String> = compute_feature_names();
+    static ref LAYOUT_HASH: u64 = compute_layout_hash();
+}
+
+pub fn feature_names() -> &'static [String] {
+    &FEATURE_NAMES
+}
+
+pub fn num_features() -> usize {
+    FEATURE_NAMES.len()
+}
+
+pub fn layout_hash() -> u64 {
+    *LAYOUT_HASH
+}
+
+// action index layout:
+//   end turn
+//   play hand card (MAX_HAND) x target (none, monster 0..MAX_MONSTERS)
+//   use potion (MAX_POTIONS) x target (none, monster 0..MAX_MONSTERS)
+//   discard potion (MAX_POTIONS)
+//   any other step, by its position among the non-combat steps
+pub const END_TURN_ACTION: usize = 0;
+const NUM_TARGETS: usize = 1 + MAX_MONSTERS;
+const PLAY_CARD_START: usize = 1;
+const USE_POTION_START: usize = PLAY_CARD_START + MAX_HAND * NUM_TARGETS;
+const DISCARD_POTION_START: usize = USE_POTION_START + MAX_POTIONS * NUM_TARGETS;
+const OTHER_START: usize = DISCARD_POTION_START + MAX_POTIONS;
+pub const MAX_OTHER_STEPS: usize = 256;
+pub const ACTION_SPACE_SIZE: usize = OTHER_START + MAX_OTHER_STEPS;
+
+fn target_index(target: Option<usize>) -> usize {
+    match target {
+        None => 0,
+        Some(t) => {
+            assert!(t < MAX_MONSTERS);
+            t + 1
+        }
+    }
+}
+
+fn target_name(t: usize) -> String {
+    if t == 0 {
+        "".to_string()
+    } else {
+        format!(" on monster {}", t - 1)
+    }
+}
+
+fn action_name(a: usize) -> String {
+    if a == END_TURN_ACTION {
+        "end turn".to_string()
+    } else if a < USE_POTION_START {
+        let i = a - PLAY_CARD_START;
+        format!(
+            "play card {}{}",
+            i / NUM_TARGETS,
+            target_name(i % NUM_TARGETS)
+        )
+    } else if a < DISCARD_POTION_START {
+        let i = a - USE_POTION_START;
+        format!(
+            "use potion {}{}",
+            i / NUM_TARGETS,
+            target_name(i % NUM_TARGETS)
+        )
+    } else if a < OTHER_START {
+        format!("discard potion {}", a - DISCARD_POTION_START)
+    } else {
+        format!("other {}", a - OTHER_START)
+    }
+}
+
+// structured index for steps that have one, or None for steps that are only
+// identified by their position
+fn structured_index(step: &dyn Step) -> Option<usize> {
+    let step = step as &dyn Any;
+    if step.is::<EndTurnStep>() {
+        Some(END_TURN_ACTION)
+    } else if let Some(s) = step.downcast_ref::<PlayCardStep>() {
+        assert!(s.hand_index < MAX_HAND);
+        Some(PLAY_CARD_START + s.hand_index * NUM_TARGETS + target_index(s.target))
+    } else if let Some(s) = step.downcast_ref::<UsePotionStep>() {
+        assert!(s.potion_index < MAX_POTIONS);
+        Some(USE_POTION_START + s.potion_index * NUM_TARGETS + target_index(s.target))
+    } else if let Some(s) = step.downcast_ref::<DiscardPotionStep>() {
+        assert!(s.potion_index < MAX_POTIONS);
+        Some(DISCARD_POTION_START + s.potion_index)
+    } else {
+        None
+    }
+}
+
+fn encode_step(step: &dyn Step, num_other: &mut usize) -> usize {
+    match structured_index(step) {
+        Some(a) => a,
+        None => {
+            let a = OTHER_START + *num_other;
+            *num_other += 1;
+            assert!(*num_other <= MAX_OTHER_STEPS);
+            a
+        }
+    }
+}
+
+// action index for each of `steps`, in the same order
+pub fn encode_steps(steps: &[Box<dyn Step>]) -> Vec<usize> {
+    let mut num_other = 0;
+    steps
+        .iter()
+        .map(|s| encode_step(s.as_ref(), &mut num_other))
+        .collect()
+}
+
+// action index for each of `game.valid_steps()`, without building the steps
+pub fn encode_valid_steps(game: &Game) -> Vec<usize> {
+    let mut num_other = 0;
+    let mut ret = Vec::new();
+    game.for_each_valid_step(|s| ret.push(encode_step(s, &mut num_other)));
+    ret
+}
+
+// index into `game.valid_steps()` for the action, if it's currently valid
+pub fn decode_action(game: &Game, action: usize) -> Option<usize> {
+    let mut num_other = 0;
+    let mut i = 0;
+    let mut ret = None;
+    game.for_each_valid_step(|s| {
+        if ret.is_none() && encode_step(s, &mut num_other) == action {
+            ret = Some(i);
+        }
+        i += 1;
+    });
+    ret
+}
+
+pub fn action_mask(game: &Game) -> Vec<bool> {
+    let mut mask = vec![false; ACTION_SPACE_SIZE];
+    let mut num_other = 0;
+    game.for_each_valid_step(|s| mask[encode_step(s, &mut num_other)] = true);
+    mask
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        game::GameBuilder,
+        monsters::test::{AttackMonster, NoopMonster},
+    };
+
+    #[test]
+    fn test_stable_layout() {
+        let g1 = GameBuilder::default().seed(1).build();
+        let g2 = GameBuilder::default()
+            .seed(2)
+            .add_cards_upgraded(CardClass::Bash, 2)
+            .build_combat_with_monsters(NoopMonster::new(), AttackMonster::new(5));
+        assert_eq!(encode(&g1).len(), num_features());
+        assert_eq!(encode(&g2).len(), num_features());
+        assert_eq!(feature_names().len(), num_features());
+        assert_eq!(layout_hash(), compute_layout_hash());
+        let unique = feature_names()
+            .iter()
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(unique.len(), num_features());
+    }
+
+    #[test]
+    fn test_features() {
+        let mut g = GameBuilder::default()
+            .add_cards_upgraded(CardClass::Bash, 2)
+            .add_cards(CardClass::Strike, 3)
+            .add_player_status(Status::Strength, 3)
+            .add_relic(RelicClass::BurningBlood)
+            .build_combat_with_monster(AttackMonster::new(7));
+        g.potions[1] = Some(Potion::Fire);
+        let f = encode(&g);
+        let get = |name: &str| f[feature_names().iter().position(|n| n == name).unwrap()];
+        assert_eq!(get("deck.Bash+"), 2.0);
+        assert_eq!(get("deck.Bash"), 0.0);
+        assert_eq!(get("deck.Strike"), 3.0);
+        assert_eq!(get("hand.Strike") + get("draw.Strike"), 3.0);
+        assert_eq!(get("player.status.Strength"), 3.0);
+        assert_eq!(get("player.hp"), g.player.cur_hp as f32);
+        assert_eq!(get("energy"), 3.0);
+        assert_eq!(get("act"), 1.0);
+        assert_eq!(get("combat.Normal"), 1.0);
+        assert_eq!(get("relic.BurningBlood"), 1.0);
+        assert_eq!(get("relic.Anchor"), 0.0);
+        assert_eq!(get("monster0.present"), 1.0);
+        assert_eq!(get("monster0.intent0"), 1.0);
+        assert_eq!(get("monster0.intent_damage"), 7.0);
+        assert_eq!(get("monster0.intent_hits"), 1.0);
+        assert_eq!(get("monster1.present"), 0.0);
+        assert_eq!(get("potion1.Fire"), 1.0);
+        assert_eq!(get("potion0.Fire"), 0.0);
+    }
+
+    #[test]
+    fn test_actions_round_trip() {
+        let mut g = GameBuilder::default()
+            .add_cards(CardClass::Strike, 5)
+            .build_combat_with_monsters(NoopMonster::new(), NoopMonster::new());
+        g.potions[0] = Some(Potion::Fire);
+        g.potions.push(None);
+        g.potions[2] = Some(Potion::Block);
+        let steps = g.valid_steps();
+        let actions = encode_steps(&steps);
+        let mask = action_mask(&g);
+        assert_eq!(mask.iter().filter(|m| **m).count(), steps.len());
+        for (i, &a) in actions.iter().enumerate() {
+            assert!(mask[a]);
+            assert_eq!(decode_action(&g, a), Some(i));
+        }
+        assert_eq!(decode_action(&g, 0), Some(0));
+        assert_eq!(action_name(actions[1]), "play card 0 on monster 0");
+        // strike on monster 1
+        assert!(mask[PLAY_CARD_START + 2]);
+        assert!(mask[USE_POTION_START + 2]);
+        assert!(mask[USE_POTION_START + 2 * NUM_TARGETS]);
+        assert!(mask[DISCARD_POTION_START + 2]);
+        assert!(!mask[DISCARD_POTION_START + 1]);
+        assert_eq!(decode_action(&g, DISCARD_POTION_START + 1), None);
+    }
+}
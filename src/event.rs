@@ -18,7 +18,7 @@ enum EventType {
     Event,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct RollEventGameState;
 
 impl GameState for RollEventGameState {
@@ -63,7 +63,7 @@ impl GameState for RollEventGameState {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct RollQuestionRoomGameState;
 
 impl GameState for RollQuestionRoomGameState {
@@ -10,18 +10,17 @@ use crate::{
     step::Step,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AccursedBlackSmithGameState;
 
 impl GameState for AccursedBlackSmithGameState {
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
-        if game.master_deck.iter().any(|c| c.borrow().can_upgrade()) {
+    fn valid_steps(&self, game: &Game, steps: &mut Steps) -> bool {
+        if game.master_deck.iter().any(|c| game.cards[c].can_upgrade()) {
             steps.push(UpgradeStep);
         }
         steps.push(RummageStep);
         steps.push(ContinueStep);
-        Some(steps)
+        true
     }
 }
 
@@ -72,7 +71,7 @@ mod tests {
             .build_with_game_state(AccursedBlackSmithGameState);
         g.step_test(UpgradeStep);
         g.step_test(ChooseUpgradeMasterStep { master_index: 0 });
-        assert_eq!(g.master_deck[0].borrow().upgrade_count, 1);
+        assert_eq!(g.cards[g.master_deck[0]].upgrade_count, 1);
     }
 
     #[test]
@@ -94,7 +93,7 @@ mod tests {
         let mut g = GameBuilder::default().build_with_game_state(AccursedBlackSmithGameState);
         g.step_test(RummageStep);
         assert_eq!(g.master_deck.len(), 1);
-        assert_eq!(g.master_deck[0].borrow().class, CardClass::Pain);
+        assert_eq!(g.cards[g.master_deck[0]].class, CardClass::Pain);
         assert!(g.has_relic(RelicClass::WarpedTongs));
     }
 }
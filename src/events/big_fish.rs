@@ -9,7 +9,7 @@ use crate::{
     step::Step,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct BigFishGameState {
     heal_amount: i32,
 }
@@ -23,12 +23,11 @@ impl BigFishGameState {
 }
 
 impl GameState for BigFishGameState {
-    fn valid_steps(&self, _: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
+    fn valid_steps(&self, _: &Game, steps: &mut Steps) -> bool {
         steps.push(BananaStep(self.heal_amount));
         steps.push(DonutStep);
         steps.push(BoxStep);
-        Some(steps)
+        true
     }
 }
 
@@ -10,21 +10,20 @@ use crate::{
     step::Step,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct BonfireGameState;
 
 impl GameState for BonfireGameState {
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
+    fn valid_steps(&self, game: &Game, steps: &mut Steps) -> bool {
         for (i, c) in game.master_deck.iter().enumerate() {
-            if c.borrow().can_remove_from_master_deck() {
+            if game.cards[c].can_remove_from_master_deck() {
                 steps.push(OfferStep { master_index: i });
             }
         }
-        if steps.steps.is_empty() {
+        if steps.is_empty() {
             steps.push(ContinueStep);
         }
-        Some(steps)
+        true
     }
 }
 
@@ -38,7 +37,7 @@ impl Step for OfferStep {
         true
     }
     fn run(&self, game: &mut Game) {
-        let class = game.master_deck.remove(self.master_index).borrow().class;
+        let class = game.cards[game.master_deck.remove(self.master_index)].class;
         game.action_queue
             .push_bot(RemovedCardFromMasterDeckAction(class));
         match class.rarity() {
@@ -66,7 +65,10 @@ impl Step for OfferStep {
         game.state.push_state(RunActionsGameState);
     }
     fn description(&self, game: &Game) -> String {
-        format!("offer {:?}", game.master_deck[self.master_index].borrow())
+        format!(
+            "offer {:?}",
+            &game.cards[game.master_deck[self.master_index]]
+        )
     }
 }
 
@@ -11,14 +11,14 @@ use crate::{
     step::Step,
 };
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 enum Reward {
     Nothing,
     Relic,
     Gold,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DeadAdventurerGameState {
     combat: Combat,
     encounter_chance: i32,
@@ -41,8 +41,7 @@ impl DeadAdventurerGameState {
 }
 
 impl GameState for DeadAdventurerGameState {
-    fn valid_steps(&self, _: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
+    fn valid_steps(&self, _: &Game, steps: &mut Steps) -> bool {
         if !self.rewards.is_empty() {
             steps.push(SearchStep {
                 combat: self.combat,
@@ -51,7 +50,7 @@ impl GameState for DeadAdventurerGameState {
             });
         }
         steps.push(ContinueStep);
-        Some(steps)
+        true
     }
 }
 
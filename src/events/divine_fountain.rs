@@ -6,17 +6,16 @@ use crate::{
     step::Step,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DivineFountainGameState;
 
 impl GameState for DivineFountainGameState {
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
+    fn valid_steps(&self, game: &Game, steps: &mut Steps) -> bool {
         if game.has_removable_cards() {
             steps.push(PurifyStep);
         }
         steps.push(ContinueStep);
-        Some(steps)
+        true
     }
 }
 
@@ -29,11 +28,10 @@ impl Step for PurifyStep {
     }
     fn run(&self, game: &mut Game) {
         for i in (0..game.master_deck.len()).rev() {
-            let c = game.master_deck[i].borrow();
+            let c = &game.cards[game.master_deck[i]];
             if c.class.ty() == CardType::Curse && c.can_remove_from_master_deck() {
-                drop(c);
                 let c = game.master_deck.remove(i);
-                game.chosen_cards.push(c);
+                game.chosen_cards.push(c, &game.cards);
             }
         }
         game.state.push_state(RemoveChosenCardsGameState);
@@ -5,15 +5,14 @@ use crate::{
     step::Step,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DuplicatorGameState;
 
 impl GameState for DuplicatorGameState {
-    fn valid_steps(&self, _: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
+    fn valid_steps(&self, _: &Game, steps: &mut Steps) -> bool {
         steps.push(DuplicateStep);
         steps.push(ContinueStep);
-        Some(steps)
+        true
     }
 }
 
@@ -47,6 +46,6 @@ mod tests {
         g.step_test(DuplicateStep);
         g.step_test(DuplicateCardInMasterStep { master_index: 2 });
         assert_eq!(g.master_deck.len(), 4);
-        assert_eq!(g.master_deck[3].borrow().class, CardClass::AscendersBane);
+        assert_eq!(g.cards[g.master_deck[3]].class, CardClass::AscendersBane);
     }
 }
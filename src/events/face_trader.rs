@@ -7,7 +7,7 @@ use crate::{
     step::Step,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct FaceTraderGameState {
     damage_amount: i32,
 }
@@ -21,14 +21,13 @@ impl FaceTraderGameState {
 }
 
 impl GameState for FaceTraderGameState {
-    fn valid_steps(&self, _: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
+    fn valid_steps(&self, _: &Game, steps: &mut Steps) -> bool {
         steps.push(TouchStep {
             damage_amount: self.damage_amount,
         });
         steps.push(TradeStep);
         steps.push(ContinueStep);
-        Some(steps)
+        true
     }
 }
 
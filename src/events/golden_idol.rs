@@ -10,7 +10,7 @@ use crate::{
     step::Step,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct GoldenIdolGameState {
     damage: i32,
     max_hp_loss: i32,
@@ -26,14 +26,13 @@ impl GoldenIdolGameState {
 }
 
 impl GameState for GoldenIdolGameState {
-    fn valid_steps(&self, _: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
+    fn valid_steps(&self, _: &Game, steps: &mut Steps) -> bool {
         steps.push(TakeStep {
             damage: self.damage,
             max_hp_loss: self.max_hp_loss,
         });
         steps.push(ContinueStep);
-        Some(steps)
+        true
     }
 }
 
@@ -61,19 +60,18 @@ impl Step for TakeStep {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct GoldenIdolTakeGameState {
     damage: i32,
     max_hp_loss: i32,
 }
 
 impl GameState for GoldenIdolTakeGameState {
-    fn valid_steps(&self, _: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
+    fn valid_steps(&self, _: &Game, steps: &mut Steps) -> bool {
         steps.push(OutrunStep);
         steps.push(SmashStep(self.damage));
         steps.push(HideStep(self.max_hp_loss));
-        Some(steps)
+        true
     }
 }
 
@@ -145,7 +143,7 @@ mod tests {
         g.step(0);
         assert_eq!(g.relics[0].get_class(), RelicClass::GoldenIdol);
         g.step(0);
-        assert_eq!(g.master_deck[0].borrow().class, CardClass::Injury);
+        assert_eq!(g.cards[g.master_deck[0]].class, CardClass::Injury);
     }
 
     #[test]
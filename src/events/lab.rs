@@ -2,7 +2,7 @@ use crate::{
     game::Game, potion::random_potion_weighted, rewards::RewardsGameState, state::GameState,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct LabGameState;
 
 impl GameState for LabGameState {
@@ -8,16 +8,15 @@ use crate::{
     step::Step,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct LivingWallGameState;
 
 impl GameState for LivingWallGameState {
-    fn valid_steps(&self, _: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
+    fn valid_steps(&self, _: &Game, steps: &mut Steps) -> bool {
         steps.push(ForgetStep);
         steps.push(ChangeStep);
         steps.push(GrowStep);
-        Some(steps)
+        true
     }
 }
 
@@ -125,7 +125,7 @@ impl Event {
         use Event::*;
         match self {
             DivineFountain => game.master_deck.iter().any(|c| {
-                let c = c.borrow();
+                let c = &game.cards[c];
                 c.can_remove_from_master_deck() && c.class.ty() == CardType::Curse
             }),
             FaceTrader => game.is_in_act(1),
@@ -9,15 +9,14 @@ use crate::{
     step::Step,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct MushroomsGameState;
 
 impl GameState for MushroomsGameState {
-    fn valid_steps(&self, _: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
+    fn valid_steps(&self, _: &Game, steps: &mut Steps) -> bool {
         steps.push(StompStep);
         steps.push(EatStep);
-        Some(steps)
+        true
     }
 }
 
@@ -96,7 +95,7 @@ mod tests {
         g.step_test(AscendStep::new(0, 0));
         g.step(1);
         assert_eq!(g.master_deck.len(), 1);
-        assert_eq!(g.master_deck[0].borrow().class, CardClass::Parasite);
+        assert_eq!(g.cards[g.master_deck[0]].class, CardClass::Parasite);
         assert_eq!(g.player.cur_hp, 30 + 15);
     }
 }
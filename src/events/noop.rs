@@ -4,15 +4,14 @@ use crate::{
     step::Step,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct NoopEventGameState;
 
 impl GameState for NoopEventGameState {
-    fn valid_steps(&self, _: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
+    fn valid_steps(&self, _: &Game, steps: &mut Steps) -> bool {
         steps.push(Continue2Step);
         steps.push(ContinueStep);
-        Some(steps)
+        true
     }
 }
 
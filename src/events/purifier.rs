@@ -5,17 +5,16 @@ use crate::{
     step::Step,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct PurifierGameState;
 
 impl GameState for PurifierGameState {
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
+    fn valid_steps(&self, game: &Game, steps: &mut Steps) -> bool {
         if game.has_removable_cards() {
             steps.push(PurifyStep);
         }
         steps.push(ContinueStep);
-        Some(steps)
+        true
     }
 }
 
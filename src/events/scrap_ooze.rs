@@ -7,19 +7,18 @@ use crate::{
     step::Step,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ScrapOozeGameState {
     pub relic_chance: i32,
 }
 
 impl GameState for ScrapOozeGameState {
-    fn valid_steps(&self, _: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
+    fn valid_steps(&self, _: &Game, steps: &mut Steps) -> bool {
         steps.push(ReachStep {
             relic_chance: self.relic_chance,
         });
         steps.push(ContinueStep);
-        Some(steps)
+        true
     }
 }
 
@@ -5,7 +5,7 @@ use crate::{
     step::Step,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ShiningLightGameState {
     lose_hp_amount: i32,
 }
@@ -19,13 +19,12 @@ impl ShiningLightGameState {
 }
 
 impl GameState for ShiningLightGameState {
-    fn valid_steps(&self, _: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
+    fn valid_steps(&self, _: &Game, steps: &mut Steps) -> bool {
         steps.push(EnterStep {
             lose_hp_amount: self.lose_hp_amount,
         });
         steps.push(ContinueStep);
-        Some(steps)
+        true
     }
 }
 
@@ -72,7 +71,7 @@ mod tests {
         g.step_test(AscendStep::new(0, 0));
         g.step(0);
         assert_eq!(g.player.cur_hp, 40 - 15);
-        assert_eq!(g.master_deck[0].borrow().upgrade_count, 1);
-        assert_eq!(g.master_deck[1].borrow().upgrade_count, 1);
+        assert_eq!(g.cards[g.master_deck[0]].upgrade_count, 1);
+        assert_eq!(g.cards[g.master_deck[1]].upgrade_count, 1);
     }
 }
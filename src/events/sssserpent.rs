@@ -8,15 +8,14 @@ use crate::{
     step::Step,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SssserpentGameState;
 
 impl GameState for SssserpentGameState {
-    fn valid_steps(&self, _: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
+    fn valid_steps(&self, _: &Game, steps: &mut Steps) -> bool {
         steps.push(AgreeStep);
         steps.push(ContinueStep);
-        Some(steps)
+        true
     }
 }
 
@@ -56,7 +55,7 @@ mod tests {
         g.step_test(AscendStep::new(0, 0));
         g.step_test(AgreeStep);
         assert_eq!(g.master_deck.len(), 1);
-        assert_eq!(g.master_deck[0].borrow().class, CardClass::Doubt);
+        assert_eq!(g.cards[g.master_deck[0]].class, CardClass::Doubt);
         assert_eq!(g.gold, 150);
     }
 }
@@ -5,17 +5,16 @@ use crate::{
     step::Step,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TransmorgrifierGameState;
 
 impl GameState for TransmorgrifierGameState {
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
+    fn valid_steps(&self, game: &Game, steps: &mut Steps) -> bool {
         if game.has_removable_cards() {
             steps.push(TransformStep);
         }
         steps.push(ContinueStep);
-        Some(steps)
+        true
     }
 }
 
@@ -5,17 +5,16 @@ use crate::{
     step::Step,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct UpgradeShrineGameState;
 
 impl GameState for UpgradeShrineGameState {
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
+    fn valid_steps(&self, game: &Game, steps: &mut Steps) -> bool {
         if game.has_upgradable_cards() {
             steps.push(UpgradeStep);
         }
         steps.push(ContinueStep);
-        Some(steps)
+        true
     }
 }
 
@@ -49,6 +48,6 @@ mod tests {
         g.step_test(UpgradeStep);
         g.step_test(ChooseUpgradeMasterStep { master_index: 0 });
         assert_eq!(g.master_deck.len(), 3);
-        assert_eq!(g.master_deck[0].borrow().upgrade_count, 1);
+        assert_eq!(g.cards[g.master_deck[0]].upgrade_count, 1);
     }
 }
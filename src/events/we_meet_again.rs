@@ -9,7 +9,7 @@ use crate::{
     step::Step,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct WeMeetAgainGameState {
     potion_index: Option<usize>,
     master_deck_index: Option<usize>,
@@ -36,8 +36,8 @@ impl WeMeetAgainGameState {
             .iter()
             .enumerate()
             .filter(|(_, c)| {
-                c.borrow().class.rarity() != CardRarity::Basic
-                    && c.borrow().class.ty() != CardType::Curse
+                game.cards[*c].class.rarity() != CardRarity::Basic
+                    && game.cards[*c].class.ty() != CardType::Curse
             })
             .map(|e| e.0)
             .collect::<Vec<_>>();
@@ -64,8 +64,7 @@ impl WeMeetAgainGameState {
 }
 
 impl GameState for WeMeetAgainGameState {
-    fn valid_steps(&self, _: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
+    fn valid_steps(&self, _: &Game, steps: &mut Steps) -> bool {
         if let Some(i) = self.potion_index {
             steps.push(OfferPotionStep { potion_index: i });
         }
@@ -78,7 +77,7 @@ impl GameState for WeMeetAgainGameState {
             steps.push(OfferGoldStep { gold: i });
         }
         steps.push(ContinueStep);
-        Some(steps)
+        true
     }
 }
 
@@ -110,13 +109,13 @@ impl Step for OfferCardStep {
     }
     fn run(&self, game: &mut Game) {
         game.chosen_cards
-            .push(game.master_deck.remove(self.master_deck_index));
+            .push(game.master_deck.remove(self.master_deck_index), &game.cards);
         game.state.push_state(RemoveChosenCardsGameState);
     }
     fn description(&self, game: &Game) -> String {
         format!(
             "offer {:?}",
-            game.master_deck[self.master_deck_index].borrow()
+            &game.cards[game.master_deck[self.master_deck_index]]
         )
     }
 }
@@ -171,7 +170,7 @@ mod tests {
             .add_cards(CardClass::AscendersBane, 1)
             .add_cards(CardClass::Cleave, 1)
             .build_with_rooms(&[RoomType::Event]);
-        g.master_deck[1].borrow_mut().is_bottled = true;
+        g.update_card(g.master_deck[1], |c| c.is_bottled = true);
         g.override_event_queue.push(Event::WeMeetAgain);
         g.step_test(AscendStep::new(0, 0));
         g.step_test(OfferCardStep {
@@ -7,17 +7,16 @@ use crate::{
     step::Step,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct WomanInBlueGameState;
 
 impl GameState for WomanInBlueGameState {
-    fn valid_steps(&self, _: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
+    fn valid_steps(&self, _: &Game, steps: &mut Steps) -> bool {
         steps.push(BuyOnePotionStep);
         steps.push(BuyTwoPotionsStep);
         steps.push(BuyThreePotionsStep);
         steps.push(LeaveStep);
-        Some(steps)
+        true
     }
 }
 
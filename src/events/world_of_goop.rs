@@ -7,7 +7,7 @@ use crate::{
     step::Step,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct WorldOfGoopGameState {
     lose_gold_amount: i32,
 }
@@ -21,14 +21,13 @@ impl WorldOfGoopGameState {
 }
 
 impl GameState for WorldOfGoopGameState {
-    fn valid_steps(&self, _: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
+    fn valid_steps(&self, _: &Game, steps: &mut Steps) -> bool {
         steps.push(GatherStep);
         steps.push(LeaveStep {
             lose_gold_amount: self.lose_gold_amount,
         });
         steps.push(ContinueStep);
-        Some(steps)
+        true
     }
 }
 
@@ -1,12 +1,14 @@
-use std::cell::RefCell;
-use std::collections::HashMap;
-use std::rc::Rc;
+use std::{
+    collections::{HashMap, VecDeque},
+    io,
+};
 
-use rand::RngExt;
 use rand::seq::SliceRandom;
+use rand::{RngExt, SeedableRng};
 
 use crate::action::Action;
 use crate::actions::block::BlockAction;
+use crate::actions::create_card_in_discard::CreateCardInDiscardAction;
 use crate::actions::damage::{DamageAction, DamageType};
 use crate::actions::discard_card::DiscardCardAction;
 use crate::actions::draw::DrawAction;
@@ -16,19 +18,19 @@ use crate::actions::gain_gold::GainGoldAction;
 use crate::actions::gain_relic::GainRelicAction;
 use crate::actions::gain_status::GainStatusAction;
 use crate::actions::heal::HealAction;
+use crate::actions::place_card_in_hand::PlaceCardInHandAction;
 use crate::actions::play_card::PlayCardAction;
 use crate::actions::reduce_status::ReduceStatusAction;
 use crate::actions::remove_status::RemoveStatusAction;
 use crate::actions::use_potion::UsePotionAction;
 use crate::blessings::ChooseBlessingGameState;
 use crate::campfire::CampfireGameState;
-use crate::card::{Card, CardPile, CardRef};
+use crate::card::{Card, CardArena, CardDrawPile, CardPile, CardRef};
 use crate::cards::{CardClass, CardCost, CardRarity, CardType};
 use crate::chest::{ChestSize, ClosedChestGameState};
 use crate::combat::RollEliteCombatGameState;
-use crate::combat::{RollBossCombatGameState, RollCombatGameState};
+use crate::combat::{FixedCombatGameState, RollBossCombatGameState, RollCombatGameState};
 use crate::creature::{Creature, CreatureState};
-use crate::draw_pile::DrawPile;
 use crate::event::RollQuestionRoomGameState;
 use crate::events::Event;
 use crate::map::{MAP_WIDTH, Map, RoomType};
@@ -37,7 +39,7 @@ use crate::monster::MonsterBehavior;
 use crate::monster::{Monster, MonsterInfo};
 use crate::monsters::Combat;
 use crate::potion::Potion;
-use crate::queue::ActionQueue;
+use crate::queue::{ActionQueue, Cause};
 use crate::relic::{
     Relic, RelicClass, RelicRarity, all_boss_relics, all_common_relics, all_rare_relics,
     all_shop_relics, all_uncommon_relics,
@@ -48,10 +50,11 @@ use crate::shop::{Shop, ShopGameState};
 use crate::state::{GameState, GameStateManager, Steps};
 use crate::status::Status;
 use crate::step::Step;
+use crate::trajectory::RecorderSlot;
 
-pub type Rand = rand::rngs::ThreadRng;
+pub type Rand = rand::rngs::SmallRng;
 
-#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct CreatureRef(usize);
 
 impl CreatureRef {
@@ -80,7 +83,7 @@ impl std::fmt::Debug for CreatureRef {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct GameStartGameState;
 
 impl GameState for GameStartGameState {
@@ -91,7 +94,7 @@ impl GameState for GameStartGameState {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg(test)]
 struct TestStartNoBlessingGameState;
 
@@ -103,7 +106,7 @@ impl GameState for TestStartNoBlessingGameState {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg(test)]
 struct TestCombatStartGameState;
 
@@ -124,7 +127,7 @@ fn unceasing_top_should_trigger(game: &Game) -> bool {
         && (!game.draw_pile.is_empty() || !game.discard_pile.is_empty())
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct RunActionsGameState;
 
 impl GameState for RunActionsGameState {
@@ -138,31 +141,35 @@ impl GameState for RunActionsGameState {
         }
         if let Some(a) = game.action_queue.pop() {
             a.run(game);
+            game.action_queue.finish_running();
         } else if !game.card_queue.is_empty() {
-            let play = game.card_queue.remove(0);
+            let play = game.card_queue.pop_front().unwrap();
             if game.combat_finished() {
                 return;
             }
+            let parent = play.parent;
             if game.can_play_card(&play) {
-                game.action_queue.push_bot(play);
+                game.action_queue.push_bot_with_parent(parent, play);
             } else if !play.is_duplicated {
                 if play.force_exhaust {
-                    game.action_queue.push_bot(ExhaustCardAction(play.card));
+                    game.action_queue
+                        .push_bot_with_parent(parent, ExhaustCardAction(play.card));
                 } else {
-                    game.action_queue.push_bot(DiscardCardAction(play.card));
+                    game.action_queue
+                        .push_bot_with_parent(parent, DiscardCardAction(play.card));
                 }
             }
         } else if !game.monster_turn_queue_active.is_empty() {
-            let monster = game.monster_turn_queue_active.remove(0);
-            if !game.get_creature(monster).is_actionable() {
+            let monster = game.monster_turn_queue_active.pop_front().unwrap();
+            if !game.get_creature(monster).takes_turns() {
                 return;
             }
             let mi = game.calculate_monster_info();
-            game.monsters[monster.monster_index()].behavior.take_turn(
-                monster,
-                &mut game.action_queue,
-                &mi,
-            );
+            let m = &mut game.monsters[monster.monster_index()];
+            game.action_queue
+                .with_cause(Cause::Monster(monster), |queue| {
+                    m.update_behavior(|b, _| b.take_turn(monster, queue, &mi))
+                });
         } else if unceasing_top_should_trigger(game) {
             game.action_queue.push_bot(DrawAction(1));
         }
@@ -191,6 +198,9 @@ impl AscendStep {
             use_wing_boots: true,
         }
     }
+    pub fn pos(&self) -> (usize, usize) {
+        (self.x, self.y)
+    }
 }
 
 impl Step for AscendStep {
@@ -227,12 +237,11 @@ impl Step for AscendStep {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct AscendGameState;
 
 impl GameState for AscendGameState {
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
+    fn valid_steps(&self, game: &Game, steps: &mut Steps) -> bool {
         match game.map_position {
             Some(p) => {
                 for e in &game.map.nodes[p.0][p.1].edges {
@@ -259,11 +268,11 @@ impl GameState for AscendGameState {
                 }
             }
         }
-        Some(steps)
+        true
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct EnterActGameState;
 
 impl GameState for EnterActGameState {
@@ -378,7 +387,7 @@ impl GameState for EnterActGameState {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct RollCampfireGameState;
 
 impl GameState for RollCampfireGameState {
@@ -395,7 +404,7 @@ impl GameState for RollCampfireGameState {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct RollShopGameState;
 
 impl GameState for RollShopGameState {
@@ -413,7 +422,7 @@ impl GameState for RollShopGameState {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct RollTreasureGameState;
 
 impl GameState for RollTreasureGameState {
@@ -430,7 +439,7 @@ impl GameState for RollTreasureGameState {
 }
 
 #[allow(dead_code)]
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct VictoryGameState;
 
 impl GameState for VictoryGameState {
@@ -439,7 +448,7 @@ impl GameState for VictoryGameState {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct DefeatGameState;
 
 impl GameState for DefeatGameState {
@@ -508,14 +517,49 @@ impl Step for DiscardPotionStep {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum GameStatus {
     Defeat,
     Victory,
     Combat,
+    // a limit was exceeded, most likely by an infinite loop, so the run was
+    // stopped; `dump` shows what was still queued up
+    Aborted { limit: Limit, dump: String },
+}
+
+impl GameStatus {
+    pub fn is_over(&self) -> bool {
+        !matches!(self, GameStatus::Combat)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Limit {
+    ActionsPerDecision,
+    CardsPerTurn,
+    TurnsPerCombat,
+}
+
+// Bounds on how long the game runs, so a bug or a true infinite can't hang a
+// batch run.
+#[derive(Debug, Clone, Copy)]
+pub struct Limits {
+    // states and actions run before the player gets to choose again
+    pub actions_per_decision: usize,
+    pub cards_per_turn: i32,
+    pub turns_per_combat: i32,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            actions_per_decision: 100_000,
+            cards_per_turn: 500,
+            turns_per_combat: 500,
+        }
+    }
 }
 
-#[derive(Default)]
 #[allow(unused)]
 pub struct GameBuilder {
     master_deck: Vec<(CardClass, bool)>,
@@ -524,10 +568,30 @@ pub struct GameBuilder {
     player_statuses: HashMap<Status, i32>,
     relics: Vec<RelicClass>,
     player_hp: Option<i32>,
+    limits: Limits,
     rng: Rand,
 }
 
+impl Default for GameBuilder {
+    fn default() -> Self {
+        Self {
+            master_deck: Default::default(),
+            force_monsters: Default::default(),
+            monster_statuses: Default::default(),
+            player_statuses: Default::default(),
+            relics: Default::default(),
+            player_hp: Default::default(),
+            limits: Default::default(),
+            rng: rand::make_rng(),
+        }
+    }
+}
+
 impl GameBuilder {
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.rng = Rand::seed_from_u64(seed);
+        self
+    }
     pub fn ironclad_starting_deck(mut self) -> Self {
         for _ in 0..5 {
             self.master_deck.push((CardClass::Strike, false));
@@ -539,6 +603,9 @@ impl GameBuilder {
         self.master_deck.push((CardClass::AscendersBane, false));
         self
     }
+    pub fn master_deck(&self) -> &[(CardClass, bool)] {
+        &self.master_deck
+    }
     pub fn add_card(mut self, c: CardClass) -> Self {
         self.master_deck.push((c, false));
         self
@@ -577,6 +644,10 @@ impl GameBuilder {
         self.relics.push(relic);
         self
     }
+    pub fn limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
     #[cfg(test)]
     pub fn set_player_hp(mut self, amount: i32) -> Self {
         self.player_hp = Some(amount);
@@ -657,9 +728,13 @@ impl GameBuilder {
     pub fn build(self) -> Game {
         self.build_with_game_state(GameStartGameState)
     }
+    pub fn build_fixed_combat(self, combat: Combat) -> Game {
+        self.build_with_game_state(FixedCombatGameState(combat))
+    }
     pub fn build_with_game_state<T: GameState + 'static>(self, start_state: T) -> Game {
         let mut g = Game::new(self.rng, &self.master_deck);
         g.force_monsters = self.force_monsters;
+        g.limits = self.limits;
         for (&k, &v) in &self.player_statuses {
             g.player.set_status(k, v);
         }
@@ -670,6 +745,7 @@ impl GameBuilder {
         g.run();
         if let Some(hp) = self.player_hp {
             g.player.cur_hp = hp;
+            g.player.rehash();
         }
         g.state.push_state(start_state);
         g.run();
@@ -697,7 +773,7 @@ macro_rules! trigger_card {
     };
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CombatType {
     None,
     Normal,
@@ -713,6 +789,9 @@ pub enum RareCardBaseChance {
     Boss,
 }
 
+// A copy plays out the same as the original from here on, but isn't recorded
+// or traced.
+#[derive(Clone)]
 pub struct Game {
     pub rng: Rand,
 
@@ -734,7 +813,8 @@ pub struct Game {
     pub potions: Vec<Option<Potion>>,
     pub gold: i32,
     pub draw_per_turn: i32,
-    pub master_deck: CardPile,
+    pub cards: CardArena,
+    pub master_deck: Vec<CardRef>,
     next_id: u32,
     pub force_monsters: Option<Vec<Monster>>,
     pub roll_noop_monsters: bool,
@@ -777,18 +857,21 @@ pub struct Game {
     pub monsters: Vec<Monster>,
     pub smoke_bombed: bool,
     pub energy: i32,
-    pub draw_pile: DrawPile<CardRef>,
+    pub draw_pile: CardDrawPile,
     pub hand: CardPile,
     pub discard_pile: CardPile,
     pub exhaust_pile: CardPile,
     pub cur_card: Option<CardRef>,
-    pub card_queue: Vec<PlayCardAction>,
+    pub card_queue: VecDeque<PlayCardAction>,
     pub monster_turn_queue_all: Vec<CreatureRef>,
-    pub monster_turn_queue_active: Vec<CreatureRef>,
+    pub monster_turn_queue_active: VecDeque<CreatureRef>,
     pub should_add_extra_decay_status: bool,
     pub num_cards_played_this_turn: i32,
     pub num_times_took_damage: i32,
-    pub chosen_cards: Vec<CardRef>,
+    pub chosen_cards: CardPile,
+
+    pub recorder: RecorderSlot,
+    pub limits: Limits,
 }
 
 impl Game {
@@ -859,6 +942,9 @@ impl Game {
             rng,
             state: Default::default(),
             chosen_cards: Default::default(),
+            recorder: Default::default(),
+            limits: Default::default(),
+            cards: Default::default(),
             next_id: 1,
             status: GameStatus::Combat,
             is_running: false,
@@ -882,6 +968,7 @@ impl Game {
             g.master_deck.push(card);
         }
         g.player.cur_hp = (g.player.cur_hp as f32 * 0.9) as i32;
+        g.player.rehash();
 
         g
     }
@@ -916,29 +1003,49 @@ impl Game {
             let cost = c.get_base_cost();
             c.update_cost((cost - self.num_times_took_damage).max(0));
         }
-        Rc::new(RefCell::new(c))
+        self.cards.insert(c)
     }
 
     pub fn new_card_upgraded(&mut self, class: CardClass) -> CardRef {
         let c = self.new_card(class);
-        c.borrow_mut().upgrade();
+        self.update_card(c, Card::upgrade);
         c
     }
 
-    pub fn clone_card_ref_same_id(&self, c: &CardRef) -> CardRef {
-        Rc::new(RefCell::new(c.borrow().clone()))
+    // Cards are only changed through here, so that the piles they're in can
+    // update their hashes.
+    pub fn update_card<T>(&mut self, c: CardRef, f: impl FnOnce(&mut Card) -> T) -> T {
+        let old = self.cards.key(c);
+        let ret = f(self.cards.get_mut(c));
+        let new = self.cards.key(c);
+        if old != new {
+            for pile in [
+                &mut self.hand,
+                &mut self.discard_pile,
+                &mut self.exhaust_pile,
+                &mut self.chosen_cards,
+            ] {
+                pile.rekey(c, old, new);
+            }
+            self.draw_pile.rekey(c, old, new);
+        }
+        ret
+    }
+
+    pub fn clone_card_ref_same_id(&mut self, c: &CardRef) -> CardRef {
+        let c = self.cards[c].clone();
+        self.cards.insert(c)
     }
 
     pub fn clone_card_ref_new_id(&mut self, c: &CardRef) -> CardRef {
-        let mut c = c.borrow().clone();
-        c.id = self.new_card_id(c.class);
-        Rc::new(RefCell::new(c))
+        let c = self.cards[c].clone();
+        self.clone_card_new_id(&c)
     }
 
     pub fn clone_card_new_id(&mut self, c: &Card) -> CardRef {
         let mut c = c.clone();
         c.id = self.new_card_id(c.class);
-        Rc::new(RefCell::new(c))
+        self.cards.insert(c)
     }
 
     pub fn add_hard_pool_combat(&mut self, combat: Combat, weight: i32) {
@@ -1014,9 +1121,18 @@ impl Game {
     }
 
     pub fn calculate_monster_info(&self) -> MonsterInfo {
+        let alive = self.get_alive_monsters();
         MonsterInfo {
-            num_alive_monsters: self.get_alive_monsters().len(),
+            num_alive_monsters: alive.len(),
             player_hp: self.player.cur_hp,
+            player_constricted: self.player.has_status(Status::Constricted),
+            monsters_missing_hp: alive
+                .iter()
+                .map(|&m| {
+                    let c = self.get_creature(m);
+                    c.max_hp - c.cur_hp
+                })
+                .sum(),
         }
     }
 
@@ -1026,31 +1142,7 @@ impl Game {
         source_ref: CreatureRef,
         target_ref: CreatureRef,
     ) -> i32 {
-        let mut amount_f = amount as f32;
-        let source = self.get_creature(source_ref);
-        let target = self.get_creature(target_ref);
-        if let Some(s) = source.get_status(Status::Strength) {
-            amount_f += s as f32;
-        }
-        if let Some(s) = source.get_status(Status::Vigor) {
-            amount_f += s as f32;
-        }
-        if source.has_status(Status::Weak) {
-            amount_f *= 0.75;
-        }
-        if source.has_status(Status::PenNib) {
-            amount_f *= 2.0;
-        }
-        if target.has_status(Status::Vulnerable) {
-            if target_ref.is_player() && self.has_relic(RelicClass::OddMushroom) {
-                amount_f *= 1.25;
-            } else if !target_ref.is_player() && self.has_relic(RelicClass::PaperPhrog) {
-                amount_f *= 1.75;
-            } else {
-                amount_f *= 1.5;
-            }
-        }
-        0.max(amount_f as i32)
+        self.calculate_damage_with(amount, source_ref, target_ref, |_| {})
     }
 
     pub fn damage(&mut self, target: CreatureRef, mut amount: i32, ty: DamageType) {
@@ -1086,48 +1178,33 @@ impl Game {
         }
         let was_bloodied = c.is_bloodied();
         let had_block = c.block != 0;
-        if c.has_status(Status::Intangible) {
-            amount = amount.min(1);
-        }
-        if ty != DamageType::HPLoss {
-            if c.block >= amount {
-                c.block -= amount;
-                amount = 0;
-            } else {
-                amount -= c.block;
-                c.block = 0;
-            }
-        }
-        amount = amount.min(c.cur_hp);
-        if amount != 0 && c.has_status(Status::Buffer) {
-            amount = 0;
+        let taken = self.damage_taken_with(target, amount, ty, |_| {});
+        let c = self.get_creature_mut(target);
+        c.block -= taken.blocked;
+        c.rehash();
+        if taken.buffered {
             self.action_queue.push_bot(ReduceStatusAction {
                 status: Status::Buffer,
                 amount: 1,
                 target,
             });
         }
-        if target.is_player()
-            && (1..=5).contains(&amount)
-            && matches!(ty, DamageType::Attack { .. })
-            && self.has_relic(RelicClass::Torii)
-        {
-            amount = 1;
-        }
-        if target.is_player() && self.has_relic(RelicClass::TungstenRod) {
-            amount = (amount - 1).max(0);
-        }
-        if !target.is_player() && amount > 0 && amount < 5 && self.has_relic(RelicClass::Boot) {
-            amount = 5;
-        }
+        let amount = taken.hp_lost;
         let c = self.get_creature_mut(target);
         c.last_damage_taken = amount;
         if amount != 0 {
             c.cur_hp -= amount;
+            c.rehash();
             if target.is_player() {
                 self.trigger_relics_on_lose_hp();
                 self.num_times_took_damage += 1;
             }
+            if let DamageType::Attack { source, .. } = ty
+                && self.get_creature(source).has_status(Status::PainfulStabs)
+            {
+                self.action_queue
+                    .push_bot(CreateCardInDiscardAction(CardClass::Wound));
+            }
             if let Some(amount) = self.get_creature(target).get_status(Status::Angry)
                 && matches!(ty, DamageType::Attack { .. })
             {
@@ -1163,6 +1240,43 @@ impl Game {
                     target,
                 });
             }
+            // blocks the rest of a multi-hit attack, so it goes on top
+            if matches!(ty, DamageType::Attack { .. })
+                && self.get_creature(target).cur_hp > 0
+                && let Some(v) = self.get_creature(target).get_status(Status::Malleable)
+            {
+                self.action_queue.push_top(BlockAction::monster(target, v));
+                self.get_creature_mut(target)
+                    .set_status(Status::Malleable, v + 1);
+            }
+            // strength comes back at the end of the turn
+            if self.get_creature(target).has_status(Status::Shifting)
+                && self.get_creature(target).cur_hp > 0
+            {
+                self.action_queue.push_top(GainStatusAction {
+                    status: Status::Strength,
+                    amount: -amount,
+                    target,
+                });
+                if !self.get_creature(target).has_status(Status::Artifact) {
+                    self.action_queue.push_top(GainStatusAction {
+                        status: Status::GainStrength,
+                        amount,
+                        target,
+                    });
+                }
+            }
+            // flight only comes down if the hit wasn't fatal
+            if matches!(ty, DamageType::Attack { .. })
+                && self.get_creature(target).has_status(Status::Flight)
+                && self.get_creature(target).cur_hp > 0
+            {
+                self.action_queue.push_bot(ReduceStatusAction {
+                    status: Status::Flight,
+                    amount: 1,
+                    target,
+                });
+            }
             if matches!(ty, DamageType::Attack { .. })
                 && let Some(v) = self.get_creature(target).get_status(Status::CurlUp)
             {
@@ -1174,24 +1288,22 @@ impl Game {
                 });
             }
 
-            let update_blood_for_blood_cost = |card: &CardRef| {
-                let mut c = card.borrow_mut();
-                if c.class == CardClass::BloodForBlood {
+            let blood_for_bloods = self
+                .hand
+                .iter()
+                .chain(&self.discard_pile)
+                .chain(self.draw_pile.get_all())
+                .copied()
+                .filter(|&c| self.cards[c].class == CardClass::BloodForBlood)
+                .collect::<Vec<_>>();
+            for c in blood_for_bloods {
+                self.update_card(c, |c| {
                     if let CardCost::Cost { base_cost, .. } = c.cost {
                         c.update_cost(0.max(base_cost - 1));
                     } else {
                         panic!();
                     }
-                }
-            };
-            for c in &self.hand {
-                update_blood_for_blood_cost(c);
-            }
-            for c in &self.discard_pile {
-                update_blood_for_blood_cost(c);
-            }
-            for c in self.draw_pile.get_all() {
-                update_blood_for_blood_cost(c);
+                });
             }
             {
                 let c = self.get_creature_mut(target);
@@ -1201,7 +1313,8 @@ impl Game {
             }
             if !target.is_player() {
                 let m = &mut self.monsters[target.monster_index()];
-                m.behavior.on_take_damage(target, &mut m.creature);
+                let rng = &mut self.rng;
+                m.update_behavior(|b, c| b.on_take_damage(target, c, rng));
             }
         }
 
@@ -1221,7 +1334,32 @@ impl Game {
                     self.action_queue.push_bot(GainEnergyAction(1));
                     self.action_queue.push_bot(DrawAction(1));
                 }
-                self.monster_turn_queue_all.retain(|c| *c != target);
+                let regrows = self.get_creature(target).has_status(Status::Regrow);
+                let reviving = if regrows {
+                    self.will_regrow(target)
+                } else {
+                    let m = &mut self.monsters[target.monster_index()];
+                    m.update_behavior(|b, c| b.on_rebirth(target, c))
+                };
+                if reviving {
+                    let c = self.get_creature_mut(target);
+                    c.cur_hp = 0;
+                    if regrows {
+                        c.clear_all_status();
+                    }
+                    c.state = CreatureState::Reviving;
+                    c.rehash();
+                } else {
+                    self.on_monster_death(target);
+                    // the rest of the group was waiting on this one to revive them
+                    if regrows {
+                        for i in 0..self.monsters.len() {
+                            if self.monsters[i].creature.state == CreatureState::Reviving {
+                                self.kill_monster(CreatureRef::monster(i));
+                            }
+                        }
+                    }
+                }
             } else if !self.has_relic(RelicClass::MarkOfTheBloom) {
                 if let Some(i) = self.potions.iter().position(|p| *p == Some(Potion::Fairy)) {
                     self.take_potion(i);
@@ -1240,8 +1378,9 @@ impl Game {
             }
             {
                 let c = self.get_creature_mut(target);
-                if c.cur_hp <= 0 {
+                if c.cur_hp <= 0 && c.is_actionable() {
                     c.state = CreatureState::Dead;
+                    c.rehash();
                 }
             }
         }
@@ -1274,24 +1413,57 @@ impl Game {
         }
     }
 
+    fn exceeded_limit(&self, actions: usize) -> Option<Limit> {
+        if actions > self.limits.actions_per_decision {
+            Some(Limit::ActionsPerDecision)
+        } else if self.num_cards_played_this_turn > self.limits.cards_per_turn {
+            Some(Limit::CardsPerTurn)
+        } else if self.in_combat != CombatType::None && self.turn >= self.limits.turns_per_combat {
+            Some(Limit::TurnsPerCombat)
+        } else {
+            None
+        }
+    }
+
+    fn abort(&mut self, limit: Limit, state: &dyn GameState) {
+        let dump = format!(
+            "running {state:?}\n{:?}\n{:?}\ncard queue: {:?}\nmonster turn queue: {:?}",
+            self.state, self.action_queue, self.card_queue, self.monster_turn_queue_active
+        );
+        self.status = GameStatus::Aborted { limit, dump };
+    }
+
     fn run(&mut self) {
-        assert!(!matches!(
-            self.status,
-            GameStatus::Defeat | GameStatus::Victory
-        ));
+        assert!(!self.status.is_over());
         assert!(!self.state.is_empty());
 
-        while !matches!(self.status, GameStatus::Defeat | GameStatus::Victory)
+        let mut actions = 0;
+        while !self.status.is_over()
             && let Some(state) = self.state.pop_state()
         {
             state.run(self);
-            if state.valid_steps(self).is_some() {
+            actions += 1;
+            if let Some(limit) = self.exceeded_limit(actions) {
+                self.abort(limit, state.as_ref());
+                break;
+            }
+            if state.valid_steps(self, &mut Steps::count()) {
                 self.state.push_boxed_state(state);
                 break;
             }
         }
     }
 
+    pub fn flush_trace(&mut self) -> io::Result<()> {
+        let header = format!("floor {}", self.floor);
+        if let Some(t) = self.action_queue.tracer_mut()
+            && !t.is_empty()
+        {
+            t.flush(&header)?;
+        }
+        Ok(())
+    }
+
     #[cfg(test)]
     pub fn add_card_to_master_deck(&mut self, class: CardClass) {
         use crate::actions::add_card_class_to_master_deck::AddCardClassToMasterDeckAction;
@@ -1317,8 +1489,18 @@ impl Game {
     }
 
     pub fn step(&mut self, step_index: usize) {
-        let step = self.valid_steps().remove(step_index);
+        let mut steps = Steps::select(step_index);
+        self.push_valid_steps(&mut steps);
+        let step = steps.into_selected().expect("invalid step index");
+        let mut recorder = self.recorder.0.take();
+        if let Some(r) = &mut recorder {
+            r.record_decision(self, step_index);
+        }
         self.step_impl(step);
+        if let Some(r) = &mut recorder {
+            r.after_step(self).expect("failed to write trajectory");
+        }
+        self.recorder.0 = recorder;
     }
 
     fn step_impl(&mut self, step: Box<dyn Step>) {
@@ -1335,7 +1517,7 @@ impl Game {
     }
 
     pub fn can_play_card(&self, play: &PlayCardAction) -> bool {
-        let c = play.card.borrow();
+        let c = &self.cards[play.card];
         let can_play_ty = match c.class.ty() {
             CardType::Attack => !self.player.has_status(Status::Entangled),
             CardType::Skill | CardType::Power => true,
@@ -1351,17 +1533,17 @@ impl Game {
             CardClass::Clash => self
                 .hand
                 .iter()
-                .all(|c| c.borrow().class.ty() == CardType::Attack),
+                .all(|c| self.cards[c].class.ty() == CardType::Attack),
             CardClass::SecretTechnique => self
                 .draw_pile
                 .get_all()
                 .iter()
-                .any(|c| c.borrow().class.ty() == CardType::Skill),
+                .any(|c| self.cards[*c].class.ty() == CardType::Skill),
             CardClass::SecretWeapon => self
                 .draw_pile
                 .get_all()
                 .iter()
-                .any(|c| c.borrow().class.ty() == CardType::Attack),
+                .any(|c| self.cards[*c].class.ty() == CardType::Attack),
             _ => true,
         };
         if !can_play_class {
@@ -1374,15 +1556,15 @@ impl Game {
             && self
                 .hand
                 .iter()
-                .any(|c| c.borrow().class == CardClass::Normality)
+                .any(|c| self.cards[c].class == CardClass::Normality)
         {
             return false;
         }
         play.free || self.energy >= play.cost
     }
 
-    pub fn valid_steps(&self) -> Vec<Box<dyn Step>> {
-        let mut steps = self.state.peek().valid_steps(self).unwrap();
+    fn push_valid_steps(&self, steps: &mut Steps) {
+        assert!(self.state.peek().valid_steps(self, steps));
         if self.cur_event != Some(Event::WeMeetAgain) {
             for (pi, p) in self.potions.iter().enumerate() {
                 if let Some(p) = p
@@ -1400,7 +1582,23 @@ impl Game {
                 }
             }
         }
-        steps.steps
+    }
+
+    pub fn valid_steps(&self) -> Vec<Box<dyn Step>> {
+        let mut steps = Steps::collect();
+        self.push_valid_steps(&mut steps);
+        steps.into_steps()
+    }
+
+    pub fn num_valid_steps(&self) -> usize {
+        let mut steps = Steps::count();
+        self.push_valid_steps(&mut steps);
+        steps.len()
+    }
+
+    // calls `f` on each valid step in order without boxing them
+    pub fn for_each_valid_step<F: FnMut(&dyn Step)>(&self, mut f: F) {
+        self.push_valid_steps(&mut Steps::visit(&mut f));
     }
 
     pub fn assert_no_actions(&self) {
@@ -1435,7 +1633,7 @@ impl Game {
         self.assert_no_actions();
         let action = PlayCardAction::new(card, target, self);
         assert!(self.can_play_card(&action));
-        self.card_queue.push(action);
+        self.card_queue.push_back(action);
         self.run_all_actions();
     }
 
@@ -1453,8 +1651,6 @@ impl Game {
 
     #[cfg(test)]
     pub fn add_card_to_hand(&mut self, class: CardClass) {
-        use crate::actions::place_card_in_hand::PlaceCardInHandAction;
-
         let card = self.new_card(class);
         self.run_action(PlaceCardInHandAction(card));
     }
@@ -1468,8 +1664,6 @@ impl Game {
 
     #[cfg(test)]
     pub fn add_card_to_hand_upgraded(&mut self, class: CardClass) {
-        use crate::actions::place_card_in_hand::PlaceCardInHandAction;
-
         let card = self.new_card_upgraded(class);
         self.run_action(PlaceCardInHandAction(card));
     }
@@ -1523,7 +1717,7 @@ impl Game {
         let cards = self
             .hand
             .iter()
-            .filter(|c| c.borrow().class == class)
+            .filter(|c| self.cards[*c].class == class)
             .collect::<Vec<_>>();
         assert_eq!(cards.len(), 1);
         cards[0]
@@ -1532,11 +1726,11 @@ impl Game {
     pub fn has_removable_cards(&self) -> bool {
         self.master_deck
             .iter()
-            .any(|c| c.borrow().can_remove_from_master_deck())
+            .any(|c| self.cards[c].can_remove_from_master_deck())
     }
 
     pub fn has_upgradable_cards(&self) -> bool {
-        self.master_deck.iter().any(|c| c.borrow().can_upgrade())
+        self.master_deck.iter().any(|c| self.cards[c].can_upgrade())
     }
 
     pub fn clear_all_piles(&mut self) {
@@ -1551,18 +1745,79 @@ impl Game {
         self.hand.len() as i32 == Game::MAX_HAND_SIZE
     }
 
+    // a monster waiting to come back keeps the combat going, see `Game::damage`
+    // for when it doesn't get to
     pub fn combat_finished(&self) -> bool {
-        self.monsters.iter().all(|m| !m.creature.is_actionable()) || self.smoke_bombed
+        self.monsters.iter().all(|m| !m.creature.takes_turns()) || self.smoke_bombed
     }
 
     pub fn no_monsters_targetable(&self) -> bool {
         self.monsters.iter().all(|m| !m.creature.is_actionable())
     }
 
+    // fix the draw pile to one random order it could be drawn in
+    pub fn determinize_draw_pile(&mut self) {
+        let order = self.draw_pile.sample_order(&mut self.rng);
+        self.draw_pile.install_order(&order);
+    }
+
+    // chance that a card matching `pred` is among the next n cards drawn,
+    // including the discard pile getting shuffled in if the draw pile runs out
+    pub fn chance_to_draw<F: Fn(&Card) -> bool>(&self, n: usize, pred: F) -> f64 {
+        let miss_draw_pile = self
+            .draw_pile
+            .count_distribution(n, |c| pred(&self.cards[c]))[0];
+        let total = self.discard_pile.len();
+        let matching = self
+            .discard_pile
+            .iter()
+            .filter(|c| pred(&self.cards[*c]))
+            .count();
+        let from_discard = n.saturating_sub(self.draw_pile.len()).min(total);
+        let mut miss_discard = 1.0;
+        for i in 0..from_discard {
+            miss_discard *= (total - matching).saturating_sub(i) as f64 / (total - i) as f64;
+        }
+        1.0 - miss_draw_pile * miss_discard
+    }
+
     pub fn monster_str(&self, c: CreatureRef) -> String {
-        let mut i = self.monsters[c.monster_index()].behavior.get_intent();
+        let m = &self.monsters[c.monster_index()];
+        let mut i = m.behavior.get_intent();
         i.modify_damage(c, self);
-        format!("{}, intent: {:?}", self.get_creature(c).str(), i)
+        let mut s = format!("{}, intent: {:?}", self.get_creature(c).str(), i);
+        if let Some(held) = m.held_card {
+            s += &format!(", holding {:?}", self.cards[held]);
+        }
+        s
+    }
+
+    fn on_monster_death(&mut self, target: CreatureRef) {
+        self.monster_turn_queue_all.retain(|c| *c != target);
+        let m = &mut self.monsters[target.monster_index()];
+        if let Some(c) = m.held_card.take() {
+            self.action_queue.push_top(PlaceCardInHandAction(c));
+        }
+        let queue = &mut self.action_queue;
+        m.update_behavior(|b, _| b.on_death(target, queue));
+    }
+
+    // regrowing monsters only stay down while another of them is still standing
+    fn will_regrow(&self, target: CreatureRef) -> bool {
+        self.monsters.iter().enumerate().any(|(i, m)| {
+            CreatureRef::monster(i) != target
+                && m.creature.is_actionable()
+                && m.creature.has_status(Status::Regrow)
+        })
+    }
+
+    // dies without taking damage, e.g. minions when their summoner dies
+    pub fn kill_monster(&mut self, target: CreatureRef) {
+        let c = self.get_creature_mut(target);
+        c.cur_hp = 0;
+        c.state = CreatureState::Dead;
+        c.rehash();
+        self.on_monster_death(target);
     }
 
     pub fn heal(&mut self, cref: CreatureRef, mut amount: i32) {
@@ -1708,14 +1963,18 @@ impl Game {
 #[cfg(test)]
 mod tests {
     use crate::{
+        assert_matches,
         blessings::{Blessing, ChooseBlessingStep},
         campfire::{CampfireRestStep, CampfireUpgradeStep},
+        card::Card,
         cards::CardClass,
+        combat::EndTurnStep,
         combat::PlayCardStep,
         events::Event,
-        game::{AscendStep, GameBuilder},
+        game::{AscendStep, CreatureRef, GameBuilder, GameStatus, Limit, Limits},
         map::{MAP_WIDTH, Map, RoomType},
         master_deck::ChooseUpgradeMasterStep,
+        monsters::test::NoopMonster,
         rewards::{BossRewardSkipStep, RewardExitStep},
         state::ContinueStep,
     };
@@ -1773,4 +2032,132 @@ mod tests {
         g.step(0);
         assert_eq!(g.floor, 18);
     }
+
+    #[test]
+    fn test_step_enumeration() {
+        let mut g = GameBuilder::default()
+            .add_cards(CardClass::Strike, 3)
+            .add_card(CardClass::Defend)
+            .build_combat();
+        g.add_potion(crate::potion::Potion::Fire);
+        let steps = g.valid_steps();
+        assert_eq!(g.num_valid_steps(), steps.len());
+        let mut visited = 0;
+        g.for_each_valid_step(|s| {
+            assert!(*steps[visited] == *s);
+            visited += 1;
+        });
+        assert_eq!(visited, steps.len());
+        for (i, s) in steps.iter().enumerate() {
+            let mut selected = crate::state::Steps::select(i);
+            g.push_valid_steps(&mut selected);
+            assert_eq!(&selected.into_selected().unwrap(), s);
+        }
+    }
+
+    #[test]
+    fn test_chance_to_draw() {
+        let mut g = GameBuilder::default()
+            .add_cards(CardClass::Strike, 6)
+            .add_card(CardClass::Bash)
+            .build_combat();
+        assert_eq!(g.draw_pile.len(), 2);
+        let is_bash = |c: &Card| c.class == CardClass::Bash;
+        let bash_in_draw_pile = g.draw_pile.get_all().iter().any(|c| is_bash(&g.cards[*c]));
+        let p = g.chance_to_draw(1, is_bash);
+        assert_eq!(p, if bash_in_draw_pile { 0.5 } else { 0.0 });
+
+        let hand = std::mem::take(&mut g.hand);
+        for &c in &hand {
+            g.discard_pile.push(c, &g.cards);
+        }
+        let p = g.chance_to_draw(3, is_bash);
+        assert!((p - if bash_in_draw_pile { 1.0 } else { 0.2 }).abs() < 1e-9);
+        assert_eq!(g.chance_to_draw(7, is_bash), 1.0);
+        assert_eq!(g.chance_to_draw(7, |c| c.class == CardClass::Defend), 0.0);
+    }
+
+    #[test]
+    fn test_game_is_send() {
+        let games = (0..4)
+            .map(|seed| {
+                std::thread::spawn(move || {
+                    let mut g = GameBuilder::default()
+                        .seed(seed)
+                        .ironclad_starting_deck()
+                        .build_combat_with_monster(NoopMonster::new());
+                    g.play_card(CardClass::Strike, Some(CreatureRef::monster(0)));
+                    g
+                })
+            })
+            .collect::<Vec<_>>();
+        for t in games {
+            let g = t.join().unwrap();
+            let m = g.get_creature(CreatureRef::monster(0));
+            assert_eq!(m.cur_hp, m.max_hp - 6);
+        }
+    }
+
+    #[test]
+    fn test_cards_per_turn_limit() {
+        let mut g = GameBuilder::default()
+            .add_cards(CardClass::TestSkill, 5)
+            .build_combat_with_monster(NoopMonster::new());
+        g.limits.cards_per_turn = 2;
+        for _ in 0..2 {
+            g.step_test(PlayCardStep {
+                hand_index: 0,
+                target: None,
+            });
+        }
+        assert_eq!(g.status, GameStatus::Combat);
+        g.step_test(PlayCardStep {
+            hand_index: 0,
+            target: None,
+        });
+        assert_matches!(
+            &g.status,
+            GameStatus::Aborted {
+                limit: Limit::CardsPerTurn,
+                ..
+            }
+        );
+    }
+
+    #[test]
+    fn test_turns_per_combat_limit() {
+        let mut g = GameBuilder::default()
+            .limits(Limits {
+                turns_per_combat: 3,
+                ..Default::default()
+            })
+            .build_combat_with_monster(NoopMonster::new());
+        for _ in 0..2 {
+            g.step_test(EndTurnStep);
+        }
+        assert_eq!(g.status, GameStatus::Combat);
+        g.step_test(EndTurnStep);
+        assert_matches!(
+            &g.status,
+            GameStatus::Aborted {
+                limit: Limit::TurnsPerCombat,
+                ..
+            }
+        );
+    }
+
+    #[test]
+    fn test_actions_per_decision_limit() {
+        let mut g = GameBuilder::default()
+            .add_cards(CardClass::Strike, 5)
+            .build_combat_with_monster(NoopMonster::new());
+        g.limits.actions_per_decision = 3;
+        g.step_test(EndTurnStep);
+        let GameStatus::Aborted { limit, dump } = &g.status else {
+            panic!("{:?}", g.status);
+        };
+        assert_eq!(*limit, Limit::ActionsPerDecision);
+        assert!(dump.contains("action queue"));
+        assert!(g.status.is_over());
+    }
 }
@@ -0,0 +1,571 @@
+use rand::SeedableRng;
+
+use crate::{
+    cards::CardClass,
+    encoding,
+    game::{CombatType, Game, GameBuilder, GameStatus, Limits, Rand},
+    monsters::Combat,
+    relic::RelicClass,
+    trace::Tracer,
+    trajectory::Recorder,
+};
+
+pub const ACTION_SPACE_SIZE: usize = encoding::ACTION_SPACE_SIZE;
+
+#[derive(Debug, Clone)]
+pub enum Episode {
+    // a whole run from neow, optionally cut off once the room on `last_floor` is done
+    FullRun {
+        last_floor: Option<i32>,
+    },
+    // a single fight, ending as soon as the combat is over
+    Combat {
+        combat: Combat,
+        deck: Vec<(CardClass, bool)>,
+        relics: Vec<RelicClass>,
+    },
+    // a whole run starting from whatever `build` sets up
+    Custom {
+        build: fn(GameBuilder) -> Game,
+    },
+}
+
+impl Episode {
+    // a fight with the ironclad's starting deck and relic
+    pub fn starting_deck_combat(combat: Combat) -> Self {
+        Episode::Combat {
+            combat,
+            deck: GameBuilder::default()
+                .ironclad_starting_deck()
+                .master_deck()
+                .to_vec(),
+            relics: vec![RelicClass::BurningBlood],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RewardConfig {
+    pub floor_reached: f32,
+    pub combat_won: f32,
+    pub hp_delta: f32,
+}
+
+impl Default for RewardConfig {
+    fn default() -> Self {
+        Self {
+            floor_reached: 1.0,
+            combat_won: 1.0,
+            hp_delta: 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EnvConfig {
+    pub episode: Episode,
+    pub rewards: RewardConfig,
+    pub limits: Limits,
+}
+
+impl Default for EnvConfig {
+    fn default() -> Self {
+        Self {
+            episode: Episode::FullRun { last_floor: None },
+            rewards: Default::default(),
+            limits: Default::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Observation {
+    pub features: Vec<f32>,
+    pub action_mask: Vec<bool>,
+    pub num_actions: usize,
+    pub floor: i32,
+    pub gold: i32,
+    pub player_hp: i32,
+    pub player_max_hp: i32,
+    pub in_combat: bool,
+    pub energy: i32,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Info {
+    pub floor: i32,
+    pub combats_won: i32,
+    pub victory: bool,
+    pub defeat: bool,
+    // stopped by `EnvConfig::limits`
+    pub aborted: bool,
+    pub truncated: bool,
+}
+
+#[derive(Default)]
+pub struct Env {
+    game: Option<Game>,
+    config: EnvConfig,
+    combats_won: i32,
+    recorder: Option<Recorder>,
+    tracer: Option<Tracer>,
+}
+
+impl Env {
+    pub fn reset(&mut self, seed: u64, config: EnvConfig) -> Observation {
+        let builder = GameBuilder::default().seed(seed).limits(config.limits);
+        let mut game = match &config.episode {
+            Episode::FullRun { .. } => builder
+                .ironclad_starting_deck()
+                .add_relic(RelicClass::BurningBlood)
+                .build(),
+            Episode::Combat {
+                combat,
+                deck,
+                relics,
+            } => {
+                let mut builder = builder;
+                for &(c, upgraded) in deck {
+                    builder = if upgraded {
+                        builder.add_card_upgraded(c)
+                    } else {
+                        builder.add_card(c)
+                    };
+                }
+                for &r in relics {
+                    builder = builder.add_relic(r);
+                }
+                builder.build_fixed_combat(*combat)
+            }
+            Episode::Custom { build } => build(builder),
+        };
+        game.recorder.0 = self.take_recorder();
+        if let Some(t) = self.take_tracer() {
+            game.action_queue.set_tracer(t);
+        }
+        self.game = Some(game);
+        self.config = config;
+        self.combats_won = 0;
+        self.observation()
+    }
+
+    pub fn step(&mut self, action: usize) -> (Observation, f32, bool, Info) {
+        assert!(!self.is_done(), "step() called on a finished episode");
+        let step_index = if action < ACTION_SPACE_SIZE {
+            encoding::decode_action(self.game(), action)
+        } else {
+            None
+        };
+        let step_index = step_index.unwrap_or_else(|| panic!("illegal action {action}"));
+
+        let game = self.game.as_mut().unwrap();
+        let floor_before = game.floor;
+        let hp_before = game.player.cur_hp;
+        let was_in_combat = game.in_combat != CombatType::None;
+
+        game.step(step_index);
+        // a combat is traced as a whole
+        let flush_trace = game.in_combat == CombatType::None || game.status.is_over();
+
+        let won_combat = was_in_combat
+            && game.in_combat == CombatType::None
+            && !matches!(game.status, GameStatus::Defeat | GameStatus::Aborted { .. });
+        if won_combat {
+            self.combats_won += 1;
+        }
+        if flush_trace {
+            self.flush_trace();
+        }
+
+        let game = self.game();
+        let rewards = &self.config.rewards;
+        let mut reward = rewards.floor_reached * (game.floor - floor_before) as f32
+            + rewards.hp_delta * (game.player.cur_hp - hp_before) as f32;
+        if won_combat {
+            reward += rewards.combat_won;
+        }
+
+        (self.observation(), reward, self.is_done(), self.info())
+    }
+
+    // An independent copy of the current episode. Not recorded or traced.
+    pub fn fork(&self) -> Env {
+        Env {
+            game: self.game.clone(),
+            config: self.config.clone(),
+            combats_won: self.combats_won,
+            recorder: None,
+            tracer: None,
+        }
+    }
+
+    // Replaces the game's rng, so that from here on draws and monster moves
+    // differ from the original episode. Used to sample possible futures.
+    pub fn reseed_rng(&mut self, seed: u64) {
+        self.game.as_mut().unwrap().rng = Rand::seed_from_u64(seed);
+    }
+
+    // Fixes the order of the current draw pile to a random one consistent
+    // with what the player knows, see DrawPile::sample_order().
+    pub fn determinize_draw_pile(&mut self) {
+        self.game.as_mut().unwrap().determinize_draw_pile();
+    }
+
+    // decisions made in every following episode are recorded
+    pub fn set_recorder(&mut self, recorder: Recorder) {
+        self.recorder = Some(recorder);
+    }
+
+    // the actions of every following episode are traced
+    pub fn set_tracer(&mut self, tracer: Tracer) {
+        self.tracer = Some(tracer);
+    }
+
+    pub fn take_tracer(&mut self) -> Option<Tracer> {
+        self.flush_trace();
+        if let Some(game) = &mut self.game
+            && let Some(t) = game.action_queue.take_tracer()
+        {
+            self.tracer = Some(t);
+        }
+        self.tracer.take()
+    }
+
+    // A trace that can't be written is reported and dropped rather than ending
+    // the episode.
+    fn flush_trace(&mut self) {
+        if let Some(game) = &mut self.game
+            && let Err(e) = game.flush_trace()
+        {
+            eprintln!("failed to write trace, no longer tracing: {e}");
+            game.action_queue.take_tracer();
+        }
+    }
+
+    // writes out anything pending from the current episode
+    pub fn take_recorder(&mut self) -> Option<Recorder> {
+        if let Some(game) = &mut self.game
+            && let Some(mut r) = game.recorder.0.take()
+        {
+            r.finish(game).expect("failed to write trajectory");
+            self.recorder = Some(r);
+        }
+        self.recorder.take()
+    }
+
+    pub fn game(&self) -> &Game {
+        self.game.as_ref().expect("reset() must be called first")
+    }
+
+    fn is_finished(&self) -> bool {
+        self.game().status.is_over()
+    }
+
+    fn is_truncated(&self) -> bool {
+        let game = self.game();
+        match self.config.episode {
+            Episode::FullRun {
+                last_floor: Some(last_floor),
+            } => game.floor >= last_floor && game.in_combat == CombatType::None,
+            Episode::FullRun { last_floor: None } | Episode::Custom { .. } => false,
+            Episode::Combat { .. } => game.in_combat == CombatType::None,
+        }
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.is_finished() || self.is_truncated()
+    }
+
+    pub fn info(&self) -> Info {
+        let game = self.game();
+        Info {
+            floor: game.floor,
+            combats_won: self.combats_won,
+            victory: matches!(game.status, GameStatus::Victory),
+            defeat: matches!(game.status, GameStatus::Defeat),
+            aborted: matches!(game.status, GameStatus::Aborted { .. }),
+            truncated: !self.is_finished() && self.is_truncated(),
+        }
+    }
+
+    fn observation(&self) -> Observation {
+        let game = self.game();
+        let action_mask = if self.is_done() {
+            vec![false; ACTION_SPACE_SIZE]
+        } else {
+            encoding::action_mask(game)
+        };
+        let num_actions = action_mask.iter().filter(|m| **m).count();
+        Observation {
+            features: encoding::encode(game),
+            action_mask,
+            num_actions,
+            floor: game.floor,
+            gold: game.gold,
+            player_hp: game.player.cur_hp,
+            player_max_hp: game.player.max_hp,
+            in_combat: game.in_combat != CombatType::None,
+            energy: game.energy,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn first_action(obs: &Observation) -> usize {
+        obs.action_mask.iter().position(|m| *m).unwrap()
+    }
+
+    fn last_action(obs: &Observation) -> usize {
+        obs.action_mask.iter().rposition(|m| *m).unwrap()
+    }
+
+    fn combat_config() -> EnvConfig {
+        EnvConfig {
+            episode: Episode::Combat {
+                combat: Combat::JawWorm,
+                deck: vec![
+                    (CardClass::Strike, false),
+                    (CardClass::Strike, false),
+                    (CardClass::Defend, false),
+                    (CardClass::Bash, true),
+                    (CardClass::DebugKillAll, false),
+                ],
+                relics: vec![],
+            },
+            rewards: RewardConfig {
+                floor_reached: 0.0,
+                combat_won: 10.0,
+                hp_delta: 1.0,
+            },
+            limits: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_combat_episode() {
+        let mut env = Env::default();
+        let obs = env.reset(0, combat_config());
+        assert!(obs.in_combat);
+        assert_eq!(obs.num_actions, env.game().valid_steps().len());
+        assert_eq!(obs.features.len(), encoding::num_features());
+
+        let steps = env.game().valid_steps();
+        let kill = steps
+            .iter()
+            .position(|s| s.description(env.game()).contains("DebugKillAll"))
+            .unwrap();
+        let (obs, reward, done, info) = env.step(encoding::encode_steps(&steps)[kill]);
+        assert!(done);
+        assert_eq!(reward, 10.0);
+        assert_eq!(info.combats_won, 1);
+        assert!(info.truncated);
+        assert!(!info.defeat);
+        assert_eq!(obs.num_actions, 0);
+    }
+
+    #[test]
+    fn test_hp_delta_reward() {
+        let mut env = Env::default();
+        let mut config = combat_config();
+        config.rewards.combat_won = 0.0;
+        env.reset(0, config);
+        let hp = env.game().player.cur_hp;
+        // end turn is always the first combat step
+        let (obs, reward, done, _) = env.step(0);
+        assert!(!done);
+        assert_eq!(reward, (obs.player_hp - hp) as f32);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_illegal_action() {
+        let mut env = Env::default();
+        let obs = env.reset(0, combat_config());
+        env.step(last_action(&obs) + 1);
+    }
+
+    #[test]
+    fn test_same_seed_same_episode() {
+        let run = |seed| {
+            let mut env = Env::default();
+            let mut obs = env.reset(seed, EnvConfig::default());
+            let mut history = vec![];
+            for _ in 0..40 {
+                if env.is_done() {
+                    break;
+                }
+                let (o, _, _, _) = env.step(last_action(&obs));
+                history.push((o.floor, o.gold, o.player_hp, o.num_actions));
+                obs = o;
+            }
+            history
+        };
+        assert_eq!(run(5), run(5));
+    }
+
+    #[test]
+    fn test_full_run_last_floor() {
+        let mut env = Env::default();
+        let mut obs = env.reset(
+            3,
+            EnvConfig {
+                episode: Episode::FullRun {
+                    last_floor: Some(1),
+                },
+                rewards: Default::default(),
+                limits: Default::default(),
+            },
+        );
+        let mut total = 0.0;
+        let mut info = Info::default();
+        while !env.is_done() {
+            let (o, reward, _, i) = env.step(first_action(&obs));
+            total += reward;
+            obs = o;
+            info = i;
+        }
+        assert_eq!(obs.floor, 1);
+        assert!(info.truncated || info.defeat);
+        assert!(total >= 1.0);
+    }
+
+    #[test]
+    fn test_recorder() {
+        let path = std::env::temp_dir().join(format!("slay-i-gym-{}.traj", std::process::id()));
+        let mut env = Env::default();
+        env.set_recorder(Recorder::create(&path).unwrap());
+        for seed in 0..2 {
+            let mut obs = env.reset(seed, combat_config());
+            while !env.is_done() {
+                let (o, _, _, _) = env.step(first_action(&obs));
+                obs = o;
+            }
+        }
+        let recorder = env.take_recorder().unwrap();
+        let rows = crate::trajectory::read_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(rows.len(), recorder.rows_written());
+        assert!(rows.len() >= 2);
+        assert!(rows.iter().all(|r| r.legal_actions[r.action as usize]));
+    }
+
+    #[test]
+    fn test_slime_boss_splits() {
+        let path =
+            std::env::temp_dir().join(format!("slay-i-gym-slime-{}.traj", std::process::id()));
+        let mut env = Env::default();
+        env.set_recorder(Recorder::create(&path).unwrap());
+        let mut obs = env.reset(
+            0,
+            EnvConfig {
+                episode: Episode::Custom {
+                    build: |b| {
+                        b.add_cards_upgraded(CardClass::Strike, 10)
+                            .set_player_hp(999)
+                            .build_fixed_combat(Combat::SlimeBoss)
+                    },
+                },
+                rewards: Default::default(),
+                limits: Default::default(),
+            },
+        );
+        let mut names = vec![];
+        while obs.in_combat {
+            // the last action plays the last card on the last monster
+            obs = env.step(last_action(&obs)).0;
+            for m in &env.game().monsters {
+                if m.creature.is_actionable() && !names.contains(&m.creature.name) {
+                    names.push(m.creature.name);
+                }
+            }
+        }
+        env.take_recorder().unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(names.contains(&"acid slime M"));
+        assert!(names.contains(&"spike slime M"));
+        assert!(env.game().monsters.len() <= encoding::MAX_MONSTERS);
+    }
+
+    struct BrokenWriter;
+
+    impl std::io::Write for BrokenWriter {
+        fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::other("broken"))
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_trace_write_error() {
+        let mut env = Env::default();
+        env.set_tracer(Tracer::new(BrokenWriter));
+        let mut obs = env.reset(0, combat_config());
+        while !env.is_done() {
+            obs = env.step(first_action(&obs)).0;
+        }
+        assert!(env.take_tracer().is_none());
+    }
+
+    #[test]
+    fn test_fork() {
+        let mut env = Env::default();
+        let mut obs = env.reset(2, combat_config());
+        for _ in 0..3 {
+            obs = env.step(first_action(&obs)).0;
+        }
+        let mut fork = env.fork();
+        assert_eq!(fork.observation().features, obs.features);
+        let a = last_action(&obs);
+        let (o1, r1, d1, _) = env.step(a);
+        let (o2, r2, d2, _) = fork.step(a);
+        assert_eq!(o1.features, o2.features);
+        assert_eq!((r1, d1), (r2, d2));
+    }
+
+    #[test]
+    fn test_fork_reseeded() {
+        let mut env = Env::default();
+        let obs = env.reset(4, combat_config());
+        let mut a = env.fork();
+        let mut b = env.fork();
+        a.reseed_rng(1);
+        b.reseed_rng(1);
+        a.step(first_action(&obs));
+        b.step(first_action(&obs));
+        let obs = a.step(first_action(&a.observation())).0;
+        b.step(first_action(&b.observation()));
+        assert_eq!(a.fork().observation().features, obs.features);
+        assert_eq!(b.observation().features, obs.features);
+    }
+
+    #[test]
+    fn test_determinize_draw_pile() {
+        let mut env = Env::default();
+        let obs = env.reset(5, combat_config());
+        env.step(first_action(&obs));
+        let mut a = env.fork();
+        a.reseed_rng(3);
+        a.determinize_draw_pile();
+        let order = a
+            .game()
+            .draw_pile
+            .get_all()
+            .iter()
+            .map(|c| a.game().cards[*c].class)
+            .collect::<Vec<_>>();
+        let b = a.fork();
+        let order_b = b
+            .game()
+            .draw_pile
+            .get_all()
+            .iter()
+            .map(|c| b.game().cards[*c].class)
+            .collect::<Vec<_>>();
+        assert_eq!(order, order_b);
+    }
+}
@@ -0,0 +1,38 @@
+mod action;
+mod actions;
+pub mod agent;
+mod blessings;
+mod campfire;
+mod card;
+pub mod cards;
+mod chest;
+mod combat;
+mod creature;
+mod damage;
+mod draw_pile;
+pub mod encoding;
+mod event;
+mod events;
+pub mod game;
+pub mod gym;
+mod map;
+mod master_deck;
+mod monster;
+mod monsters;
+mod move_history;
+mod potion;
+mod queue;
+pub mod relic;
+mod rewards;
+mod rng;
+mod shop;
+pub mod solver;
+mod state;
+mod status;
+mod step;
+#[cfg(test)]
+mod test;
+pub mod trace;
+pub mod trajectory;
+mod transposition;
+pub mod value;
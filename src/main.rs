@@ -1,38 +1,14 @@
-mod action;
-mod actions;
-mod blessings;
-mod campfire;
-mod card;
-mod cards;
-mod chest;
-mod combat;
-mod creature;
-mod draw_pile;
-mod event;
-mod events;
-mod game;
-mod map;
-mod master_deck;
-mod monster;
-mod monsters;
-mod move_history;
-mod potion;
-mod queue;
-mod relic;
-mod rewards;
-mod rng;
-mod shop;
-mod state;
-mod status;
-mod step;
-mod test;
+use std::{any::Any, time::Duration};
 
-use game::Game;
-
-use crate::{
+use slay_i::{
     cards::CardClass,
-    game::{GameBuilder, GameStatus},
+    encoding::{decode_action, encode_steps},
+    game::{AscendStep, CombatType, Game, GameBuilder, GameStatus},
+    gym::{Env, EnvConfig, Episode},
     relic::RelicClass,
+    solver::{MctsSolver, OneTurnSolver, PimcSolver, RouteSolver, Solver, SquashedHeuristic},
+    trace::Tracer,
+    trajectory::Recorder,
 };
 
 fn print_state(g: &Game) {
@@ -55,27 +31,45 @@ fn print_state(g: &Game) {
     }
     println!("hand:");
     for c in &g.hand {
-        println!(" {:?}", c.borrow());
+        println!(" {:?}", &g.cards[c]);
     }
     println!("draw pile:");
     for c in g.draw_pile.get_all() {
-        println!(" {:?}", c.borrow());
+        println!(" {:?}", &g.cards[c]);
     }
     println!("discard pile:");
     for c in &g.discard_pile {
-        println!(" {:?}", c.borrow());
+        println!(" {:?}", &g.cards[c]);
     }
     println!("exhaust pile:");
     for c in &g.exhaust_pile {
-        println!(" {:?}", c.borrow());
+        println!(" {:?}", &g.cards[c]);
+    }
+    if g.in_combat != CombatType::None && !(g.draw_pile.is_empty() && g.discard_pile.is_empty()) {
+        let n = g.draw_per_turn as usize;
+        let mut cards = g
+            .draw_pile
+            .get_all()
+            .into_iter()
+            .chain(&g.discard_pile)
+            .map(|c| (g.cards[c].class, g.cards[c].upgrade_count))
+            .collect::<Vec<_>>();
+        cards.sort_by_key(|&(class, upgrades)| (format!("{class:?}"), upgrades));
+        cards.dedup();
+        println!("chance to draw in the next {n} cards:");
+        for (class, upgrades) in cards {
+            let p = g.chance_to_draw(n, |c| c.class == class && c.upgrade_count == upgrades);
+            let plus = if upgrades > 0 { "+" } else { "" };
+            println!(" {class:?}{plus}: {:.0}%", p * 100.0);
+        }
     }
     if let Some(c) = &g.cur_card {
-        println!("current card being played: {:?}", c.borrow());
+        println!("current card being played: {:?}", &g.cards[c]);
     }
     if !g.chosen_cards.is_empty() {
         println!("cards being processed:");
         for c in &g.chosen_cards {
-            println!(" {:?}", c.borrow());
+            println!(" {:?}", &g.cards[c]);
         }
     }
     println!("moves:");
@@ -87,6 +81,7 @@ fn print_state(g: &Game) {
 enum UserInput {
     Step(usize),
     PrintMap,
+    Hint(Vec<String>),
 }
 
 fn read_user_input(max: usize) -> UserInput {
@@ -98,6 +93,9 @@ fn read_user_input(max: usize) -> UserInput {
         if s == "m" {
             return UserInput::PrintMap;
         }
+        if s == "h" || s.starts_with("h ") {
+            return UserInput::Hint(s.split_whitespace().skip(1).map(String::from).collect());
+        }
         if let Ok(v) = s.parse()
             && v < max
         {
@@ -105,21 +103,118 @@ fn read_user_input(max: usize) -> UserInput {
         }
         if !s.trim().is_empty() {
             println!("invalid num \"{}\"", s.trim());
-            println!("number to choose action, \"m\" to print map");
+            println!("number to choose action, \"m\" to print map, \"h\" for a hint");
+            println!("hint solvers: \"h turn\", \"h mcts [ms]\", \"h pimc\", \"h route\"");
         }
     }
 }
 
-fn main() {
-    let mut game = GameBuilder::default()
+fn hint(env: &Env, args: &[String]) {
+    let game = env.game();
+    let is_map = game
+        .valid_steps()
+        .iter()
+        .any(|s| (s.as_ref() as &dyn Any).is::<AscendStep>());
+    let default = if is_map {
+        "route"
+    } else if game.in_combat != CombatType::None {
+        "mcts"
+    } else {
+        "turn"
+    };
+    let mut solver: Box<dyn Solver> = match args.first().map_or(default, |s| s.as_str()) {
+        "turn" => Box::new(OneTurnSolver {
+            max_nodes: 2000,
+            seed: rand::random(),
+        }),
+        "mcts" => {
+            let ms = match args.get(1).map(|ms| ms.parse()) {
+                None => 1000,
+                Some(Ok(ms)) => ms,
+                Some(Err(_)) => {
+                    println!("invalid time \"{}\"", args[1]);
+                    return;
+                }
+            };
+            Box::new(MctsSolver {
+                budget: Duration::from_millis(ms),
+                max_simulations: None,
+                evaluator: SquashedHeuristic,
+                exploration: 1.0,
+                seed: rand::random(),
+            })
+        }
+        "pimc" => Box::new(PimcSolver {
+            samples: 8,
+            solver: OneTurnSolver {
+                max_nodes: 500,
+                seed: rand::random(),
+            },
+            seed: rand::random(),
+        }),
+        "route" => Box::new(RouteSolver),
+        s => {
+            println!("unknown solver \"{s}\"");
+            return;
+        }
+    };
+    let recs = solver.solve(env);
+    if recs.is_empty() {
+        println!("no recommendation");
+        return;
+    }
+    let steps = game.valid_steps();
+    for (i, r) in recs.iter().take(4).enumerate() {
+        let si = decode_action(game, r.action).unwrap();
+        println!(
+            "{} {si}: {} ({})",
+            if i == 0 { "recommended" } else { "alternative" },
+            steps[si].description(game),
+            r.outcome
+        );
+    }
+}
+
+fn build_game(builder: GameBuilder) -> Game {
+    builder
         .ironclad_starting_deck()
         .add_card(CardClass::Armaments)
         .add_card(CardClass::Purity)
         .add_card_upgraded(CardClass::Inflame)
         .add_relic(RelicClass::BurningBlood)
-        .build();
+        .build()
+}
+
+fn main() {
+    let mut record_path = None;
+    let mut trace_path = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--record" => record_path = Some(args.next().expect("--record needs a path")),
+            "--trace" => trace_path = Some(args.next().expect("--trace needs a path")),
+            _ => panic!("unknown argument \"{arg}\""),
+        }
+    }
+
+    let mut env = Env::default();
+    if let Some(path) = record_path {
+        env.set_recorder(Recorder::create(&path).expect("couldn't create trajectory file"));
+    }
+    if let Some(path) = trace_path {
+        env.set_tracer(Tracer::create(&path).expect("couldn't create trace file"));
+    }
+    env.reset(
+        rand::random(),
+        EnvConfig {
+            episode: Episode::Custom { build: build_game },
+            rewards: Default::default(),
+            limits: Default::default(),
+        },
+    );
     loop {
-        match game.status {
+        let game = env.game();
+        match &game.status {
             GameStatus::Defeat => {
                 println!("defeat :(");
                 break;
@@ -128,16 +223,25 @@ fn main() {
                 println!("victory! :)");
                 break;
             }
+            GameStatus::Aborted { limit, dump } => {
+                println!("aborted, exceeded {limit:?}:\n{dump}");
+                break;
+            }
             GameStatus::Combat => {
-                print_state(&game);
+                print_state(game);
                 let valid_steps = game.valid_steps();
                 let i = read_user_input(valid_steps.len());
                 match i {
-                    UserInput::Step(s) => game.step(s),
+                    UserInput::Step(s) => {
+                        let action = encode_steps(&valid_steps)[s];
+                        env.step(action);
+                    }
                     UserInput::PrintMap => game.map.print(),
+                    UserInput::Hint(args) => hint(&env, &args),
                 }
                 println!("-----------------------------");
             }
         }
     }
+    env.take_recorder();
 }
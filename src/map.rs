@@ -4,6 +4,7 @@ use rand::{RngExt, seq::SliceRandom};
 
 use crate::{game::Rand, rng::rand_slice};
 
+#[derive(Clone)]
 pub struct Map {
     pub nodes: Vec<Vec<Node>>,
 }
@@ -21,7 +22,7 @@ pub enum RoomType {
 }
 
 impl RoomType {
-    fn char(&self) -> char {
+    pub fn char(&self) -> char {
         match self {
             RoomType::Monster => 'm',
             RoomType::Elite => 'E',
@@ -445,7 +446,7 @@ mod tests {
 
     #[test]
     fn test_map() {
-        let mut rng = Rand::default();
+        let mut rng: Rand = rand::make_rng();
         for _ in 0..20 {
             let map = Map::generate(&mut rng);
             let num_start_points = (0..MAP_WIDTH)
@@ -9,7 +9,7 @@ use crate::{
     step::Step,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ChooseTransformMasterGameState {
     pub num_cards_remaining: usize,
     pub upgrade: bool,
@@ -20,13 +20,13 @@ impl GameState for ChooseTransformMasterGameState {
         let count = game
             .master_deck
             .iter()
-            .filter(|c| c.borrow().can_remove_from_master_deck())
+            .filter(|c| game.cards[*c].can_remove_from_master_deck())
             .count();
         if count <= self.num_cards_remaining {
             for i in (0..game.master_deck.len()).rev() {
-                if game.master_deck[i].borrow().can_remove_from_master_deck() {
+                if game.cards[game.master_deck[i]].can_remove_from_master_deck() {
                     let c = game.master_deck.remove(i);
-                    game.chosen_cards.push(c);
+                    game.chosen_cards.push(c, &game.cards);
                 }
             }
             game.state.push_state(TransformChosenCardsGameState {
@@ -34,10 +34,9 @@ impl GameState for ChooseTransformMasterGameState {
             });
         }
     }
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
-        let mut moves = Steps::default();
+    fn valid_steps(&self, game: &Game, moves: &mut Steps) -> bool {
         for (i, c) in game.master_deck.iter().enumerate() {
-            if c.borrow().can_remove_from_master_deck() {
+            if game.cards[c].can_remove_from_master_deck() {
                 moves.push(ChooseTransformMasterStep {
                     master_index: i,
                     num_cards_remaining: self.num_cards_remaining,
@@ -45,11 +44,7 @@ impl GameState for ChooseTransformMasterGameState {
                 });
             }
         }
-        if moves.steps.is_empty() {
-            None
-        } else {
-            Some(moves)
-        }
+        !moves.is_empty()
     }
 }
 
@@ -67,7 +62,7 @@ impl Step for ChooseTransformMasterStep {
 
     fn run(&self, game: &mut Game) {
         let c = game.master_deck.remove(self.master_index);
-        game.chosen_cards.push(c);
+        game.chosen_cards.push(c, &game.cards);
         let num_cards_remaining = self.num_cards_remaining - 1;
         if num_cards_remaining == 0 {
             game.state.push_state(TransformChosenCardsGameState {
@@ -84,20 +79,20 @@ impl Step for ChooseTransformMasterStep {
     fn description(&self, game: &Game) -> String {
         format!(
             "transform {:?}",
-            game.master_deck[self.master_index].borrow()
+            &game.cards[game.master_deck[self.master_index]]
         )
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TransformChosenCardsGameState {
     pub upgrade: bool,
 }
 
 impl GameState for TransformChosenCardsGameState {
     fn run(&self, game: &mut Game) {
-        while let Some(c) = game.chosen_cards.pop() {
-            let class = c.borrow().class;
+        while let Some(c) = game.chosen_cards.pop(&game.cards) {
+            let class = game.cards[c].class;
             let transformed = transformed(class, &mut game.rng);
             game.action_queue
                 .push_bot(RemovedCardFromMasterDeckAction(class));
@@ -113,18 +108,17 @@ impl GameState for TransformChosenCardsGameState {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ChooseUpgradeMasterGameState;
 
 impl GameState for ChooseUpgradeMasterGameState {
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
-        let mut moves = Steps::default();
+    fn valid_steps(&self, game: &Game, moves: &mut Steps) -> bool {
         for (i, c) in game.master_deck.iter().enumerate() {
-            if c.borrow().can_upgrade() {
+            if game.cards[c].can_upgrade() {
                 moves.push(ChooseUpgradeMasterStep { master_index: i });
             }
         }
-        Some(moves)
+        true
     }
 }
 
@@ -139,17 +133,20 @@ impl Step for ChooseUpgradeMasterStep {
     }
 
     fn run(&self, game: &mut Game) {
-        let c = game.master_deck[self.master_index].clone();
+        let c = game.master_deck[self.master_index];
         game.action_queue.push_bot(UpgradeAction(c));
         game.state.push_state(RunActionsGameState);
     }
 
     fn description(&self, game: &Game) -> String {
-        format!("upgrade {:?}", game.master_deck[self.master_index].borrow())
+        format!(
+            "upgrade {:?}",
+            &game.cards[game.master_deck[self.master_index]]
+        )
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ChooseRemoveFromMasterGameState {
     pub num_cards_remaining: usize,
 }
@@ -159,33 +156,28 @@ impl GameState for ChooseRemoveFromMasterGameState {
         let count = game
             .master_deck
             .iter()
-            .filter(|c| c.borrow().can_remove_from_master_deck())
+            .filter(|c| game.cards[*c].can_remove_from_master_deck())
             .count();
         if count <= self.num_cards_remaining {
             for i in (0..game.master_deck.len()).rev() {
-                if game.master_deck[i].borrow().can_remove_from_master_deck() {
+                if game.cards[game.master_deck[i]].can_remove_from_master_deck() {
                     let c = game.master_deck.remove(i);
-                    game.chosen_cards.push(c);
+                    game.chosen_cards.push(c, &game.cards);
                 }
             }
             game.state.push_state(RemoveChosenCardsGameState);
         }
     }
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
-        let mut moves = Steps::default();
+    fn valid_steps(&self, game: &Game, moves: &mut Steps) -> bool {
         for (i, c) in game.master_deck.iter().enumerate() {
-            if c.borrow().can_remove_from_master_deck() {
+            if game.cards[c].can_remove_from_master_deck() {
                 moves.push(ChooseRemoveFromMasterStep {
                     master_index: i,
                     num_cards_remaining: self.num_cards_remaining,
                 });
             }
         }
-        if moves.steps.is_empty() {
-            None
-        } else {
-            Some(moves)
-        }
+        !moves.is_empty()
     }
 }
 
@@ -202,7 +194,7 @@ impl Step for ChooseRemoveFromMasterStep {
 
     fn run(&self, game: &mut Game) {
         game.chosen_cards
-            .push(game.master_deck.remove(self.master_index));
+            .push(game.master_deck.remove(self.master_index), &game.cards);
         let num_cards_remaining = self.num_cards_remaining - 1;
         if num_cards_remaining == 0 {
             game.state.push_state(RemoveChosenCardsGameState);
@@ -214,33 +206,35 @@ impl Step for ChooseRemoveFromMasterStep {
     }
 
     fn description(&self, game: &Game) -> String {
-        format!("remove {:?}", game.master_deck[self.master_index].borrow())
+        format!(
+            "remove {:?}",
+            &game.cards[game.master_deck[self.master_index]]
+        )
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct RemoveChosenCardsGameState;
 
 impl GameState for RemoveChosenCardsGameState {
     fn run(&self, game: &mut Game) {
-        while let Some(c) = game.chosen_cards.pop() {
+        while let Some(c) = game.chosen_cards.pop(&game.cards) {
             game.action_queue
-                .push_bot(RemovedCardFromMasterDeckAction(c.borrow().class));
+                .push_bot(RemovedCardFromMasterDeckAction(game.cards[c].class));
         }
         game.state.push_state(RunActionsGameState);
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ChooseDuplicateCardInMasterGameState;
 
 impl GameState for ChooseDuplicateCardInMasterGameState {
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
-        let mut moves = Steps::default();
+    fn valid_steps(&self, game: &Game, moves: &mut Steps) -> bool {
         for i in 0..game.master_deck.len() {
             moves.push(DuplicateCardInMasterStep { master_index: i });
         }
-        Some(moves)
+        true
     }
 }
 
@@ -255,9 +249,9 @@ impl Step for DuplicateCardInMasterStep {
     }
 
     fn run(&self, game: &mut Game) {
-        let original = game.master_deck[self.master_index].clone();
+        let original = game.master_deck[self.master_index];
         let c = game.clone_card_ref_new_id(&original);
-        c.borrow_mut().is_bottled = false;
+        game.update_card(c, |c| c.is_bottled = false);
         game.action_queue.push_bot(AddCardToMasterDeckAction(c));
         game.state.push_state(RunActionsGameState);
     }
@@ -265,25 +259,24 @@ impl Step for DuplicateCardInMasterStep {
     fn description(&self, game: &Game) -> String {
         format!(
             "duplicate {:?}",
-            game.master_deck[self.master_index].borrow()
+            &game.cards[game.master_deck[self.master_index]]
         )
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ChooseBottledCardGameState {
     pub ty: CardType,
 }
 
 impl GameState for ChooseBottledCardGameState {
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
+    fn valid_steps(&self, game: &Game, steps: &mut Steps) -> bool {
         for (i, c) in game.master_deck.iter().enumerate() {
-            if c.borrow().class.ty() == self.ty {
+            if game.cards[c].class.ty() == self.ty {
                 steps.push(ChooseBottledCardStep { master_index: i });
             }
         }
-        Some(steps)
+        true
     }
 }
 
@@ -297,9 +290,12 @@ impl Step for ChooseBottledCardStep {
         true
     }
     fn run(&self, game: &mut Game) {
-        game.master_deck[self.master_index].borrow_mut().is_bottled = true;
+        game.update_card(game.master_deck[self.master_index], |c| c.is_bottled = true);
     }
     fn description(&self, game: &Game) -> String {
-        format!("bottle {:?}", game.master_deck[self.master_index].borrow())
+        format!(
+            "bottle {:?}",
+            &game.cards[game.master_deck[self.master_index]]
+        )
     }
 }
@@ -1,10 +1,17 @@
+use std::hash::Hash;
+
+use dyn_clone::DynClone;
+use dyn_eq::DynEq;
 use rand::RngExt;
 
-use crate::creature::Creature;
+use crate::card::CardRef;
+use crate::creature::{Creature, CreatureState};
 use crate::game::{CreatureRef, Game, Rand};
 use crate::queue::ActionQueue;
+use crate::status::Status;
+use crate::transposition;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Intent {
     Attack(i32, i32),
     AttackBuff(i32, i32),
@@ -43,27 +50,75 @@ impl Intent {
         };
         *d = game.calculate_damage(*d, this, CreatureRef::player());
     }
+    // (damage per hit, number of hits) for attacks
+    pub fn damage(&self) -> Option<(i32, i32)> {
+        use Intent::*;
+        match *self {
+            Attack(d, n) | AttackBuff(d, n) | AttackDebuff(d, n) | AttackDefend(d, n) => {
+                Some((d, n))
+            }
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct MonsterInfo {
     pub num_alive_monsters: usize,
     pub player_hp: i32,
+    pub player_constricted: bool,
+    // hp the alive monsters are missing in total
+    pub monsters_missing_hp: i32,
 }
 
-pub trait MonsterBehavior {
+// What a behavior remembers between turns (its next move, move history, ...),
+// compared and hashed by `Game::state_eq` and `Game::state_hash`.
+pub trait MonsterState: DynEq {
+    fn state_hash(&self) -> u64;
+}
+
+impl<T: Hash + Eq + 'static> MonsterState for T {
+    fn state_hash(&self) -> u64 {
+        transposition::key(self)
+    }
+}
+
+pub trait MonsterBehavior: MonsterState + DynClone + Send {
     fn name(&self) -> &'static str;
     fn hp_range(&self) -> (i32, i32);
     fn pre_combat(&self, _queue: &mut ActionQueue, _this: CreatureRef, _rng: &mut Rand) {}
-    fn on_take_damage(&mut self, _this: CreatureRef, _this_creature: &mut Creature) {}
+    fn on_take_damage(
+        &mut self,
+        _this: CreatureRef,
+        _this_creature: &mut Creature,
+        _rng: &mut Rand,
+    ) {
+    }
+    fn on_turn_begin(&mut self, _this: CreatureRef, _this_creature: &mut Creature) {}
+    // a status ran out or was removed
+    fn on_lose_status(&mut self, _this: CreatureRef, _status: Status) {}
+    fn on_death(&mut self, _this: CreatureRef, _queue: &mut ActionQueue) {}
+    // it would die; returning true keeps it in the combat to come back later
+    fn on_rebirth(&mut self, _this: CreatureRef, _this_creature: &mut Creature) -> bool {
+        false
+    }
     fn roll_next_action(&mut self, r: &mut Rand, info: &MonsterInfo);
     fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, info: &MonsterInfo);
     fn get_intent(&self) -> Intent;
 }
 
+dyn_clone::clone_trait_object!(MonsterBehavior);
+dyn_eq::eq_trait_object!(MonsterBehavior);
+
+// The game changes the behavior through `update_behavior`, which keeps
+// `behavior_hash` up to date.
+#[derive(Clone)]
 pub struct Monster {
     pub creature: Creature,
     pub behavior: Box<dyn MonsterBehavior>,
+    behavior_hash: u64,
+    // a card taken from the player (Stasis), given back when the monster dies
+    pub held_card: Option<CardRef>,
 }
 
 impl Monster {
@@ -73,18 +128,39 @@ impl Monster {
     pub fn new_boxed(m: Box<dyn MonsterBehavior>, rng: &mut Rand) -> Self {
         let (lo, hi) = m.hp_range();
         let hp = rng.random_range(lo..=hi);
-        let name = m.name();
-
-        Monster {
-            creature: Creature::new(name, hp),
-            behavior: m,
-        }
+        Self::new_boxed_with_hp(m, hp)
     }
     pub fn new_with_hp<M: MonsterBehavior + 'static>(m: M, hp: i32) -> Self {
-        let name = m.name();
+        Self::new_boxed_with_hp(Box::new(m), hp)
+    }
+    // a slot for a monster that is only summoned later, see
+    // `SummonMonsterInSlotAction`
+    pub fn new_empty_slot(m: Box<dyn MonsterBehavior>) -> Self {
+        let mut monster = Self::new_boxed_with_hp(m, 0);
+        monster.creature.state = CreatureState::Dead;
+        monster.creature.rehash();
+        monster
+    }
+    fn new_boxed_with_hp(m: Box<dyn MonsterBehavior>, hp: i32) -> Self {
         Monster {
-            creature: Creature::new(name, hp),
-            behavior: Box::new(m),
+            creature: Creature::new(m.name(), hp),
+            behavior_hash: m.state_hash(),
+            behavior: m,
+            held_card: None,
         }
     }
+    pub fn behavior_hash(&self) -> u64 {
+        self.behavior_hash
+    }
+    // Rehashes the whole behavior after `f`, not just what it changed.
+    // Behaviors are a few small fields, and the bench runs within noise (~2%)
+    // of skipping the rehash entirely.
+    pub fn update_behavior<T>(
+        &mut self,
+        f: impl FnOnce(&mut dyn MonsterBehavior, &mut Creature) -> T,
+    ) -> T {
+        let ret = f(&mut *self.behavior, &mut self.creature);
+        self.behavior_hash = self.behavior.state_hash();
+        ret
+    }
 }
@@ -0,0 +1,243 @@
+use rand::RngExt;
+
+use crate::{
+    actions::{
+        damage::DamageAction, escape_all_monsters::EscapeAllMonstersAction,
+        gain_status::GainStatusAction, revive_monster::ReviveMonsterAction,
+        shuffle_card_into_draw::ShuffleCardIntoDrawAction,
+    },
+    cards::CardClass,
+    creature::Creature,
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    move_history::MoveHistory,
+    queue::ActionQueue,
+    status::Status,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    None,
+    Slash,
+    SoulStrike,
+    Rebirth,
+    DarkEcho,
+    Sludge,
+    Tackle,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct AwakenedOne {
+    action: Action,
+    history: MoveHistory<Action>,
+    awakened: bool,
+    reviving: bool,
+}
+
+impl AwakenedOne {
+    pub fn new() -> Self {
+        Self {
+            action: Action::None,
+            history: MoveHistory::new(),
+            awakened: false,
+            reviving: false,
+        }
+    }
+}
+
+impl MonsterBehavior for AwakenedOne {
+    fn name(&self) -> &'static str {
+        "awakened one"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (320, 320)
+    }
+
+    fn pre_combat(&self, queue: &mut ActionQueue, this: CreatureRef, _: &mut Rand) {
+        for (status, amount) in [
+            (Status::Strength, 2),
+            (Status::RegenMonster, 15),
+            (Status::Curiosity, 2),
+            (Status::Unawakened, 1),
+        ] {
+            queue.push_bot(GainStatusAction {
+                status,
+                amount,
+                target: this,
+            });
+        }
+    }
+
+    // the first death only wakes it up, without its debuffs or curiosity
+    fn on_rebirth(&mut self, _this: CreatureRef, c: &mut Creature) -> bool {
+        if !c.has_status(Status::Unawakened) {
+            return false;
+        }
+        let lost = c
+            .all_statuses()
+            .filter(|&(s, v)| s.is_debuff(v) || matches!(s, Status::Curiosity | Status::Unawakened))
+            .collect::<Vec<_>>();
+        for (s, _) in lost {
+            c.remove_status(s);
+        }
+        self.reviving = true;
+        self.action = Action::Rebirth;
+        self.history.add(Action::Rebirth);
+        true
+    }
+
+    // the cultists flee once it's gone for good
+    fn on_death(&mut self, _this: CreatureRef, queue: &mut ActionQueue) {
+        queue.push_top(EscapeAllMonstersAction);
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        match self.action {
+            Action::Slash => queue.push_bot(DamageAction::from_monster(20, this)),
+            Action::SoulStrike => {
+                for _ in 0..4 {
+                    queue.push_bot(DamageAction::from_monster(6, this));
+                }
+            }
+            Action::Rebirth => {
+                self.reviving = false;
+                self.awakened = true;
+                queue.push_bot(ReviveMonsterAction::full_hp(this));
+            }
+            Action::DarkEcho => queue.push_bot(DamageAction::from_monster(40, this)),
+            Action::Sludge => {
+                queue.push_bot(DamageAction::from_monster(18, this));
+                queue.push_bot(ShuffleCardIntoDrawAction {
+                    class: CardClass::Void,
+                    is_free: false,
+                });
+            }
+            Action::Tackle => {
+                for _ in 0..3 {
+                    queue.push_bot(DamageAction::from_monster(10, this));
+                }
+            }
+            Action::None => unreachable!(),
+        }
+    }
+    fn roll_next_action(&mut self, r: &mut Rand, _info: &MonsterInfo) {
+        let num = r.random_range(0..100);
+        if self.reviving {
+            return;
+        }
+        let next = if self.awakened {
+            if self.history.last(Action::Rebirth) {
+                Action::DarkEcho
+            } else if num < 50 {
+                if !self.history.last_two(Action::Sludge) {
+                    Action::Sludge
+                } else {
+                    Action::Tackle
+                }
+            } else if !self.history.last_two(Action::Tackle) {
+                Action::Tackle
+            } else {
+                Action::Sludge
+            }
+        } else if self.action == Action::None {
+            Action::Slash
+        } else if num < 25 {
+            if !self.history.last(Action::SoulStrike) {
+                Action::SoulStrike
+            } else {
+                Action::Slash
+            }
+        } else if !self.history.last_two(Action::Slash) {
+            Action::Slash
+        } else {
+            Action::SoulStrike
+        };
+        self.history.add(next);
+        self.action = next;
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::None => unreachable!(),
+            Action::Slash => Intent::Attack(20, 1),
+            Action::SoulStrike => Intent::Attack(6, 4),
+            Action::Rebirth => Intent::Unknown,
+            Action::DarkEcho => Intent::Attack(40, 1),
+            Action::Sludge => Intent::AttackDebuff(18, 1),
+            Action::Tackle => Intent::Attack(10, 3),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_matches,
+        cards::CardClass,
+        combat::EndTurnStep,
+        creature::CreatureState,
+        game::{CombatType, GameBuilder},
+        monsters::Combat,
+    };
+
+    #[test]
+    fn test_curiosity() {
+        let mut g = GameBuilder::default().build_combat_with_monster(AwakenedOne::new());
+        g.play_card(CardClass::Inflame, None);
+        assert_eq!(g.monsters[0].creature.get_status(Status::Strength), Some(4));
+        g.play_card(CardClass::Strike, Some(CreatureRef::monster(0)));
+        assert_eq!(g.monsters[0].creature.get_status(Status::Strength), Some(4));
+    }
+
+    #[test]
+    fn test_rebirth() {
+        let mut g = GameBuilder::default()
+            .add_cards(CardClass::Strike, 5)
+            .build_combat_with_monster(AwakenedOne::new());
+        g.player.max_hp = 999;
+        g.player.cur_hp = 999;
+        g.play_card(CardClass::Bash, Some(CreatureRef::monster(0)));
+        g.monsters[0].creature.cur_hp = 1;
+        g.play_card(CardClass::Strike, Some(CreatureRef::monster(0)));
+        let c = &g.monsters[0].creature;
+        assert_eq!(c.state, CreatureState::Reviving);
+        assert!(!c.has_status(Status::Vulnerable));
+        assert!(!c.has_status(Status::Curiosity));
+        assert!(!c.has_status(Status::Unawakened));
+        assert_eq!(c.get_status(Status::Strength), Some(2));
+        assert!(!g.combat_finished());
+        assert_eq!(g.in_combat, CombatType::Normal);
+        assert_matches!(g.monsters[0].behavior.get_intent(), Intent::Unknown);
+
+        g.step_test(EndTurnStep);
+        let c = &g.monsters[0].creature;
+        assert_eq!(c.state, CreatureState::Alive);
+        assert_eq!(c.cur_hp, c.max_hp);
+        assert_eq!(g.monsters[0].behavior.get_intent(), Intent::Attack(40, 1));
+
+        g.play_card(CardClass::Inflame, None);
+        assert_eq!(g.monsters[0].creature.get_status(Status::Strength), Some(2));
+        g.play_card(CardClass::DebugKill, Some(CreatureRef::monster(0)));
+        assert_eq!(g.in_combat, CombatType::None);
+    }
+
+    #[test]
+    fn test_cultists_flee() {
+        let mut g = GameBuilder::default().build_fixed_combat(Combat::AwakenedOne);
+        assert_eq!(
+            g.monsters
+                .iter()
+                .map(|m| m.behavior.name())
+                .collect::<Vec<_>>(),
+            vec!["cultist", "cultist", "awakened one"]
+        );
+        g.player.max_hp = 999;
+        g.player.cur_hp = 999;
+        g.play_card(CardClass::DebugKill, Some(CreatureRef::monster(2)));
+        assert_eq!(g.monsters[2].creature.state, CreatureState::Reviving);
+        g.step_test(EndTurnStep);
+        g.play_card(CardClass::DebugKill, Some(CreatureRef::monster(2)));
+        assert_eq!(g.in_combat, CombatType::None);
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{
+    actions::{block::BlockAction, damage::DamageAction, gain_status::GainStatusAction},
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    queue::ActionQueue,
+    status::Status,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    BearHug,
+    Lunge,
+    Maul,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct Bear {
+    action: Action,
+    turn: i32,
+}
+
+impl Bear {
+    pub fn new() -> Self {
+        Self {
+            action: Action::BearHug,
+            turn: 0,
+        }
+    }
+}
+
+impl MonsterBehavior for Bear {
+    fn name(&self) -> &'static str {
+        "bear"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (40, 44)
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        match self.action {
+            Action::BearHug => queue.push_bot(GainStatusAction {
+                status: Status::Dexterity,
+                amount: -4,
+                target: CreatureRef::player(),
+            }),
+            Action::Lunge => {
+                queue.push_bot(DamageAction::from_monster(10, this));
+                queue.push_bot(BlockAction::monster(this, 9));
+            }
+            Action::Maul => queue.push_bot(DamageAction::from_monster(20, this)),
+        }
+        self.turn += 1;
+    }
+    fn roll_next_action(&mut self, _: &mut Rand, _info: &MonsterInfo) {
+        if self.turn > 0 {
+            self.action = match self.action {
+                Action::BearHug | Action::Maul => Action::Lunge,
+                Action::Lunge => Action::Maul,
+            };
+        }
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::BearHug => Intent::StrongDebuff,
+            Action::Lunge => Intent::AttackDefend(10, 1),
+            Action::Maul => Intent::Attack(20, 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        actions::increase_max_hp::IncreaseMaxHPAction, assert_matches, combat::EndTurnStep,
+        game::GameBuilder,
+    };
+
+    #[test]
+    fn test_moves() {
+        let mut g = GameBuilder::default().build_combat_with_monster(Bear::new());
+        g.run_action(IncreaseMaxHPAction(999));
+        assert_matches!(g.monsters[0].behavior.get_intent(), Intent::StrongDebuff);
+        g.step_test(EndTurnStep);
+        assert_eq!(g.player.get_status(Status::Dexterity), Some(-4));
+        for _ in 0..2 {
+            assert_matches!(
+                g.monsters[0].behavior.get_intent(),
+                Intent::AttackDefend(10, 1)
+            );
+            g.step_test(EndTurnStep);
+            assert_eq!(g.monsters[0].creature.block, 9);
+            assert_matches!(g.monsters[0].behavior.get_intent(), Intent::Attack(20, 1));
+            g.step_test(EndTurnStep);
+        }
+    }
+}
@@ -9,13 +9,14 @@ use crate::{
     status::Status,
 };
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 enum Action {
     None,
     Stab,
     Rake,
 }
 
+#[derive(PartialEq, Eq, Hash, Clone)]
 pub struct BlueSlaver {
     action: Action,
     history: MoveHistory<Action>,
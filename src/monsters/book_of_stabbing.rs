@@ -0,0 +1,136 @@
+use rand::RngExt;
+
+use crate::{
+    actions::{damage::DamageAction, gain_status::GainStatusAction},
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    move_history::MoveHistory,
+    queue::ActionQueue,
+    status::Status,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    None,
+    MultiStab,
+    SingleStab,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct BookOfStabbing {
+    action: Action,
+    history: MoveHistory<Action>,
+    stab_count: i32,
+}
+
+impl BookOfStabbing {
+    pub fn new() -> Self {
+        Self {
+            action: Action::None,
+            history: MoveHistory::new(),
+            stab_count: 1,
+        }
+    }
+}
+
+impl MonsterBehavior for BookOfStabbing {
+    fn name(&self) -> &'static str {
+        "book of stabbing"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (168, 172)
+    }
+
+    fn pre_combat(&self, queue: &mut ActionQueue, this: CreatureRef, _: &mut Rand) {
+        queue.push_bot(GainStatusAction {
+            status: Status::PainfulStabs,
+            amount: 1,
+            target: this,
+        });
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        match self.action {
+            Action::MultiStab => {
+                for _ in 0..self.stab_count {
+                    queue.push_bot(DamageAction::from_monster(7, this));
+                }
+            }
+            Action::SingleStab => queue.push_bot(DamageAction::from_monster(24, this)),
+            Action::None => unreachable!(),
+        }
+    }
+    fn roll_next_action(&mut self, r: &mut Rand, _info: &MonsterInfo) {
+        let next = if r.random_range(0..100) < 15 {
+            if self.history.last(Action::SingleStab) {
+                Action::MultiStab
+            } else {
+                Action::SingleStab
+            }
+        } else if self.history.last_two(Action::MultiStab) {
+            Action::SingleStab
+        } else {
+            Action::MultiStab
+        };
+        // each move adds another stab to the next multi stab
+        self.stab_count += 1;
+        self.history.add(next);
+        self.action = next;
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::None => unreachable!(),
+            Action::MultiStab => Intent::Attack(7, self.stab_count),
+            Action::SingleStab => Intent::Attack(24, 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cards::CardClass, combat::EndTurnStep, game::GameBuilder};
+
+    #[test]
+    fn test_escalating_stabs() {
+        let mut g = GameBuilder::default()
+            .seed(0)
+            .build_combat_with_monster(BookOfStabbing::new());
+        let mut last_stabs = 1;
+        for _ in 0..6 {
+            if let Intent::Attack(7, n) = g.monsters[0].behavior.get_intent() {
+                assert!(n > last_stabs);
+                last_stabs = n;
+            }
+            g.player.max_hp = 999;
+            g.player.cur_hp = 999;
+            g.step_test(EndTurnStep);
+        }
+        assert!(last_stabs > 2);
+    }
+
+    #[test]
+    fn test_painful_stabs() {
+        let mut g = GameBuilder::default().build_combat_with_monster(BookOfStabbing::new());
+        g.monsters[0].behavior = Box::new(BookOfStabbing {
+            action: Action::MultiStab,
+            history: MoveHistory::new(),
+            stab_count: 3,
+        });
+        g.player.cur_hp = 50;
+        g.player.block = 10;
+        g.step_test(EndTurnStep);
+        assert_eq!(g.player.cur_hp, 50 - 11);
+        // only the hits that got through block
+        let draw = g.draw_pile.get_all();
+        let wounds = g
+            .hand
+            .iter()
+            .chain(&g.discard_pile)
+            .chain(draw.iter().copied())
+            .filter(|c| g.cards[**c].class == CardClass::Wound)
+            .count();
+        assert_eq!(wounds, 2);
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{
+    actions::{
+        block::BlockAction,
+        damage::DamageAction,
+        gain_status::GainStatusAction,
+        kill_all_monsters::KillAllMonstersAction,
+        summon_monster::{SummonMonsterAction, SummonMonsterType},
+    },
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    queue::ActionQueue,
+    status::Status,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    Start,
+    SpawnOrbs,
+    Flail,
+    Boost,
+    HyperBeam,
+    Stunned,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct BronzeAutomaton {
+    action: Action,
+    // flails and boosts since the last hyper beam
+    num_turns: i32,
+}
+
+impl BronzeAutomaton {
+    pub fn new() -> Self {
+        Self {
+            action: Action::Start,
+            num_turns: 0,
+        }
+    }
+}
+
+impl MonsterBehavior for BronzeAutomaton {
+    fn name(&self) -> &'static str {
+        "bronze automaton"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (320, 320)
+    }
+
+    fn pre_combat(&self, queue: &mut ActionQueue, this: CreatureRef, _: &mut Rand) {
+        queue.push_bot(GainStatusAction {
+            status: Status::Artifact,
+            amount: 3,
+            target: this,
+        });
+    }
+
+    // the orbs go down with it
+    fn on_death(&mut self, _this: CreatureRef, queue: &mut ActionQueue) {
+        queue.push_top(KillAllMonstersAction);
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        match self.action {
+            Action::SpawnOrbs => {
+                for _ in 0..2 {
+                    queue.push_bot(SummonMonsterAction(SummonMonsterType::BronzeOrb(this)));
+                }
+            }
+            Action::Flail => {
+                for _ in 0..2 {
+                    queue.push_bot(DamageAction::from_monster(8, this));
+                }
+            }
+            Action::Boost => {
+                queue.push_bot(BlockAction::monster(this, 12));
+                queue.push_bot(GainStatusAction {
+                    status: Status::Strength,
+                    amount: 4,
+                    target: this,
+                });
+            }
+            Action::HyperBeam => queue.push_bot(DamageAction::from_monster(50, this)),
+            Action::Stunned => {}
+            Action::Start => unreachable!(),
+        }
+    }
+    fn roll_next_action(&mut self, _: &mut Rand, _info: &MonsterInfo) {
+        self.action = match self.action {
+            Action::Start => Action::SpawnOrbs,
+            _ if self.num_turns == 4 => {
+                self.num_turns = 0;
+                Action::HyperBeam
+            }
+            Action::HyperBeam => Action::Stunned,
+            Action::SpawnOrbs | Action::Stunned | Action::Boost => {
+                self.num_turns += 1;
+                Action::Flail
+            }
+            Action::Flail => {
+                self.num_turns += 1;
+                Action::Boost
+            }
+        };
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::Start => unreachable!(),
+            Action::SpawnOrbs => Intent::Unknown,
+            Action::Flail => Intent::Attack(8, 2),
+            Action::Boost => Intent::DefendBuff,
+            Action::HyperBeam => Intent::Attack(50, 1),
+            Action::Stunned => Intent::Stun,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        actions::damage::DamageAction,
+        assert_matches,
+        combat::EndTurnStep,
+        game::{CombatType, GameBuilder},
+    };
+
+    #[test]
+    fn test_pattern() {
+        let mut g = GameBuilder::default().build_combat_with_monster(BronzeAutomaton::new());
+        assert_eq!(g.monsters[0].creature.get_status(Status::Artifact), Some(3));
+        assert_matches!(g.monsters[0].behavior.get_intent(), Intent::Unknown);
+        g.player.max_hp = 999;
+        g.player.cur_hp = 999;
+        g.step_test(EndTurnStep);
+        assert_eq!(g.monsters.len(), 3);
+        assert!(g.monsters.iter().all(|m| m.creature.is_actionable()));
+        let mut intents = vec![];
+        for _ in 0..8 {
+            intents.push(g.monsters[0].behavior.get_intent());
+            g.player.cur_hp = 999;
+            g.step_test(EndTurnStep);
+        }
+        assert_eq!(
+            intents,
+            vec![
+                Intent::Attack(8, 2),
+                Intent::DefendBuff,
+                Intent::Attack(8, 2),
+                Intent::DefendBuff,
+                Intent::Attack(50, 1),
+                Intent::Stun,
+                Intent::Attack(8, 2),
+                Intent::DefendBuff,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_orbs_die_with_it() {
+        let mut g = GameBuilder::default().build_combat_with_monster(BronzeAutomaton::new());
+        g.player.max_hp = 999;
+        g.player.cur_hp = 999;
+        g.step_test(EndTurnStep);
+        assert_eq!(g.monsters.len(), 3);
+        g.run_action(DamageAction::thorns_no_rupture(
+            999,
+            CreatureRef::monster(0),
+        ));
+        assert_eq!(g.in_combat, CombatType::None);
+    }
+}
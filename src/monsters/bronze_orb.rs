@@ -0,0 +1,128 @@
+use rand::RngExt;
+
+use crate::{
+    actions::{block::BlockAction, damage::DamageAction, stasis::StasisAction},
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    move_history::MoveHistory,
+    queue::ActionQueue,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    None,
+    Stasis,
+    Beam,
+    SupportBeam,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct BronzeOrb {
+    action: Action,
+    history: MoveHistory<Action>,
+    used_stasis: bool,
+    automaton: CreatureRef,
+}
+
+impl BronzeOrb {
+    pub fn new(automaton: CreatureRef) -> Self {
+        Self {
+            action: Action::None,
+            history: MoveHistory::new(),
+            used_stasis: false,
+            automaton,
+        }
+    }
+}
+
+impl MonsterBehavior for BronzeOrb {
+    fn name(&self) -> &'static str {
+        "bronze orb"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (54, 60)
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        match self.action {
+            Action::Stasis => queue.push_bot(StasisAction(this)),
+            Action::Beam => queue.push_bot(DamageAction::from_monster(8, this)),
+            Action::SupportBeam => queue.push_bot(BlockAction::monster(self.automaton, 12)),
+            Action::None => unreachable!(),
+        }
+    }
+    fn roll_next_action(&mut self, r: &mut Rand, _info: &MonsterInfo) {
+        let num = r.random_range(0..100);
+        let next = if !self.used_stasis && num >= 25 {
+            self.used_stasis = true;
+            Action::Stasis
+        } else if num >= 70 && !self.history.last_two(Action::SupportBeam) {
+            Action::SupportBeam
+        } else if !self.history.last_two(Action::Beam) {
+            Action::Beam
+        } else {
+            Action::SupportBeam
+        };
+        self.history.add(next);
+        self.action = next;
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::None => unreachable!(),
+            Action::Stasis => Intent::StrongDebuff,
+            Action::Beam => Intent::Attack(8, 1),
+            Action::SupportBeam => Intent::Defend,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_matches, cards::CardClass, combat::EndTurnStep, game::GameBuilder,
+        monsters::test::NoopMonster,
+    };
+
+    #[test]
+    fn test_stasis_once() {
+        let mut g = GameBuilder::default()
+            .seed(0)
+            .add_cards(CardClass::Strike, 10)
+            .build_combat_with_monsters(
+                NoopMonster::new(),
+                BronzeOrb::new(CreatureRef::monster(0)),
+            );
+        let mut stases = 0;
+        for _ in 0..10 {
+            let intent = g.monsters[1].behavior.get_intent();
+            if matches!(intent, Intent::StrongDebuff) {
+                stases += 1;
+            } else {
+                assert_matches!(intent, Intent::Attack(8, 1) | Intent::Defend);
+            }
+            g.player.cur_hp = g.player.max_hp;
+            g.step_test(EndTurnStep);
+        }
+        assert_eq!(stases, 1);
+        assert!(g.monsters[1].held_card.is_some());
+    }
+
+    #[test]
+    fn test_support_beam() {
+        let mut g = GameBuilder::default().build_combat_with_monsters(
+            NoopMonster::new(),
+            BronzeOrb::new(CreatureRef::monster(0)),
+        );
+        g.monsters[1].behavior = Box::new(BronzeOrb {
+            action: Action::SupportBeam,
+            history: MoveHistory::new(),
+            used_stasis: true,
+            automaton: CreatureRef::monster(0),
+        });
+        g.step_test(EndTurnStep);
+        assert_eq!(g.monsters[0].creature.block, 12);
+        assert_eq!(g.monsters[1].creature.block, 0);
+    }
+}
@@ -0,0 +1,220 @@
+use rand::RngExt;
+
+use crate::{
+    actions::{damage::DamageAction, gain_status::GainStatusAction},
+    creature::Creature,
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    move_history::MoveHistory,
+    queue::ActionQueue,
+    status::Status,
+};
+
+const FLIGHT: i32 = 4;
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    Start,
+    Peck,
+    Swoop,
+    Caw,
+    Stunned,
+    Headbutt,
+    FlyUp,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct Byrd {
+    action: Action,
+    history: MoveHistory<Action>,
+    flying: bool,
+}
+
+impl Byrd {
+    pub fn new() -> Self {
+        Self {
+            action: Action::Start,
+            history: MoveHistory::new(),
+            flying: true,
+        }
+    }
+    fn gain_flight(this: CreatureRef, queue: &mut ActionQueue) {
+        queue.push_bot(GainStatusAction {
+            status: Status::Flight,
+            amount: FLIGHT,
+            target: this,
+        });
+    }
+}
+
+impl MonsterBehavior for Byrd {
+    fn name(&self) -> &'static str {
+        "byrd"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (26, 33)
+    }
+
+    fn pre_combat(&self, queue: &mut ActionQueue, this: CreatureRef, _: &mut Rand) {
+        Self::gain_flight(this, queue);
+    }
+
+    fn on_turn_begin(&mut self, _this: CreatureRef, c: &mut Creature) {
+        if c.has_status(Status::Flight) {
+            c.set_status(Status::Flight, FLIGHT);
+        }
+    }
+
+    // knocked down once it runs out of flight
+    fn on_lose_status(&mut self, _this: CreatureRef, status: Status) {
+        if status == Status::Flight {
+            self.flying = false;
+            self.action = Action::Stunned;
+            self.history.add(Action::Stunned);
+        }
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        match self.action {
+            Action::Peck => {
+                for _ in 0..6 {
+                    queue.push_bot(DamageAction::from_monster(1, this));
+                }
+            }
+            Action::Swoop => queue.push_bot(DamageAction::from_monster(14, this)),
+            Action::Caw => queue.push_bot(GainStatusAction {
+                status: Status::Strength,
+                amount: 1,
+                target: this,
+            }),
+            Action::Stunned => {}
+            Action::Headbutt => queue.push_bot(DamageAction::from_monster(3, this)),
+            Action::FlyUp => {
+                self.flying = true;
+                Self::gain_flight(this, queue);
+            }
+            Action::Start => unreachable!(),
+        }
+    }
+    fn roll_next_action(&mut self, r: &mut Rand, _info: &MonsterInfo) {
+        let next = match self.action {
+            Action::Start => {
+                if r.random_bool(0.375) {
+                    Action::Caw
+                } else {
+                    Action::Peck
+                }
+            }
+            Action::Stunned => Action::Headbutt,
+            _ if !self.flying => Action::FlyUp,
+            _ => {
+                let num = r.random_range(0..100);
+                if num < 50 {
+                    if self.history.last_two(Action::Peck) {
+                        if r.random_bool(0.4) {
+                            Action::Swoop
+                        } else {
+                            Action::Caw
+                        }
+                    } else {
+                        Action::Peck
+                    }
+                } else if num < 70 {
+                    if self.history.last(Action::Swoop) {
+                        if r.random_bool(0.375) {
+                            Action::Caw
+                        } else {
+                            Action::Peck
+                        }
+                    } else {
+                        Action::Swoop
+                    }
+                } else if self.history.last(Action::Caw) {
+                    if r.random_bool(0.2857) {
+                        Action::Swoop
+                    } else {
+                        Action::Peck
+                    }
+                } else {
+                    Action::Caw
+                }
+            }
+        };
+        self.history.add(next);
+        self.action = next;
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::Start => unreachable!(),
+            Action::Peck => Intent::Attack(1, 6),
+            Action::Swoop => Intent::Attack(14, 1),
+            Action::Caw => Intent::Buff,
+            Action::Stunned => Intent::Stun,
+            Action::Headbutt => Intent::Attack(3, 1),
+            Action::FlyUp => Intent::Buff,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        actions::increase_max_hp::IncreaseMaxHPAction, assert_matches, cards::CardClass,
+        combat::EndTurnStep, game::GameBuilder, potion::Potion,
+    };
+
+    #[test]
+    fn test_flight() {
+        let mut g = GameBuilder::default().build_combat_with_monster(Byrd::new());
+        g.run_action(IncreaseMaxHPAction(999));
+        let hp = g.monsters[0].creature.cur_hp;
+        assert_eq!(g.monsters[0].creature.get_status(Status::Flight), Some(4));
+        g.play_card(CardClass::Strike, Some(CreatureRef::monster(0)));
+        assert_eq!(g.monsters[0].creature.cur_hp, hp - 3);
+        assert_eq!(g.monsters[0].creature.get_status(Status::Flight), Some(3));
+        // thorns style damage doesn't knock it down or get halved
+        g.throw_potion(Potion::Fire, Some(CreatureRef::monster(0)));
+        assert_eq!(g.monsters[0].creature.cur_hp, hp - 23);
+        assert_eq!(g.monsters[0].creature.get_status(Status::Flight), Some(3));
+
+        g.step_test(EndTurnStep);
+        assert_eq!(g.monsters[0].creature.get_status(Status::Flight), Some(4));
+    }
+
+    #[test]
+    fn test_grounded() {
+        let mut g = GameBuilder::default().build_combat_with_monster(Byrd::new());
+        g.run_action(IncreaseMaxHPAction(999));
+        g.energy = 5;
+        for _ in 0..3 {
+            g.play_card(CardClass::Strike, Some(CreatureRef::monster(0)));
+        }
+        assert_eq!(g.monsters[0].creature.get_status(Status::Flight), Some(1));
+        assert_matches!(
+            g.monsters[0].behavior.get_intent(),
+            Intent::Attack(..) | Intent::Buff
+        );
+        let hp = g.monsters[0].creature.cur_hp;
+        g.play_card(CardClass::Strike, Some(CreatureRef::monster(0)));
+        assert_eq!(g.monsters[0].creature.cur_hp, hp - 3);
+        assert!(!g.monsters[0].creature.has_status(Status::Flight));
+        assert_matches!(g.monsters[0].behavior.get_intent(), Intent::Stun);
+        g.play_card(CardClass::Strike, Some(CreatureRef::monster(0)));
+        assert_eq!(g.monsters[0].creature.cur_hp, hp - 9);
+
+        g.step_test(EndTurnStep);
+        assert_matches!(g.monsters[0].behavior.get_intent(), Intent::Attack(3, 1));
+        g.player.cur_hp = 50;
+        g.step_test(EndTurnStep);
+        assert_eq!(g.player.cur_hp, 47);
+        assert_matches!(g.monsters[0].behavior.get_intent(), Intent::Buff);
+        g.step_test(EndTurnStep);
+        assert_eq!(g.monsters[0].creature.get_status(Status::Flight), Some(4));
+        assert_matches!(
+            g.monsters[0].behavior.get_intent(),
+            Intent::Attack(..) | Intent::Buff
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use rand::RngExt;
+
+use crate::{
+    actions::{block_random_monster::BlockRandomMonsterAction, damage::DamageAction},
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    move_history::MoveHistory,
+    queue::ActionQueue,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    None,
+    Slash,
+    Protect,
+    Fury,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct Centurion {
+    action: Action,
+    history: MoveHistory<Action>,
+}
+
+impl Centurion {
+    pub fn new() -> Self {
+        Self {
+            action: Action::None,
+            history: MoveHistory::new(),
+        }
+    }
+}
+
+impl MonsterBehavior for Centurion {
+    fn name(&self) -> &'static str {
+        "centurion"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (78, 83)
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        match self.action {
+            Action::Slash => queue.push_bot(DamageAction::from_monster(14, this)),
+            Action::Protect => queue.push_bot(BlockRandomMonsterAction {
+                source: this,
+                amount: 20,
+            }),
+            Action::Fury => {
+                for _ in 0..3 {
+                    queue.push_bot(DamageAction::from_monster(7, this));
+                }
+            }
+            Action::None => unreachable!(),
+        }
+    }
+    fn roll_next_action(&mut self, r: &mut Rand, info: &MonsterInfo) {
+        // protects its allies, or attacks harder once it's alone
+        let protect_or_fury = if info.num_alive_monsters > 1 {
+            Action::Protect
+        } else {
+            Action::Fury
+        };
+        let next = if r.random_range(0..100) >= 65
+            && !self.history.last_two(Action::Protect)
+            && !self.history.last_two(Action::Fury)
+        {
+            protect_or_fury
+        } else if !self.history.last_two(Action::Slash) {
+            Action::Slash
+        } else {
+            protect_or_fury
+        };
+        self.history.add(next);
+        self.action = next;
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::None => unreachable!(),
+            Action::Slash => Intent::Attack(14, 1),
+            Action::Protect => Intent::Defend,
+            Action::Fury => Intent::Attack(7, 3),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_matches, combat::EndTurnStep, game::GameBuilder, monsters::test::NoopMonster,
+    };
+
+    #[test]
+    fn test_fury_when_alone() {
+        let mut g = GameBuilder::default()
+            .seed(0)
+            .build_combat_with_monster(Centurion::new());
+        for _ in 0..20 {
+            assert_matches!(
+                g.monsters[0].behavior.get_intent(),
+                Intent::Attack(14, 1) | Intent::Attack(7, 3)
+            );
+            g.player.cur_hp = g.player.max_hp;
+            g.step_test(EndTurnStep);
+        }
+    }
+
+    #[test]
+    fn test_protect() {
+        let mut g = GameBuilder::default()
+            .seed(0)
+            .build_combat_with_monsters(Centurion::new(), NoopMonster::new());
+        let mut protected = false;
+        for _ in 0..20 {
+            let protect = matches!(g.monsters[0].behavior.get_intent(), Intent::Defend);
+            g.player.cur_hp = g.player.max_hp;
+            g.step_test(EndTurnStep);
+            if protect {
+                assert_eq!(g.monsters[1].creature.block, 20);
+                protected = true;
+                break;
+            }
+        }
+        assert!(protected);
+    }
+}
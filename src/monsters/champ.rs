@@ -0,0 +1,232 @@
+use rand::RngExt;
+
+use crate::{
+    actions::{
+        block::BlockAction, damage::DamageAction, gain_status::GainStatusAction,
+        remove_all_debuffs::RemoveAllDebuffsAction,
+    },
+    creature::Creature,
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    move_history::MoveHistory,
+    queue::ActionQueue,
+    status::Status,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    None,
+    HeavySlash,
+    DefensiveStance,
+    Execute,
+    FaceSlap,
+    Gloat,
+    Taunt,
+    Anger,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct Champ {
+    action: Action,
+    history: MoveHistory<Action>,
+    num_turns: i32,
+    defensive_stances: i32,
+    bloodied: bool,
+    angry: bool,
+}
+
+impl Champ {
+    pub fn new() -> Self {
+        Self {
+            action: Action::None,
+            history: MoveHistory::new(),
+            num_turns: 0,
+            defensive_stances: 0,
+            bloodied: false,
+            angry: false,
+        }
+    }
+}
+
+impl MonsterBehavior for Champ {
+    fn name(&self) -> &'static str {
+        "champ"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (440, 440)
+    }
+
+    fn on_take_damage(
+        &mut self,
+        _this: CreatureRef,
+        this_creature: &mut Creature,
+        _rng: &mut Rand,
+    ) {
+        if this_creature.is_bloodied() {
+            self.bloodied = true;
+        }
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        let player = CreatureRef::player();
+        match self.action {
+            Action::HeavySlash => queue.push_bot(DamageAction::from_monster(18, this)),
+            Action::DefensiveStance => {
+                queue.push_bot(BlockAction::monster(this, 20));
+                queue.push_bot(GainStatusAction {
+                    status: Status::Metallicize,
+                    amount: 7,
+                    target: this,
+                });
+            }
+            Action::Execute => {
+                for _ in 0..2 {
+                    queue.push_bot(DamageAction::from_monster(10, this));
+                }
+            }
+            Action::FaceSlap => {
+                queue.push_bot(DamageAction::from_monster(14, this));
+                queue.push_bot(GainStatusAction {
+                    status: Status::Frail,
+                    amount: 2,
+                    target: player,
+                });
+                queue.push_bot(GainStatusAction {
+                    status: Status::Vulnerable,
+                    amount: 2,
+                    target: player,
+                });
+            }
+            Action::Gloat => queue.push_bot(GainStatusAction {
+                status: Status::Strength,
+                amount: 4,
+                target: this,
+            }),
+            Action::Taunt => {
+                queue.push_bot(GainStatusAction {
+                    status: Status::Weak,
+                    amount: 2,
+                    target: player,
+                });
+                queue.push_bot(GainStatusAction {
+                    status: Status::Vulnerable,
+                    amount: 2,
+                    target: player,
+                });
+            }
+            Action::Anger => {
+                queue.push_bot(RemoveAllDebuffsAction(this));
+                queue.push_bot(GainStatusAction {
+                    status: Status::Strength,
+                    amount: 12,
+                    target: this,
+                });
+            }
+            Action::None => unreachable!(),
+        }
+    }
+    fn roll_next_action(&mut self, r: &mut Rand, _info: &MonsterInfo) {
+        self.num_turns += 1;
+        let num = r.random_range(0..100);
+        let next = if self.bloodied && !self.angry {
+            self.angry = true;
+            Action::Anger
+        } else if self.angry
+            && !self.history.last(Action::Execute)
+            && !self.history.last_last(Action::Execute)
+        {
+            Action::Execute
+        } else if self.num_turns == 4 && !self.angry {
+            self.num_turns = 0;
+            Action::Taunt
+        } else if !self.history.last(Action::DefensiveStance)
+            && self.defensive_stances < 2
+            && num <= 30
+        {
+            self.defensive_stances += 1;
+            Action::DefensiveStance
+        } else if !self.history.last(Action::Gloat)
+            && !self.history.last(Action::DefensiveStance)
+            && num <= 30
+        {
+            Action::Gloat
+        } else if !self.history.last(Action::FaceSlap) && num <= 55 {
+            Action::FaceSlap
+        } else if !self.history.last(Action::HeavySlash) {
+            Action::HeavySlash
+        } else {
+            Action::FaceSlap
+        };
+        self.history.add(next);
+        self.action = next;
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::None => unreachable!(),
+            Action::HeavySlash => Intent::Attack(18, 1),
+            Action::DefensiveStance => Intent::DefendBuff,
+            Action::Execute => Intent::Attack(10, 2),
+            Action::FaceSlap => Intent::AttackDebuff(14, 1),
+            Action::Gloat => Intent::Buff,
+            Action::Taunt => Intent::Debuff,
+            Action::Anger => Intent::Buff,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_matches, cards::CardClass, combat::EndTurnStep, game::GameBuilder, potion::Potion,
+    };
+
+    #[test]
+    fn test_taunt_every_fourth_turn() {
+        let mut g = GameBuilder::default()
+            .seed(0)
+            .build_combat_with_monster(Champ::new());
+        g.player.max_hp = 999;
+        for turn in 1..=8 {
+            let taunt = matches!(g.monsters[0].behavior.get_intent(), Intent::Debuff);
+            assert_eq!(taunt, turn % 4 == 0);
+            g.player.cur_hp = g.player.max_hp;
+            g.step_test(EndTurnStep);
+        }
+    }
+
+    #[test]
+    fn test_anger() {
+        let mut g = GameBuilder::default()
+            .seed(0)
+            .build_combat_with_monster(Champ::new());
+        g.player.max_hp = 999;
+        g.throw_potion(Potion::Weak, Some(CreatureRef::monster(0)));
+        g.play_card(CardClass::Bash, Some(CreatureRef::monster(0)));
+        g.monsters[0].creature.cur_hp = 225;
+        g.play_card(CardClass::Strike, Some(CreatureRef::monster(0)));
+        g.player.cur_hp = g.player.max_hp;
+        g.step_test(EndTurnStep);
+        assert_matches!(g.monsters[0].behavior.get_intent(), Intent::Buff);
+        g.player.cur_hp = g.player.max_hp;
+        g.step_test(EndTurnStep);
+        assert!(!g.monsters[0].creature.has_status(Status::Weak));
+        assert!(!g.monsters[0].creature.has_status(Status::Vulnerable));
+        assert_eq!(
+            g.monsters[0].creature.get_status(Status::Strength),
+            Some(12)
+        );
+
+        let mut executes = vec![];
+        for _ in 0..6 {
+            executes.push(matches!(
+                g.monsters[0].behavior.get_intent(),
+                Intent::Attack(10, 2)
+            ));
+            g.player.cur_hp = g.player.max_hp;
+            g.step_test(EndTurnStep);
+        }
+        assert_eq!(executes, vec![true, false, false, true, false, false]);
+    }
+}
@@ -0,0 +1,157 @@
+use rand::RngExt;
+
+use crate::{
+    actions::{damage::DamageAction, gain_status::GainStatusAction},
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    move_history::MoveHistory,
+    queue::ActionQueue,
+    status::Status,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    Start,
+    Hex,
+    Debilitate,
+    Drain,
+    Zap,
+    Poke,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct Chosen {
+    action: Action,
+    history: MoveHistory<Action>,
+}
+
+impl Chosen {
+    pub fn new() -> Self {
+        Self {
+            action: Action::Start,
+            history: MoveHistory::new(),
+        }
+    }
+}
+
+impl MonsterBehavior for Chosen {
+    fn name(&self) -> &'static str {
+        "chosen"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (98, 103)
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        match self.action {
+            Action::Hex => queue.push_bot(GainStatusAction {
+                status: Status::Hex,
+                amount: 1,
+                target: CreatureRef::player(),
+            }),
+            Action::Debilitate => {
+                queue.push_bot(DamageAction::from_monster(12, this));
+                queue.push_bot(GainStatusAction {
+                    status: Status::Vulnerable,
+                    amount: 2,
+                    target: CreatureRef::player(),
+                });
+            }
+            Action::Drain => {
+                queue.push_bot(GainStatusAction {
+                    status: Status::Weak,
+                    amount: 3,
+                    target: CreatureRef::player(),
+                });
+                queue.push_bot(GainStatusAction {
+                    status: Status::Strength,
+                    amount: 3,
+                    target: this,
+                });
+            }
+            Action::Zap => queue.push_bot(DamageAction::from_monster(21, this)),
+            Action::Poke => {
+                for _ in 0..2 {
+                    queue.push_bot(DamageAction::from_monster(6, this));
+                }
+            }
+            Action::Start => unreachable!(),
+        }
+    }
+    fn roll_next_action(&mut self, r: &mut Rand, _info: &MonsterInfo) {
+        let next = if self.action == Action::Start {
+            Action::Hex
+        } else if !self.history.last(Action::Debilitate) && !self.history.last(Action::Drain) {
+            if r.random_range(0..100) < 50 {
+                Action::Debilitate
+            } else {
+                Action::Drain
+            }
+        } else if r.random_range(0..100) < 40 {
+            Action::Zap
+        } else {
+            Action::Poke
+        };
+        self.history.add(next);
+        self.action = next;
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::Start => unreachable!(),
+            Action::Hex => Intent::StrongDebuff,
+            Action::Debilitate => Intent::AttackDebuff(12, 1),
+            Action::Drain => Intent::Debuff,
+            Action::Zap => Intent::Attack(21, 1),
+            Action::Poke => Intent::Attack(6, 2),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_matches, assert_not_matches, cards::CardClass, combat::EndTurnStep,
+        game::GameBuilder,
+    };
+
+    #[test]
+    fn test_hex() {
+        let mut g = GameBuilder::default().build_combat_with_monster(Chosen::new());
+        assert_matches!(g.monsters[0].behavior.get_intent(), Intent::StrongDebuff);
+        g.step_test(EndTurnStep);
+        assert_eq!(g.player.get_status(Status::Hex), Some(1));
+        assert_not_matches!(g.monsters[0].behavior.get_intent(), Intent::StrongDebuff);
+
+        let draw = g.draw_pile.len();
+        g.play_card(CardClass::Strike, Some(CreatureRef::monster(0)));
+        assert_eq!(g.draw_pile.len(), draw);
+        g.play_card(CardClass::Defend, None);
+        assert_eq!(g.draw_pile.len(), draw + 1);
+        assert!(
+            g.draw_pile
+                .get_all()
+                .iter()
+                .any(|c| g.cards[*c].class == CardClass::Dazed)
+        );
+    }
+
+    #[test]
+    fn test_debuff_every_other_turn() {
+        let mut g = GameBuilder::default()
+            .seed(0)
+            .build_combat_with_monster(Chosen::new());
+        let mut last_debuffed = false;
+        for _ in 0..20 {
+            g.player.cur_hp = g.player.max_hp;
+            g.step_test(EndTurnStep);
+            let debuffs = matches!(
+                g.monsters[0].behavior.get_intent(),
+                Intent::AttackDebuff(..) | Intent::Debuff
+            );
+            assert_eq!(debuffs, !last_debuffed);
+            last_debuffed = debuffs;
+        }
+    }
+}
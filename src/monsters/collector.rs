@@ -0,0 +1,186 @@
+use rand::RngExt;
+
+use crate::{
+    actions::{
+        block::BlockAction,
+        damage::DamageAction,
+        gain_status::GainStatusAction,
+        gain_status_all_monsters::GainStatusAllMonstersAction,
+        kill_all_monsters::KillAllMonstersAction,
+        summon_monster::{SummonMonsterAction, SummonMonsterType},
+    },
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    move_history::MoveHistory,
+    queue::ActionQueue,
+    status::Status,
+};
+
+const TORCH_HEADS: usize = 2;
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    None,
+    Spawn,
+    Fireball,
+    Buff,
+    MegaDebuff,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct Collector {
+    action: Action,
+    history: MoveHistory<Action>,
+    turns_taken: i32,
+    used_mega_debuff: bool,
+}
+
+impl Collector {
+    pub fn new() -> Self {
+        Self {
+            action: Action::None,
+            history: MoveHistory::new(),
+            turns_taken: 0,
+            used_mega_debuff: false,
+        }
+    }
+}
+
+impl MonsterBehavior for Collector {
+    fn name(&self) -> &'static str {
+        "collector"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (300, 300)
+    }
+
+    // the torch heads go out with it
+    fn on_death(&mut self, _this: CreatureRef, queue: &mut ActionQueue) {
+        queue.push_top(KillAllMonstersAction);
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, info: &MonsterInfo) {
+        match self.action {
+            Action::Spawn => {
+                for _ in info.num_alive_monsters - 1..TORCH_HEADS {
+                    queue.push_bot(SummonMonsterAction(SummonMonsterType::TorchHead));
+                }
+            }
+            Action::Fireball => queue.push_bot(DamageAction::from_monster(21, this)),
+            Action::Buff => {
+                queue.push_bot(BlockAction::monster(this, 23));
+                queue.push_bot(GainStatusAllMonstersAction {
+                    status: Status::Strength,
+                    amount: 5,
+                });
+            }
+            Action::MegaDebuff => {
+                for status in [Status::Weak, Status::Vulnerable, Status::Frail] {
+                    queue.push_bot(GainStatusAction {
+                        status,
+                        amount: 3,
+                        target: CreatureRef::player(),
+                    });
+                }
+            }
+            Action::None => unreachable!(),
+        }
+        self.turns_taken += 1;
+    }
+    fn roll_next_action(&mut self, r: &mut Rand, info: &MonsterInfo) {
+        let torch_heads = info.num_alive_monsters - 1;
+        let num = r.random_range(0..100);
+        let next = if self.action == Action::None {
+            Action::Spawn
+        } else if self.turns_taken >= 3 && !self.used_mega_debuff {
+            self.used_mega_debuff = true;
+            Action::MegaDebuff
+        } else if num <= 25 && torch_heads < TORCH_HEADS && !self.history.last(Action::Spawn) {
+            Action::Spawn
+        } else if num <= 70 && !self.history.last_two(Action::Fireball) {
+            Action::Fireball
+        } else if !self.history.last(Action::Buff) {
+            Action::Buff
+        } else {
+            Action::Fireball
+        };
+        self.history.add(next);
+        self.action = next;
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::None => unreachable!(),
+            Action::Spawn => Intent::Unknown,
+            Action::Fireball => Intent::Attack(21, 1),
+            Action::Buff => Intent::DefendBuff,
+            Action::MegaDebuff => Intent::StrongDebuff,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        actions::damage::DamageAction,
+        assert_matches,
+        combat::EndTurnStep,
+        game::{CombatType, GameBuilder},
+    };
+
+    #[test]
+    fn test_spawn_and_mega_debuff() {
+        let mut g = GameBuilder::default()
+            .seed(0)
+            .build_combat_with_monster(Collector::new());
+        g.player.max_hp = 999;
+        g.player.cur_hp = 999;
+        assert_matches!(g.monsters[0].behavior.get_intent(), Intent::Unknown);
+        g.step_test(EndTurnStep);
+        assert_eq!(g.monsters.len(), 3);
+        assert_eq!(g.monsters[1].behavior.name(), "torch head");
+        assert_eq!(g.monsters[2].behavior.name(), "torch head");
+        g.step_test(EndTurnStep);
+        g.step_test(EndTurnStep);
+        assert_matches!(g.monsters[0].behavior.get_intent(), Intent::StrongDebuff);
+        g.step_test(EndTurnStep);
+        assert_eq!(g.player.get_status(Status::Weak), Some(3));
+        assert_eq!(g.player.get_status(Status::Vulnerable), Some(3));
+        assert_eq!(g.player.get_status(Status::Frail), Some(3));
+    }
+
+    #[test]
+    fn test_respawn() {
+        let mut g = GameBuilder::default().build_combat_with_monster(Collector::new());
+        g.player.max_hp = 999;
+        g.player.cur_hp = 999;
+        g.step_test(EndTurnStep);
+        g.run_action(DamageAction::thorns_no_rupture(
+            999,
+            CreatureRef::monster(1),
+        ));
+        g.monsters[0].behavior = Box::new(Collector {
+            action: Action::Spawn,
+            history: MoveHistory::new(),
+            turns_taken: 1,
+            used_mega_debuff: false,
+        });
+        g.step_test(EndTurnStep);
+        assert_eq!(g.monsters.len(), 3);
+        assert!(g.monsters.iter().all(|m| m.creature.is_actionable()));
+    }
+
+    #[test]
+    fn test_torch_heads_die_with_it() {
+        let mut g = GameBuilder::default().build_combat_with_monster(Collector::new());
+        g.player.max_hp = 999;
+        g.player.cur_hp = 999;
+        g.step_test(EndTurnStep);
+        g.run_action(DamageAction::thorns_no_rupture(
+            999,
+            CreatureRef::monster(0),
+        ));
+        assert_eq!(g.in_combat, CombatType::None);
+    }
+}
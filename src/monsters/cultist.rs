@@ -6,13 +6,14 @@ use crate::{
     status::Status,
 };
 
-#[derive(PartialEq, Eq, Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 enum Action {
     Start,
     Buff,
     Attack,
 }
 
+#[derive(PartialEq, Eq, Hash, Clone)]
 pub struct Cultist {
     action: Action,
 }
@@ -0,0 +1,212 @@
+use rand::RngExt;
+
+use crate::{
+    actions::{
+        block::BlockAction, damage::DamageAction, gain_status::GainStatusAction,
+        revive_monster::ReviveMonsterAction,
+    },
+    creature::Creature,
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    move_history::MoveHistory,
+    queue::ActionQueue,
+    status::Status,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    None,
+    Chomp,
+    Harden,
+    Nip,
+    Count,
+    Reincarnate,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct Darkling {
+    action: Action,
+    history: MoveHistory<Action>,
+    // only every other darkling chomps
+    can_chomp: bool,
+    nip_damage: i32,
+    reviving: bool,
+    counted: bool,
+}
+
+impl Darkling {
+    pub fn new(can_chomp: bool) -> Self {
+        Self {
+            action: Action::None,
+            history: MoveHistory::new(),
+            can_chomp,
+            nip_damage: 0,
+            reviving: false,
+            counted: false,
+        }
+    }
+}
+
+impl MonsterBehavior for Darkling {
+    fn name(&self) -> &'static str {
+        "darkling"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (50, 59)
+    }
+
+    fn pre_combat(&self, queue: &mut ActionQueue, this: CreatureRef, _: &mut Rand) {
+        queue.push_bot(GainStatusAction {
+            status: Status::Regrow,
+            amount: 1,
+            target: this,
+        });
+    }
+
+    fn on_take_damage(
+        &mut self,
+        _this: CreatureRef,
+        this_creature: &mut Creature,
+        _rng: &mut Rand,
+    ) {
+        if this_creature.cur_hp <= 0 {
+            self.reviving = true;
+            self.counted = false;
+            self.action = Action::Count;
+        }
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        match self.action {
+            Action::Chomp => {
+                for _ in 0..2 {
+                    queue.push_bot(DamageAction::from_monster(9, this));
+                }
+            }
+            Action::Harden => {
+                queue.push_bot(BlockAction::monster(this, 12));
+                queue.push_bot(GainStatusAction {
+                    status: Status::Strength,
+                    amount: 2,
+                    target: this,
+                });
+            }
+            Action::Nip => queue.push_bot(DamageAction::from_monster(self.nip_damage, this)),
+            Action::Count => self.counted = true,
+            Action::Reincarnate => {
+                self.reviving = false;
+                queue.push_bot(ReviveMonsterAction::half_hp(this));
+                queue.push_bot(GainStatusAction {
+                    status: Status::Regrow,
+                    amount: 1,
+                    target: this,
+                });
+            }
+            Action::None => unreachable!(),
+        }
+    }
+    fn roll_next_action(&mut self, r: &mut Rand, _info: &MonsterInfo) {
+        let next = if self.reviving {
+            if self.counted {
+                Action::Reincarnate
+            } else {
+                Action::Count
+            }
+        } else if self.action == Action::None {
+            self.nip_damage = r.random_range(9..=13);
+            if r.random_range(0..100) < 50 {
+                Action::Harden
+            } else {
+                Action::Nip
+            }
+        } else {
+            let mut num = r.random_range(0..100);
+            loop {
+                if num < 40 {
+                    if !self.history.last(Action::Chomp) && self.can_chomp {
+                        break Action::Chomp;
+                    }
+                    num = r.random_range(40..100);
+                } else if num < 70 {
+                    if !self.history.last(Action::Harden) {
+                        break Action::Harden;
+                    }
+                    break Action::Nip;
+                } else {
+                    if !self.history.last_two(Action::Nip) {
+                        break Action::Nip;
+                    }
+                    num = r.random_range(0..100);
+                }
+            }
+        };
+        self.history.add(next);
+        self.action = next;
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::None => unreachable!(),
+            Action::Chomp => Intent::Attack(9, 2),
+            Action::Harden => Intent::DefendBuff,
+            Action::Nip => Intent::Attack(self.nip_damage, 1),
+            Action::Count => Intent::Unknown,
+            Action::Reincarnate => Intent::Buff,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_matches, cards::CardClass, combat::EndTurnStep, creature::CreatureState,
+        game::GameBuilder, monsters::Combat,
+    };
+
+    #[test]
+    fn test_revive() {
+        let mut g = GameBuilder::default()
+            .seed(0)
+            .add_cards(CardClass::Strike, 5)
+            .build_fixed_combat(Combat::ThreeDarklings);
+        g.player.max_hp = 999;
+        g.player.cur_hp = g.player.max_hp;
+        g.monsters[0].creature.cur_hp = 1;
+        g.play_card(CardClass::Strike, Some(CreatureRef::monster(0)));
+        assert_eq!(g.monsters[0].creature.state, CreatureState::Reviving);
+        assert!(!g.monsters[0].creature.has_status(Status::Regrow));
+        assert!(!g.combat_finished());
+        assert_matches!(g.monsters[0].behavior.get_intent(), Intent::Unknown);
+
+        g.step_test(EndTurnStep);
+        assert_eq!(g.monsters[0].creature.state, CreatureState::Reviving);
+        assert_matches!(g.monsters[0].behavior.get_intent(), Intent::Buff);
+
+        g.player.cur_hp = g.player.max_hp;
+        g.step_test(EndTurnStep);
+        let c = &g.monsters[0].creature;
+        assert_eq!(c.state, CreatureState::Alive);
+        assert_eq!(c.cur_hp, c.max_hp / 2);
+        assert!(c.has_status(Status::Regrow));
+    }
+
+    #[test]
+    fn test_all_down_at_once() {
+        let mut g = GameBuilder::default()
+            .seed(0)
+            .add_cards(CardClass::Strike, 5)
+            .build_fixed_combat(Combat::ThreeDarklings);
+        for i in 0..3 {
+            g.monsters[i].creature.cur_hp = 1;
+        }
+        g.play_card(CardClass::Strike, Some(CreatureRef::monster(0)));
+        g.play_card(CardClass::Strike, Some(CreatureRef::monster(1)));
+        assert_eq!(g.monsters[1].creature.state, CreatureState::Reviving);
+        g.play_card(CardClass::Strike, Some(CreatureRef::monster(2)));
+        for m in &g.monsters {
+            assert_eq!(m.creature.state, CreatureState::Dead);
+        }
+        assert!(g.combat_finished());
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{
+    actions::{damage::DamageAction, gain_status::GainStatusAction},
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    queue::ActionQueue,
+    status::Status,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct Exploder {
+    num_turns: i32,
+}
+
+impl Exploder {
+    pub fn new() -> Self {
+        Self { num_turns: 0 }
+    }
+}
+
+impl MonsterBehavior for Exploder {
+    fn name(&self) -> &'static str {
+        "exploder"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (30, 35)
+    }
+
+    fn pre_combat(&self, queue: &mut ActionQueue, this: CreatureRef, _: &mut Rand) {
+        queue.push_bot(GainStatusAction {
+            status: Status::Explosive,
+            amount: 3,
+            target: this,
+        });
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        // the explosion itself comes from the explosive status
+        if self.num_turns <= 2 {
+            queue.push_bot(DamageAction::from_monster(11, this));
+        }
+    }
+    fn roll_next_action(&mut self, _: &mut Rand, _info: &MonsterInfo) {
+        self.num_turns += 1;
+    }
+
+    fn get_intent(&self) -> Intent {
+        if self.num_turns <= 2 {
+            Intent::Attack(11, 1)
+        } else {
+            Intent::Unknown
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_matches, combat::EndTurnStep, game::GameBuilder, monsters::test::NoopMonster,
+    };
+
+    #[test]
+    fn test_explodes_on_third_turn() {
+        let mut g =
+            GameBuilder::default().build_combat_with_monsters(Exploder::new(), NoopMonster::new());
+        g.player.max_hp = 999;
+        g.player.cur_hp = 999;
+        g.step_test(EndTurnStep);
+        g.step_test(EndTurnStep);
+        assert_eq!(g.player.cur_hp, 999 - 11 - 11);
+        assert_eq!(
+            g.monsters[0].creature.get_status(Status::Explosive),
+            Some(1)
+        );
+        assert_matches!(g.monsters[0].behavior.get_intent(), Intent::Unknown);
+        g.step_test(EndTurnStep);
+        assert_eq!(g.player.cur_hp, 999 - 11 - 11 - 30);
+        assert!(!g.monsters[0].creature.is_actionable());
+    }
+}
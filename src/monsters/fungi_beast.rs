@@ -9,13 +9,14 @@ use crate::{
     status::Status,
 };
 
-#[derive(PartialEq, Eq, Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 enum Action {
     None,
     Attack,
     Buff,
 }
 
+#[derive(PartialEq, Eq, Hash, Clone)]
 pub struct FungiBeast {
     action: Action,
     history: MoveHistory<Action>,
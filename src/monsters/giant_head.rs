@@ -0,0 +1,150 @@
+use rand::RngExt;
+
+use crate::{
+    actions::{damage::DamageAction, gain_status::GainStatusAction},
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    move_history::MoveHistory,
+    queue::ActionQueue,
+    status::Status,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    None,
+    Glare,
+    Count,
+    ItIsTime,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct GiantHead {
+    action: Action,
+    history: MoveHistory<Action>,
+    // counts down to It Is Time, then keeps going to make it hit harder
+    count: i32,
+}
+
+impl GiantHead {
+    pub fn new() -> Self {
+        Self {
+            action: Action::None,
+            history: MoveHistory::new(),
+            count: 4,
+        }
+    }
+    fn it_is_time_damage(&self) -> i32 {
+        40 - self.count * 5
+    }
+}
+
+impl MonsterBehavior for GiantHead {
+    fn name(&self) -> &'static str {
+        "giant head"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (520, 520)
+    }
+
+    fn pre_combat(&self, queue: &mut ActionQueue, this: CreatureRef, _: &mut Rand) {
+        queue.push_bot(GainStatusAction {
+            status: Status::Slow,
+            amount: 0,
+            target: this,
+        });
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        match self.action {
+            Action::Glare => queue.push_bot(GainStatusAction {
+                status: Status::Weak,
+                amount: 1,
+                target: CreatureRef::player(),
+            }),
+            Action::Count => queue.push_bot(DamageAction::from_monster(13, this)),
+            Action::ItIsTime => {
+                queue.push_bot(DamageAction::from_monster(self.it_is_time_damage(), this))
+            }
+            Action::None => unreachable!(),
+        }
+    }
+    fn roll_next_action(&mut self, r: &mut Rand, _info: &MonsterInfo) {
+        let num = r.random_range(0..100);
+        let next = if self.count <= 1 {
+            if self.count > -6 {
+                self.count -= 1;
+            }
+            Action::ItIsTime
+        } else {
+            self.count -= 1;
+            if num < 50 {
+                if !self.history.last_two(Action::Glare) {
+                    Action::Glare
+                } else {
+                    Action::Count
+                }
+            } else if !self.history.last_two(Action::Count) {
+                Action::Count
+            } else {
+                Action::Glare
+            }
+        };
+        self.history.add(next);
+        self.action = next;
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::None => unreachable!(),
+            Action::Glare => Intent::Debuff,
+            Action::Count => Intent::Attack(13, 1),
+            Action::ItIsTime => Intent::Attack(self.it_is_time_damage(), 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cards::CardClass, combat::EndTurnStep, game::GameBuilder};
+
+    #[test]
+    fn test_slow() {
+        let mut g = GameBuilder::default().build_combat_with_monster(GiantHead::new());
+        assert_eq!(g.monsters[0].creature.get_status(Status::Slow), Some(0));
+        let hp = g.monsters[0].creature.cur_hp;
+        g.play_card(CardClass::Strike, Some(CreatureRef::monster(0)));
+        assert_eq!(g.monsters[0].creature.cur_hp, hp - 6);
+        g.play_card(CardClass::Defend, None);
+        assert_eq!(g.monsters[0].creature.get_status(Status::Slow), Some(2));
+        g.play_card(CardClass::Strike, Some(CreatureRef::monster(0)));
+        // x1.2
+        assert_eq!(g.monsters[0].creature.cur_hp, hp - 6 - 7);
+        g.player.max_hp = 999;
+        g.player.cur_hp = 999;
+        g.step_test(EndTurnStep);
+        assert_eq!(g.monsters[0].creature.get_status(Status::Slow), Some(0));
+    }
+
+    #[test]
+    fn test_it_is_time() {
+        let mut g = GameBuilder::default().build_combat_with_monster(GiantHead::new());
+        g.player.max_hp = 999;
+        for _ in 0..3 {
+            assert!(!matches!(
+                g.monsters[0].behavior.get_intent(),
+                Intent::Attack(d, _) if d >= 40
+            ));
+            g.player.cur_hp = 999;
+            g.step_test(EndTurnStep);
+        }
+        for damage in [40, 45, 50, 55, 60, 65, 70, 70] {
+            assert_eq!(
+                g.monsters[0].behavior.get_intent(),
+                Intent::Attack(damage, 1)
+            );
+            g.player.cur_hp = 999;
+            g.step_test(EndTurnStep);
+        }
+    }
+}
@@ -6,6 +6,7 @@ use crate::{
     status::Status,
 };
 
+#[derive(PartialEq, Eq, Hash, Clone)]
 pub struct GremlinFat;
 
 impl GremlinFat {
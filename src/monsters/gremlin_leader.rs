@@ -0,0 +1,204 @@
+use rand::RngExt;
+
+use crate::{
+    actions::{
+        block_other_monsters::BlockOtherMonstersAction,
+        damage::DamageAction,
+        escape_all_monsters::EscapeAllMonstersAction,
+        gain_status_all_monsters::GainStatusAllMonstersAction,
+        summon_monster::{SummonMonsterAction, SummonMonsterType},
+    },
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    move_history::MoveHistory,
+    queue::ActionQueue,
+    status::Status,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    None,
+    Rally,
+    Encourage,
+    Stab,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct GremlinLeader {
+    action: Action,
+    history: MoveHistory<Action>,
+}
+
+impl GremlinLeader {
+    pub fn new() -> Self {
+        Self {
+            action: Action::None,
+            history: MoveHistory::new(),
+        }
+    }
+}
+
+impl MonsterBehavior for GremlinLeader {
+    fn name(&self) -> &'static str {
+        "gremlin leader"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (145, 155)
+    }
+
+    // the gremlins run away without their leader
+    fn on_death(&mut self, _this: CreatureRef, queue: &mut ActionQueue) {
+        queue.push_top(EscapeAllMonstersAction);
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        match self.action {
+            Action::Rally => {
+                for _ in 0..2 {
+                    queue.push_bot(SummonMonsterAction(SummonMonsterType::Gremlin));
+                }
+            }
+            Action::Encourage => {
+                queue.push_bot(GainStatusAllMonstersAction {
+                    status: Status::Strength,
+                    amount: 5,
+                });
+                queue.push_bot(BlockOtherMonstersAction {
+                    source: this,
+                    amount: 10,
+                });
+            }
+            Action::Stab => {
+                for _ in 0..3 {
+                    queue.push_bot(DamageAction::from_monster(6, this));
+                }
+            }
+            Action::None => unreachable!(),
+        }
+    }
+    fn roll_next_action(&mut self, r: &mut Rand, info: &MonsterInfo) {
+        let gremlins = info.num_alive_monsters - 1;
+        let mut num = r.random_range(0..100);
+        let next = loop {
+            if gremlins == 0 {
+                break if num < 75 {
+                    if self.history.last(Action::Rally) {
+                        Action::Stab
+                    } else {
+                        Action::Rally
+                    }
+                } else if self.history.last(Action::Stab) {
+                    Action::Rally
+                } else {
+                    Action::Stab
+                };
+            } else if gremlins < 2 {
+                if num < 50 {
+                    if !self.history.last(Action::Rally) {
+                        break Action::Rally;
+                    }
+                    num = r.random_range(50..100);
+                } else if num < 80 {
+                    break if self.history.last(Action::Encourage) {
+                        Action::Stab
+                    } else {
+                        Action::Encourage
+                    };
+                } else {
+                    if !self.history.last(Action::Stab) {
+                        break Action::Stab;
+                    }
+                    num = r.random_range(0..80);
+                }
+            } else {
+                break if num < 66 {
+                    if self.history.last(Action::Encourage) {
+                        Action::Stab
+                    } else {
+                        Action::Encourage
+                    }
+                } else if self.history.last(Action::Stab) {
+                    Action::Encourage
+                } else {
+                    Action::Stab
+                };
+            }
+        };
+        self.history.add(next);
+        self.action = next;
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::None => unreachable!(),
+            Action::Rally => Intent::Unknown,
+            Action::Encourage => Intent::DefendBuff,
+            Action::Stab => Intent::Attack(6, 3),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        actions::damage::DamageAction,
+        assert_matches,
+        combat::EndTurnStep,
+        game::{CombatType, GameBuilder},
+        monsters::{Combat, gremlin_mad::GremlinMad},
+    };
+
+    #[test]
+    fn test_encourage() {
+        let mut g = GameBuilder::default()
+            .build_combat_with_monsters(GremlinMad::new(), GremlinLeader::new());
+        g.monsters[1].behavior = Box::new(GremlinLeader {
+            action: Action::Encourage,
+            history: MoveHistory::new(),
+        });
+        g.player.cur_hp = g.player.max_hp;
+        g.step_test(EndTurnStep);
+        assert_eq!(g.monsters[0].creature.get_status(Status::Strength), Some(5));
+        assert_eq!(g.monsters[0].creature.block, 10);
+        assert_eq!(g.monsters[1].creature.get_status(Status::Strength), Some(5));
+        assert_eq!(g.monsters[1].creature.block, 0);
+    }
+
+    #[test]
+    fn test_rally_when_alone() {
+        let mut g = GameBuilder::default()
+            .seed(0)
+            .build_combat_with_monster(GremlinLeader::new());
+        let mut rallied = false;
+        for _ in 0..5 {
+            let rally = matches!(g.monsters[0].behavior.get_intent(), Intent::Unknown);
+            g.player.cur_hp = g.player.max_hp;
+            g.step_test(EndTurnStep);
+            if rally {
+                rallied = true;
+                break;
+            }
+        }
+        assert!(rallied);
+        assert_eq!(g.monsters.len(), 3);
+        assert!(g.monsters.iter().all(|m| m.creature.is_actionable()));
+        // with two gremlins back it encourages or stabs
+        assert_matches!(
+            g.monsters[0].behavior.get_intent(),
+            Intent::DefendBuff | Intent::Attack(6, 3)
+        );
+    }
+
+    #[test]
+    fn test_gremlins_escape() {
+        let mut g = GameBuilder::default().build_fixed_combat(Combat::GremlinLeader);
+        assert_eq!(g.monsters.len(), 3);
+        assert_eq!(g.monsters[2].behavior.name(), "gremlin leader");
+        g.run_action(DamageAction::thorns_no_rupture(
+            999,
+            CreatureRef::monster(2),
+        ));
+        assert_eq!(g.in_combat, CombatType::None);
+    }
+}
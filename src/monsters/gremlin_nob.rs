@@ -7,7 +7,7 @@ use crate::{
     status::Status,
 };
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 enum Action {
     None,
     Bellow,
@@ -15,6 +15,7 @@ enum Action {
     Rush,
 }
 
+#[derive(PartialEq, Eq, Hash, Clone)]
 pub struct GremlinNob {
     action: Action,
     history: MoveHistory<Action>,
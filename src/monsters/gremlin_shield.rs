@@ -5,6 +5,7 @@ use crate::{
     queue::ActionQueue,
 };
 
+#[derive(PartialEq, Eq, Hash, Clone)]
 pub struct GremlinShield {
     is_bashing: bool,
 }
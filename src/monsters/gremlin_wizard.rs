@@ -5,12 +5,13 @@ use crate::{
     queue::ActionQueue,
 };
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 enum Action {
     Charge,
     Attack,
 }
 
+#[derive(PartialEq, Eq, Hash, Clone)]
 pub struct GremlinWizard {
     current_charge: i32,
     action: Action,
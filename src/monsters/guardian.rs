@@ -10,7 +10,7 @@ use crate::{
     status::Status,
 };
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 enum Action {
     ChargingUp,
     FierceBash,
@@ -21,6 +21,7 @@ enum Action {
     TwinSlam,
 }
 
+#[derive(PartialEq, Eq, Hash, Clone)]
 pub struct Guardian {
     action: Action,
     mode_shift_amount: i32,
@@ -56,7 +57,7 @@ impl MonsterBehavior for Guardian {
         self.apply_mode_shift(this, queue);
     }
 
-    fn on_take_damage(&mut self, _this: CreatureRef, c: &mut Creature) {
+    fn on_take_damage(&mut self, _this: CreatureRef, c: &mut Creature, _rng: &mut Rand) {
         if let Some(amount) = c.get_status(Status::ModeShift)
             && amount <= 0
         {
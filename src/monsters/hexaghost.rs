@@ -10,7 +10,7 @@ use crate::{
     status::Status,
 };
 
-#[derive(PartialEq, Eq, Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 enum Action {
     Activate,
     Divider,
@@ -23,6 +23,7 @@ enum Action {
     Inferno,
 }
 
+#[derive(PartialEq, Eq, Hash, Clone)]
 pub struct Hexaghost {
     action: Action,
     divider_amount: i32,
@@ -164,7 +165,7 @@ mod tests {
     #[test]
     fn test_basic() {
         let assert_discard_pile = |g: &Game, class: CardClass| {
-            assert!(g.discard_pile.iter().all(|c| c.borrow().class == class));
+            assert!(g.discard_pile.iter().all(|c| g.cards[c].class == class));
         };
         let mut g = GameBuilder::default()
             .add_cards(CardClass::Strike, 10)
@@ -227,7 +228,7 @@ mod tests {
         g.step_test(EndTurnStep);
         let mut upgraded_count = 0;
         let mut not_upgraded_count = 0;
-        let mut count = |c: &CardRef| match c.borrow().class {
+        let mut count = |c: &CardRef| match g.cards[c].class {
             CardClass::Burn => not_upgraded_count += 1,
             CardClass::BurnPlus => upgraded_count += 1,
             _ => {}
@@ -9,7 +9,7 @@ use crate::{
     status::Status,
 };
 
-#[derive(PartialEq, Eq, Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 enum Action {
     None,
     Bellow,
@@ -17,9 +17,12 @@ enum Action {
     Thrash,
 }
 
+#[derive(PartialEq, Eq, Hash, Clone)]
 pub struct JawWorm {
     action: Action,
     history: MoveHistory<Action>,
+    // starts with its bellow already done
+    hard: bool,
 }
 
 impl JawWorm {
@@ -27,6 +30,13 @@ impl JawWorm {
         Self {
             action: Action::None,
             history: MoveHistory::new(),
+            hard: false,
+        }
+    }
+    pub fn new_hard() -> Self {
+        Self {
+            hard: true,
+            ..Self::new()
         }
     }
 }
@@ -39,6 +49,17 @@ impl MonsterBehavior for JawWorm {
         (42, 46)
     }
 
+    fn pre_combat(&self, queue: &mut ActionQueue, this: CreatureRef, _: &mut Rand) {
+        if self.hard {
+            queue.push_bot(GainStatusAction {
+                status: Status::Strength,
+                amount: 5,
+                target: this,
+            });
+            queue.push_bot(BlockAction::monster(this, 9));
+        }
+    }
+
     fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
         match self.action {
             Action::Chomp => {
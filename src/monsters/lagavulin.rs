@@ -8,7 +8,7 @@ use crate::{
     status::Status,
 };
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 enum Action {
     Start,
     EventStart,
@@ -20,6 +20,7 @@ enum Action {
     Stunned,
 }
 
+#[derive(PartialEq, Eq, Hash, Clone)]
 pub struct Lagavulin {
     action: Action,
     history: MoveHistory<Action>,
@@ -58,7 +59,12 @@ impl MonsterBehavior for Lagavulin {
         });
     }
 
-    fn on_take_damage(&mut self, _this: CreatureRef, _this_creature: &mut Creature) {
+    fn on_take_damage(
+        &mut self,
+        _this: CreatureRef,
+        _this_creature: &mut Creature,
+        _rng: &mut Rand,
+    ) {
         if matches!(
             self.action,
             Action::Sleep1 | Action::Sleep2 | Action::Sleep3
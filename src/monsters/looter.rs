@@ -10,7 +10,7 @@ use crate::{
     queue::ActionQueue,
 };
 
-#[derive(PartialEq, Eq, Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 enum Action {
     Mug,
     Lunge,
@@ -18,6 +18,7 @@ enum Action {
     Escape,
 }
 
+#[derive(PartialEq, Eq, Hash, Clone)]
 pub struct Looter {
     action: Action,
     turn: i32,
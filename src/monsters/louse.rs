@@ -9,13 +9,14 @@ use crate::{
     status::Status,
 };
 
-#[derive(PartialEq, Eq, Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 enum Action {
     Start,
     BuffOrDebuff,
     Attack,
 }
 
+#[derive(PartialEq, Eq, Hash, Clone)]
 pub struct Louse {
     action: Action,
     damage: i32,
@@ -0,0 +1,127 @@
+use rand::RngExt;
+
+use crate::{
+    actions::{damage::DamageAction, gain_status::GainStatusAction},
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    move_history::MoveHistory,
+    queue::ActionQueue,
+    status::Status,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    None,
+    Roar,
+    Slam,
+    Nom,
+    Drool,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct Maw {
+    action: Action,
+    history: MoveHistory<Action>,
+    num_turns: i32,
+}
+
+impl Maw {
+    pub fn new() -> Self {
+        Self {
+            action: Action::None,
+            history: MoveHistory::new(),
+            num_turns: 1,
+        }
+    }
+    fn nom_hits(&self) -> i32 {
+        (self.num_turns / 2).max(1)
+    }
+}
+
+impl MonsterBehavior for Maw {
+    fn name(&self) -> &'static str {
+        "maw"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (300, 300)
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        let player = CreatureRef::player();
+        match self.action {
+            Action::Roar => {
+                queue.push_bot(GainStatusAction {
+                    status: Status::Weak,
+                    amount: 5,
+                    target: player,
+                });
+                queue.push_bot(GainStatusAction {
+                    status: Status::Frail,
+                    amount: 5,
+                    target: player,
+                });
+            }
+            Action::Slam => queue.push_bot(DamageAction::from_monster(30, this)),
+            Action::Nom => {
+                for _ in 0..self.nom_hits() {
+                    queue.push_bot(DamageAction::from_monster(5, this));
+                }
+            }
+            Action::Drool => queue.push_bot(GainStatusAction {
+                status: Status::Strength,
+                amount: 5,
+                target: this,
+            }),
+            Action::None => unreachable!(),
+        }
+    }
+    fn roll_next_action(&mut self, r: &mut Rand, _info: &MonsterInfo) {
+        self.num_turns += 1;
+        let next = if self.action == Action::None {
+            Action::Roar
+        } else if r.random_range(0..100) < 50 && !self.history.last(Action::Nom) {
+            Action::Nom
+        } else if self.history.last(Action::Slam) || self.history.last(Action::Nom) {
+            Action::Drool
+        } else {
+            Action::Slam
+        };
+        self.history.add(next);
+        self.action = next;
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::None => unreachable!(),
+            Action::Roar => Intent::StrongDebuff,
+            Action::Slam => Intent::Attack(30, 1),
+            Action::Nom => Intent::Attack(5, self.nom_hits()),
+            Action::Drool => Intent::Buff,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_matches, combat::EndTurnStep, game::GameBuilder};
+
+    #[test]
+    fn test_nom_hits_grow() {
+        let mut g = GameBuilder::default()
+            .seed(0)
+            .build_combat_with_monster(Maw::new());
+        g.player.max_hp = 999;
+        assert_matches!(g.monsters[0].behavior.get_intent(), Intent::StrongDebuff);
+        let mut max_hits = 0;
+        for num_turns in 3..20 {
+            g.player.cur_hp = 999;
+            g.step_test(EndTurnStep);
+            if let Intent::Attack(5, hits) = g.monsters[0].behavior.get_intent() {
+                assert_eq!(hits, (num_turns / 2).max(1));
+                max_hits = hits;
+            }
+        }
+        assert!(max_hits > 1);
+    }
+}
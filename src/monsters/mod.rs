@@ -1,25 +1,47 @@
 use rand::RngExt;
 
 use crate::{
-    game::Game,
+    game::{CombatType, Game},
     monster::{Monster, MonsterBehavior},
     monsters::{
-        blue_slaver::BlueSlaver, cultist::Cultist, fungi_beast::FungiBeast,
-        gremlin_fat::GremlinFat, gremlin_mad::GremlinMad, gremlin_nob::GremlinNob,
-        gremlin_shield::GremlinShield, gremlin_sneaky::GremlinSneaky,
+        awakened_one::AwakenedOne, bear::Bear, blue_slaver::BlueSlaver,
+        book_of_stabbing::BookOfStabbing, bronze_automaton::BronzeAutomaton, byrd::Byrd,
+        centurion::Centurion, champ::Champ, chosen::Chosen, collector::Collector, cultist::Cultist,
+        darkling::Darkling, exploder::Exploder, fungi_beast::FungiBeast, giant_head::GiantHead,
+        gremlin_fat::GremlinFat, gremlin_leader::GremlinLeader, gremlin_mad::GremlinMad,
+        gremlin_nob::GremlinNob, gremlin_shield::GremlinShield, gremlin_sneaky::GremlinSneaky,
         gremlin_wizard::GremlinWizard, guardian::Guardian, hexaghost::Hexaghost, jawworm::JawWorm,
-        lagavulin::Lagavulin, looter::Looter, louse::Louse, red_slaver::RedSlaver, sentry::Sentry,
-        slime_acid_l::SlimeAcidL, slime_acid_m::SlimeAcidM, slime_acid_s::SlimeAcidS,
-        slime_boss::SlimeBoss, slime_spike_l::SlimeSpikeL, slime_spike_m::SlimeSpikeM,
-        slime_spike_s::SlimeSpikeS,
+        lagavulin::Lagavulin, looter::Looter, louse::Louse, maw::Maw, mugger::Mugger,
+        mystic::Mystic, nemesis::Nemesis, orb_walker::OrbWalker, pointy::Pointy,
+        red_slaver::RedSlaver, reptomancer::Reptomancer, repulsor::Repulsor, romeo::Romeo,
+        sentry::Sentry, shelled_parasite::ShelledParasite, slime_acid_l::SlimeAcidL,
+        slime_acid_m::SlimeAcidM, slime_acid_s::SlimeAcidS, slime_boss::SlimeBoss,
+        slime_spike_l::SlimeSpikeL, slime_spike_m::SlimeSpikeM, slime_spike_s::SlimeSpikeS,
+        snake_dagger::SnakeDagger, snake_plant::SnakePlant, snecko::Snecko,
+        spheric_guardian::SphericGuardian, spiker::Spiker, spire_growth::SpireGrowth,
+        taskmaster::Taskmaster, transient::Transient, writhing_mass::WrithingMass,
     },
     rng::remove_random,
 };
 
+pub mod awakened_one;
+pub mod bear;
 pub mod blue_slaver;
+pub mod book_of_stabbing;
+pub mod bronze_automaton;
+pub mod bronze_orb;
+pub mod byrd;
+pub mod centurion;
+pub mod champ;
+pub mod chosen;
+pub mod collector;
 pub mod cultist;
+pub mod darkling;
+pub mod exploder;
 pub mod fungi_beast;
+pub mod giant_head;
 pub mod gremlin_fat;
+pub mod gremlin_leader;
 pub mod gremlin_mad;
 pub mod gremlin_nob;
 pub mod gremlin_shield;
@@ -31,8 +53,18 @@ pub mod jawworm;
 pub mod lagavulin;
 pub mod looter;
 pub mod louse;
+pub mod maw;
+pub mod mugger;
+pub mod mystic;
+pub mod nemesis;
+pub mod orb_walker;
+pub mod pointy;
 pub mod red_slaver;
+pub mod reptomancer;
+pub mod repulsor;
+pub mod romeo;
 pub mod sentry;
+pub mod shelled_parasite;
 pub mod slime_acid_l;
 pub mod slime_acid_m;
 pub mod slime_acid_s;
@@ -40,10 +72,20 @@ pub mod slime_boss;
 pub mod slime_spike_l;
 pub mod slime_spike_m;
 pub mod slime_spike_s;
+pub mod snake_dagger;
+pub mod snake_plant;
+pub mod snecko;
+pub mod spheric_guardian;
+pub mod spiker;
+pub mod spire_growth;
+pub mod taskmaster;
 pub mod test;
+pub mod torch_head;
+pub mod transient;
+pub mod writhing_mass;
 
 #[allow(dead_code)]
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub enum Combat {
     // act 1 easy
     Cultist,
@@ -127,18 +169,57 @@ pub enum Combat {
     CorruptHeart,
 }
 
-struct Helper(Vec<Box<dyn MonsterBehavior>>);
+// the gremlins a gang or the gremlin leader picks from
+fn shape_pool() -> Vec<Box<dyn MonsterBehavior>> {
+    vec![
+        Box::new(Repulsor::new()),
+        Box::new(Repulsor::new()),
+        Box::new(Exploder::new()),
+        Box::new(Exploder::new()),
+        Box::new(Spiker::new()),
+        Box::new(Spiker::new()),
+    ]
+}
+
+pub fn gremlin_pool() -> Vec<Box<dyn MonsterBehavior>> {
+    vec![
+        Box::new(GremlinFat::new()),
+        Box::new(GremlinFat::new()),
+        Box::new(GremlinMad::new()),
+        Box::new(GremlinMad::new()),
+        Box::new(GremlinSneaky::new()),
+        Box::new(GremlinSneaky::new()),
+        Box::new(GremlinShield::new()),
+        Box::new(GremlinWizard::new()),
+    ]
+}
+
+// monsters in slot order, and whether they are there from the start
+struct Helper(Vec<(Box<dyn MonsterBehavior>, bool)>);
 
 impl Helper {
     fn add<M: MonsterBehavior + 'static>(&mut self, m: M) {
-        self.0.push(Box::new(m));
+        self.0.push((Box::new(m), true));
     }
     fn add_boxed(&mut self, m: Box<dyn MonsterBehavior>) {
-        self.0.push(m);
+        self.0.push((m, true));
+    }
+    fn add_empty_slot<M: MonsterBehavior + 'static>(&mut self, m: M) {
+        self.0.push((Box::new(m), false));
     }
 }
 
 impl Combat {
+    pub fn ty(&self) -> CombatType {
+        use Combat::*;
+        match self {
+            GremlinNob | Lagavulin | ThreeSentries | GremlinLeader | Slavers | BookOfStabbing
+            | GiantHead | Nemesis | Reptomancer | SpireShieldAndSpear => CombatType::Elite,
+            Guardian | Hexaghost | SlimeBoss | Automaton | Collector | Champ | AwakenedOne
+            | TimeEater | DonuDeca | CorruptHeart => CombatType::Boss,
+            _ => CombatType::Normal,
+        }
+    }
     pub fn monsters(&self, game: &mut Game) -> Vec<Monster> {
         let mut ret = Helper(vec![]);
         match self {
@@ -164,16 +245,7 @@ impl Combat {
             }
             Combat::BlueSlaver => ret.add(BlueSlaver::new()),
             Combat::GremlinGang => {
-                let mut pool = vec![
-                    Box::new(GremlinFat::new()) as Box<dyn MonsterBehavior>,
-                    Box::new(GremlinFat::new()),
-                    Box::new(GremlinMad::new()),
-                    Box::new(GremlinMad::new()),
-                    Box::new(GremlinSneaky::new()),
-                    Box::new(GremlinSneaky::new()),
-                    Box::new(GremlinShield::new()),
-                    Box::new(GremlinWizard::new()),
-                ];
+                let mut pool = gremlin_pool();
                 for _ in 0..4 {
                     ret.add_boxed(remove_random(&mut game.rng, &mut pool));
                 }
@@ -276,12 +348,125 @@ impl Combat {
             Combat::Guardian => ret.add(Guardian::new()),
             Combat::Hexaghost => ret.add(Hexaghost::new()),
             Combat::SlimeBoss => ret.add(SlimeBoss::new()),
+            Combat::SphericGuardian => ret.add(SphericGuardian::new()),
+            Combat::Chosen => ret.add(Chosen::new()),
+            Combat::ShellParasite => ret.add(ShelledParasite::new()),
+            Combat::ThreeByrds => {
+                for _ in 0..3 {
+                    ret.add(Byrd::new());
+                }
+            }
+            Combat::TwoThieves => {
+                ret.add(Looter::new());
+                ret.add(Mugger::new());
+            }
+            Combat::ChosenAndByrd => {
+                ret.add(Byrd::new());
+                ret.add(Chosen::new());
+            }
+            Combat::ChosenAndCultist => {
+                ret.add(Cultist::new());
+                ret.add(Chosen::new());
+            }
+            Combat::SentryAndSphericGuardian => {
+                ret.add(Sentry::new_debuff_first());
+                ret.add(SphericGuardian::new());
+            }
+            Combat::SnakePlant => ret.add(SnakePlant::new()),
+            Combat::Snecko => ret.add(Snecko::new()),
+            Combat::ThreeCultists => {
+                for _ in 0..3 {
+                    ret.add(Cultist::new());
+                }
+            }
+            Combat::ShelledParasiteAndFungiBeast => {
+                ret.add(ShelledParasite::new());
+                ret.add(FungiBeast::new());
+            }
+            Combat::CenturionAndMystic => {
+                ret.add(Centurion::new());
+                ret.add(Mystic::new());
+            }
+            Combat::MaskedBandits => {
+                ret.add(Pointy::new());
+                ret.add(Romeo::new());
+                ret.add(Bear::new());
+            }
+            Combat::GremlinLeader => {
+                let mut pool = gremlin_pool();
+                for _ in 0..2 {
+                    ret.add_boxed(remove_random(&mut game.rng, &mut pool));
+                }
+                ret.add(GremlinLeader::new());
+            }
+            Combat::Slavers => {
+                ret.add(BlueSlaver::new());
+                ret.add(Taskmaster::new());
+                ret.add(RedSlaver::new());
+            }
+            Combat::BookOfStabbing => ret.add(BookOfStabbing::new()),
+            Combat::Automaton => ret.add(BronzeAutomaton::new()),
+            Combat::Collector => ret.add(Collector::new()),
+            Combat::Champ => ret.add(Champ::new()),
+            Combat::OrbWalker => ret.add(OrbWalker::new()),
+            Combat::ThreeShapes => {
+                let mut pool = shape_pool();
+                for _ in 0..3 {
+                    ret.add_boxed(remove_random(&mut game.rng, &mut pool));
+                }
+            }
+            Combat::ThreeDarklings => {
+                for i in 0..3 {
+                    ret.add(Darkling::new(i % 2 == 0));
+                }
+            }
+            Combat::FourShapes => {
+                let mut pool = shape_pool();
+                for _ in 0..4 {
+                    ret.add_boxed(remove_random(&mut game.rng, &mut pool));
+                }
+            }
+            Combat::SphereAndTwoShapes => {
+                for _ in 0..2 {
+                    ret.add_boxed(remove_random(&mut game.rng, &mut shape_pool()));
+                }
+                ret.add(SphericGuardian::new());
+            }
+            Combat::SpireGrowth => ret.add(SpireGrowth::new()),
+            Combat::Transient => ret.add(Transient::new()),
+            Combat::Maw => ret.add(Maw::new()),
+            Combat::JawWormHorde => {
+                for _ in 0..3 {
+                    ret.add(JawWorm::new_hard());
+                }
+            }
+            Combat::WrithingMass => ret.add(WrithingMass::new()),
+            Combat::GiantHead => ret.add(GiantHead::new()),
+            Combat::Nemesis => ret.add(Nemesis::new()),
+            Combat::Reptomancer => {
+                ret.add_empty_slot(SnakeDagger::new());
+                ret.add(SnakeDagger::new());
+                ret.add(Reptomancer::new());
+                ret.add(SnakeDagger::new());
+                ret.add_empty_slot(SnakeDagger::new());
+            }
+            Combat::AwakenedOne => {
+                ret.add(Cultist::new());
+                ret.add(Cultist::new());
+                ret.add(AwakenedOne::new());
+            }
             _ => panic!(),
         }
         assert!(!ret.0.is_empty());
         ret.0
             .into_iter()
-            .map(|m| Monster::new_boxed(m, &mut game.rng))
+            .map(|(m, present)| {
+                if present {
+                    Monster::new_boxed(m, &mut game.rng)
+                } else {
+                    Monster::new_empty_slot(m)
+                }
+            })
             .collect()
     }
 }
@@ -0,0 +1,136 @@
+use rand::RngExt;
+
+use crate::{
+    actions::{
+        block::BlockAction, damage::DamageAction, escape_monster::EscapeMonsterAction,
+        rob::RobAction,
+    },
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    queue::ActionQueue,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    Swipe,
+    BigSwipe,
+    SmokeBomb,
+    Escape,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct Mugger {
+    action: Action,
+    turn: i32,
+}
+
+impl Mugger {
+    pub fn new() -> Self {
+        Self {
+            action: Action::Swipe,
+            turn: 0,
+        }
+    }
+}
+
+impl MonsterBehavior for Mugger {
+    fn name(&self) -> &'static str {
+        "mugger"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (50, 54)
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        match self.action {
+            Action::Swipe => {
+                queue.push_bot(DamageAction::from_monster(11, this));
+                queue.push_bot(RobAction {
+                    source: this,
+                    amount: 20,
+                });
+            }
+            Action::BigSwipe => {
+                queue.push_bot(DamageAction::from_monster(18, this));
+                queue.push_bot(RobAction {
+                    source: this,
+                    amount: 20,
+                });
+            }
+            Action::SmokeBomb => queue.push_bot(BlockAction::monster(this, 17)),
+            Action::Escape => queue.push_bot(EscapeMonsterAction(this)),
+        }
+        self.turn += 1;
+    }
+    fn roll_next_action(&mut self, rng: &mut Rand, _info: &MonsterInfo) {
+        let next = match self.action {
+            Action::Swipe => {
+                if self.turn == 2 {
+                    if rng.random() {
+                        Action::BigSwipe
+                    } else {
+                        Action::SmokeBomb
+                    }
+                } else {
+                    Action::Swipe
+                }
+            }
+            Action::BigSwipe => Action::SmokeBomb,
+            Action::SmokeBomb => Action::Escape,
+            Action::Escape => Action::Escape,
+        };
+        self.action = next;
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::Swipe => Intent::Attack(11, 1),
+            Action::BigSwipe => Intent::Attack(18, 1),
+            Action::SmokeBomb => Intent::Defend,
+            Action::Escape => Intent::Escape,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        actions::increase_max_hp::IncreaseMaxHPAction,
+        assert_matches,
+        combat::EndTurnStep,
+        game::{CombatType, GameBuilder},
+        status::Status,
+    };
+
+    #[test]
+    fn test_basic() {
+        let mut g = GameBuilder::default().build_combat_with_monster(Mugger::new());
+        g.gold = 25;
+        g.step_test(EndTurnStep);
+        assert_eq!(g.gold, 5);
+        assert_eq!(
+            g.monsters[0].creature.get_status(Status::StolenGold),
+            Some(20)
+        );
+    }
+
+    #[test]
+    fn test_escape() {
+        let mut g = GameBuilder::default().build_combat_with_monster(Mugger::new());
+        g.run_action(IncreaseMaxHPAction(999));
+        for _ in 0..2 {
+            assert_matches!(g.monsters[0].behavior.get_intent(), Intent::Attack(11, 1));
+            g.step_test(EndTurnStep);
+        }
+        if let Intent::Attack(18, 1) = g.monsters[0].behavior.get_intent() {
+            g.step_test(EndTurnStep);
+        }
+        assert_matches!(g.monsters[0].behavior.get_intent(), Intent::Defend);
+        g.step_test(EndTurnStep);
+        assert_eq!(g.monsters[0].creature.block, 17);
+        assert_matches!(g.monsters[0].behavior.get_intent(), Intent::Escape);
+        g.step_test(EndTurnStep);
+        assert_eq!(g.in_combat, CombatType::None);
+    }
+}
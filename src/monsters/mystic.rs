@@ -0,0 +1,145 @@
+use rand::RngExt;
+
+use crate::{
+    actions::{
+        damage::DamageAction, gain_status::GainStatusAction,
+        gain_status_all_monsters::GainStatusAllMonstersAction,
+        heal_all_monsters::HealAllMonstersAction,
+    },
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    move_history::MoveHistory,
+    queue::ActionQueue,
+    status::Status,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    None,
+    Attack,
+    Heal,
+    Buff,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct Mystic {
+    action: Action,
+    history: MoveHistory<Action>,
+}
+
+impl Mystic {
+    pub fn new() -> Self {
+        Self {
+            action: Action::None,
+            history: MoveHistory::new(),
+        }
+    }
+}
+
+impl MonsterBehavior for Mystic {
+    fn name(&self) -> &'static str {
+        "mystic"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (50, 58)
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        match self.action {
+            Action::Attack => {
+                queue.push_bot(DamageAction::from_monster(9, this));
+                queue.push_bot(GainStatusAction {
+                    status: Status::Frail,
+                    amount: 2,
+                    target: CreatureRef::player(),
+                });
+            }
+            Action::Heal => queue.push_bot(HealAllMonstersAction(20)),
+            Action::Buff => queue.push_bot(GainStatusAllMonstersAction {
+                status: Status::Strength,
+                amount: 4,
+            }),
+            Action::None => unreachable!(),
+        }
+    }
+    fn roll_next_action(&mut self, r: &mut Rand, info: &MonsterInfo) {
+        let num = r.random_range(0..100);
+        let next = if info.monsters_missing_hp > 15 && !self.history.last_two(Action::Heal) {
+            Action::Heal
+        } else if num >= 40 && !self.history.last(Action::Buff) {
+            Action::Buff
+        } else if !self.history.last_two(Action::Attack) {
+            Action::Attack
+        } else {
+            Action::Buff
+        };
+        self.history.add(next);
+        self.action = next;
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::None => unreachable!(),
+            Action::Attack => Intent::AttackDebuff(9, 1),
+            Action::Heal => Intent::Buff,
+            Action::Buff => Intent::Buff,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{combat::EndTurnStep, game::GameBuilder, monsters::centurion::Centurion};
+
+    #[test]
+    fn test_heal() {
+        let mut g =
+            GameBuilder::default().build_combat_with_monsters(Centurion::new(), Mystic::new());
+        let c = &mut g.monsters[0].creature;
+        c.cur_hp -= 30;
+        let c = &mut g.monsters[1].creature;
+        c.cur_hp -= 5;
+        g.monsters[1].behavior = Box::new(Mystic {
+            action: Action::Heal,
+            history: MoveHistory::new(),
+        });
+        let hp = g.monsters[0].creature.cur_hp;
+        g.player.cur_hp = g.player.max_hp;
+        g.step_test(EndTurnStep);
+        assert_eq!(g.monsters[0].creature.cur_hp, hp + 20);
+        assert_eq!(g.monsters[1].creature.cur_hp, g.monsters[1].creature.max_hp);
+    }
+
+    #[test]
+    fn test_heals_hurt_allies() {
+        let mut g =
+            GameBuilder::default().build_combat_with_monsters(Centurion::new(), Mystic::new());
+        let c = &mut g.monsters[0].creature;
+        c.cur_hp -= 16;
+        let info = g.calculate_monster_info();
+        let mut m = Mystic::new();
+        m.roll_next_action(&mut g.rng, &info);
+        assert!(m.action == Action::Heal);
+    }
+
+    #[test]
+    fn test_buff() {
+        let mut g = GameBuilder::default()
+            .seed(0)
+            .build_combat_with_monsters(Centurion::new(), Mystic::new());
+        let mut buffed = false;
+        for _ in 0..10 {
+            let buff = matches!(g.monsters[1].behavior.get_intent(), Intent::Buff);
+            g.player.cur_hp = g.player.max_hp;
+            g.step_test(EndTurnStep);
+            if buff {
+                buffed = true;
+                break;
+            }
+        }
+        assert!(buffed);
+        assert_eq!(g.monsters[0].creature.get_status(Status::Strength), Some(4));
+        assert_eq!(g.monsters[1].creature.get_status(Status::Strength), Some(4));
+    }
+}
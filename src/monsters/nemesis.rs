@@ -0,0 +1,164 @@
+use rand::RngExt;
+
+use crate::{
+    actions::{
+        create_card_in_discard::CreateCardInDiscardAction, damage::DamageAction,
+        gain_status::GainStatusAction,
+    },
+    cards::CardClass,
+    creature::Creature,
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    move_history::MoveHistory,
+    queue::ActionQueue,
+    status::Status,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    None,
+    TriAttack,
+    Scythe,
+    Burn,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct Nemesis {
+    action: Action,
+    history: MoveHistory<Action>,
+    scythe_cooldown: i32,
+    intangible: bool,
+}
+
+impl Nemesis {
+    pub fn new() -> Self {
+        Self {
+            action: Action::None,
+            history: MoveHistory::new(),
+            scythe_cooldown: 0,
+            intangible: false,
+        }
+    }
+    fn scythe(&mut self) -> Action {
+        self.scythe_cooldown = 2;
+        Action::Scythe
+    }
+}
+
+impl MonsterBehavior for Nemesis {
+    fn name(&self) -> &'static str {
+        "nemesis"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (200, 200)
+    }
+
+    fn on_turn_begin(&mut self, _this: CreatureRef, c: &mut Creature) {
+        self.intangible = c.has_status(Status::Intangible);
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        match self.action {
+            Action::TriAttack => {
+                for _ in 0..3 {
+                    queue.push_bot(DamageAction::from_monster(7, this));
+                }
+            }
+            Action::Scythe => queue.push_bot(DamageAction::from_monster(45, this)),
+            Action::Burn => {
+                for _ in 0..5 {
+                    queue.push_bot(CreateCardInDiscardAction(CardClass::Burn));
+                }
+            }
+            Action::None => unreachable!(),
+        }
+        // 2 so it survives this round's decay and lasts through the player's
+        // next turn, which makes it every other turn
+        if !self.intangible {
+            queue.push_bot(GainStatusAction {
+                status: Status::Intangible,
+                amount: 2,
+                target: this,
+            });
+        }
+    }
+    fn roll_next_action(&mut self, r: &mut Rand, _info: &MonsterInfo) {
+        self.scythe_cooldown -= 1;
+        let num = r.random_range(0..100);
+        let next = if self.action == Action::None {
+            if num < 50 {
+                Action::TriAttack
+            } else {
+                Action::Burn
+            }
+        } else if num < 30 {
+            if !self.history.last(Action::Scythe) && self.scythe_cooldown <= 0 {
+                self.scythe()
+            } else if r.random_bool(0.5) {
+                if !self.history.last_two(Action::TriAttack) {
+                    Action::TriAttack
+                } else {
+                    Action::Burn
+                }
+            } else if !self.history.last(Action::Burn) {
+                Action::Burn
+            } else {
+                Action::TriAttack
+            }
+        } else if num < 65 {
+            if !self.history.last_two(Action::TriAttack) {
+                Action::TriAttack
+            } else if r.random_bool(0.5) {
+                if self.scythe_cooldown > 0 {
+                    Action::Burn
+                } else {
+                    self.scythe()
+                }
+            } else {
+                Action::Burn
+            }
+        } else if !self.history.last(Action::Burn) {
+            Action::Burn
+        } else if r.random_bool(0.5) && self.scythe_cooldown <= 0 {
+            self.scythe()
+        } else {
+            Action::TriAttack
+        };
+        self.history.add(next);
+        self.action = next;
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::None => unreachable!(),
+            Action::TriAttack => Intent::Attack(7, 3),
+            Action::Scythe => Intent::Attack(45, 1),
+            Action::Burn => Intent::Debuff,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cards::CardClass, combat::EndTurnStep, game::GameBuilder};
+
+    #[test]
+    fn test_intangible_every_other_turn() {
+        let mut g = GameBuilder::default()
+            .add_cards(CardClass::Strike, 10)
+            .build_combat_with_monster(Nemesis::new());
+        g.player.max_hp = 999;
+        for turn in 0..6 {
+            let intangible = g.monsters[0].creature.has_status(Status::Intangible);
+            assert_eq!(intangible, turn % 2 == 1);
+            let hp = g.monsters[0].creature.cur_hp;
+            g.energy = 1;
+            g.play_card(CardClass::Strike, Some(CreatureRef::monster(0)));
+            let lost = if intangible { 1 } else { 6 };
+            assert_eq!(g.monsters[0].creature.cur_hp, hp - lost);
+            g.player.cur_hp = 999;
+            g.step_test(EndTurnStep);
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use rand::RngExt;
+
+use crate::{
+    actions::{
+        create_card_in_discard::CreateCardInDiscardAction, damage::DamageAction,
+        gain_status::GainStatusAction, shuffle_card_into_draw::ShuffleCardIntoDrawAction,
+    },
+    cards::CardClass,
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    move_history::MoveHistory,
+    queue::ActionQueue,
+    status::Status,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    None,
+    Laser,
+    Claw,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct OrbWalker {
+    action: Action,
+    history: MoveHistory<Action>,
+}
+
+impl OrbWalker {
+    pub fn new() -> Self {
+        Self {
+            action: Action::None,
+            history: MoveHistory::new(),
+        }
+    }
+}
+
+impl MonsterBehavior for OrbWalker {
+    fn name(&self) -> &'static str {
+        "orb walker"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (92, 102)
+    }
+
+    fn pre_combat(&self, queue: &mut ActionQueue, this: CreatureRef, _: &mut Rand) {
+        queue.push_bot(GainStatusAction {
+            status: Status::StrengthUp,
+            amount: 5,
+            target: this,
+        });
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        match self.action {
+            Action::Laser => {
+                queue.push_bot(DamageAction::from_monster(11, this));
+                queue.push_bot(CreateCardInDiscardAction(CardClass::Burn));
+                queue.push_bot(ShuffleCardIntoDrawAction {
+                    class: CardClass::Burn,
+                    is_free: false,
+                });
+            }
+            Action::Claw => queue.push_bot(DamageAction::from_monster(16, this)),
+            Action::None => unreachable!(),
+        }
+    }
+    fn roll_next_action(&mut self, r: &mut Rand, _info: &MonsterInfo) {
+        let next = if r.random_range(0..100) < 40 {
+            if !self.history.last_two(Action::Claw) {
+                Action::Claw
+            } else {
+                Action::Laser
+            }
+        } else if !self.history.last_two(Action::Laser) {
+            Action::Laser
+        } else {
+            Action::Claw
+        };
+        self.history.add(next);
+        self.action = next;
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::None => unreachable!(),
+            Action::Laser => Intent::AttackDebuff(11, 1),
+            Action::Claw => Intent::Attack(16, 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{combat::EndTurnStep, game::GameBuilder};
+
+    #[test]
+    fn test_strength_up() {
+        let mut g = GameBuilder::default().build_combat_with_monster(OrbWalker::new());
+        g.player.max_hp = 999;
+        g.player.cur_hp = g.player.max_hp;
+        assert_eq!(g.monsters[0].creature.get_status(Status::Strength), None);
+        g.step_test(EndTurnStep);
+        assert_eq!(g.monsters[0].creature.get_status(Status::Strength), Some(5));
+        g.step_test(EndTurnStep);
+        assert_eq!(
+            g.monsters[0].creature.get_status(Status::Strength),
+            Some(10)
+        );
+    }
+
+    #[test]
+    fn test_laser_burns() {
+        let mut g = GameBuilder::default().build_combat_with_monster(OrbWalker::new());
+        g.player.max_hp = 999;
+        while !matches!(
+            g.monsters[0].behavior.get_intent(),
+            Intent::AttackDebuff(..)
+        ) {
+            g.player.cur_hp = g.player.max_hp;
+            g.step_test(EndTurnStep);
+        }
+        let burns = |g: &crate::game::Game| {
+            let draw = g.draw_pile.get_all();
+            g.hand
+                .iter()
+                .chain(&g.discard_pile)
+                .chain(draw.iter().copied())
+                .filter(|c| g.cards[*c].class == CardClass::Burn)
+                .count()
+        };
+        let before = burns(&g);
+        g.step_test(EndTurnStep);
+        assert_eq!(burns(&g), before + 2);
+    }
+}
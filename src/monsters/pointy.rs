@@ -0,0 +1,35 @@
+use crate::{
+    actions::damage::DamageAction,
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    queue::ActionQueue,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct Pointy;
+
+impl Pointy {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl MonsterBehavior for Pointy {
+    fn name(&self) -> &'static str {
+        "pointy"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (34, 34)
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        for _ in 0..2 {
+            queue.push_bot(DamageAction::from_monster(6, this));
+        }
+    }
+    fn roll_next_action(&mut self, _: &mut Rand, _info: &MonsterInfo) {}
+
+    fn get_intent(&self) -> Intent {
+        Intent::Attack(6, 2)
+    }
+}
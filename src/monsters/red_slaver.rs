@@ -9,7 +9,7 @@ use crate::{
     status::Status,
 };
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 enum Action {
     None,
     Stab,
@@ -17,6 +17,7 @@ enum Action {
     Scrape,
 }
 
+#[derive(PartialEq, Eq, Hash, Clone)]
 pub struct RedSlaver {
     action: Action,
     history: MoveHistory<Action>,
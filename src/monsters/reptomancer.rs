@@ -0,0 +1,204 @@
+use rand::RngExt;
+
+use crate::{
+    actions::{
+        damage::DamageAction,
+        gain_status::GainStatusAction,
+        kill_all_monsters::KillAllMonstersAction,
+        summon_monster::{SummonMonsterInSlotAction, SummonMonsterType},
+    },
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    move_history::MoveHistory,
+    queue::ActionQueue,
+    status::Status,
+};
+
+const MAX_DAGGERS: usize = 4;
+const DAGGERS_PER_SPAWN: usize = 2;
+// where its daggers go relative to it, in the order they are filled
+const DAGGER_SLOTS: [isize; MAX_DAGGERS] = [1, -1, 2, -2];
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    None,
+    SnakeStrike,
+    SpawnDaggers,
+    BigBite,
+}
+
+// Starts between two snake daggers, with two more dagger slots further out on
+// either side. New daggers go into the free slot nearest to it, right before
+// left.
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct Reptomancer {
+    action: Action,
+    history: MoveHistory<Action>,
+}
+
+impl Reptomancer {
+    pub fn new() -> Self {
+        Self {
+            action: Action::None,
+            history: MoveHistory::new(),
+        }
+    }
+}
+
+impl MonsterBehavior for Reptomancer {
+    fn name(&self) -> &'static str {
+        "reptomancer"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (190, 200)
+    }
+
+    // the daggers go out with it
+    fn on_death(&mut self, _this: CreatureRef, queue: &mut ActionQueue) {
+        queue.push_top(KillAllMonstersAction);
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, info: &MonsterInfo) {
+        match self.action {
+            Action::SnakeStrike => {
+                for _ in 0..2 {
+                    queue.push_bot(DamageAction::from_monster(16, this));
+                }
+                queue.push_bot(GainStatusAction {
+                    status: Status::Weak,
+                    amount: 1,
+                    target: CreatureRef::player(),
+                });
+            }
+            Action::SpawnDaggers => {
+                let daggers = info.num_alive_monsters - 1;
+                let slots = DAGGER_SLOTS
+                    .iter()
+                    .map(|&o| this.monster_index().checked_add_signed(o).unwrap())
+                    .collect::<Vec<_>>();
+                for _ in daggers..MAX_DAGGERS.min(daggers + DAGGERS_PER_SPAWN) {
+                    queue.push_bot(SummonMonsterInSlotAction {
+                        ty: SummonMonsterType::SnakeDagger,
+                        slots: slots.clone(),
+                    });
+                }
+            }
+            Action::BigBite => queue.push_bot(DamageAction::from_monster(34, this)),
+            Action::None => unreachable!(),
+        }
+    }
+    fn roll_next_action(&mut self, r: &mut Rand, info: &MonsterInfo) {
+        let can_spawn = info.num_alive_monsters - 1 < MAX_DAGGERS;
+        let next = if self.action == Action::None {
+            Action::SpawnDaggers
+        } else {
+            let mut num = r.random_range(0..100);
+            loop {
+                if num < 33 {
+                    if !self.history.last(Action::SnakeStrike) {
+                        break Action::SnakeStrike;
+                    }
+                    num = r.random_range(33..100);
+                } else if num < 66 {
+                    if !self.history.last_two(Action::SpawnDaggers) && can_spawn {
+                        break Action::SpawnDaggers;
+                    }
+                    break Action::SnakeStrike;
+                } else {
+                    if !self.history.last(Action::BigBite) {
+                        break Action::BigBite;
+                    }
+                    num = r.random_range(0..66);
+                }
+            }
+        };
+        self.history.add(next);
+        self.action = next;
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::None => unreachable!(),
+            Action::SnakeStrike => Intent::AttackDebuff(16, 2),
+            Action::SpawnDaggers => Intent::Unknown,
+            Action::BigBite => Intent::Attack(34, 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        cards::CardClass,
+        combat::EndTurnStep,
+        game::{CombatType, Game, GameBuilder},
+        monsters::Combat,
+    };
+
+    fn alive(g: &Game) -> Vec<bool> {
+        g.monsters
+            .iter()
+            .map(|m| m.creature.is_actionable())
+            .collect()
+    }
+
+    #[test]
+    fn test_daggers_fill_slots_around_it() {
+        let mut g = GameBuilder::default().build_fixed_combat(Combat::Reptomancer);
+        assert_eq!(
+            g.monsters
+                .iter()
+                .map(|m| m.behavior.name())
+                .collect::<Vec<_>>(),
+            vec![
+                "snake dagger",
+                "snake dagger",
+                "reptomancer",
+                "snake dagger",
+                "snake dagger"
+            ]
+        );
+        assert_eq!(alive(&g), vec![false, true, true, true, false]);
+        g.player.max_hp = 999;
+        g.player.cur_hp = 999;
+        g.step_test(EndTurnStep);
+        assert_eq!(alive(&g), vec![true; 5]);
+        // the first daggers explode, the ones next to it
+        g.player.cur_hp = 999;
+        g.step_test(EndTurnStep);
+        assert_eq!(alive(&g), vec![true, false, true, false, true]);
+        let spawn = || SummonMonsterInSlotAction {
+            ty: SummonMonsterType::SnakeDagger,
+            slots: vec![3, 1, 4, 0],
+        };
+        g.run_action(spawn());
+        assert_eq!(alive(&g), vec![true, false, true, true, true]);
+        g.run_action(spawn());
+        assert_eq!(alive(&g), vec![true; 5]);
+        // with every slot taken, nothing more is summoned
+        g.run_action(spawn());
+        assert_eq!(g.monsters.len(), 5);
+    }
+
+    #[test]
+    fn test_spawn_refills_the_nearest_slots() {
+        let mut g = GameBuilder::default().build_fixed_combat(Combat::Reptomancer);
+        g.play_card(CardClass::DebugKill, Some(CreatureRef::monster(1)));
+        g.play_card(CardClass::DebugKill, Some(CreatureRef::monster(3)));
+        let mut m = Reptomancer::new();
+        m.action = Action::SpawnDaggers;
+        g.monsters[2].behavior = Box::new(m);
+        g.player.max_hp = 999;
+        g.player.cur_hp = 999;
+        g.step_test(EndTurnStep);
+        assert_eq!(alive(&g), vec![false, true, true, true, false]);
+    }
+
+    #[test]
+    fn test_daggers_die_with_it() {
+        let mut g = GameBuilder::default().build_fixed_combat(Combat::Reptomancer);
+        g.play_card(CardClass::DebugKill, Some(CreatureRef::monster(2)));
+        assert_eq!(g.in_combat, CombatType::None);
+    }
+}
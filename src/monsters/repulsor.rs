@@ -0,0 +1,73 @@
+use rand::RngExt;
+
+use crate::{
+    actions::{damage::DamageAction, shuffle_card_into_draw::ShuffleCardIntoDrawAction},
+    cards::CardClass,
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    move_history::MoveHistory,
+    queue::ActionQueue,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    None,
+    Repulse,
+    Attack,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct Repulsor {
+    action: Action,
+    history: MoveHistory<Action>,
+}
+
+impl Repulsor {
+    pub fn new() -> Self {
+        Self {
+            action: Action::None,
+            history: MoveHistory::new(),
+        }
+    }
+}
+
+impl MonsterBehavior for Repulsor {
+    fn name(&self) -> &'static str {
+        "repulsor"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (31, 38)
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        match self.action {
+            Action::Repulse => {
+                for _ in 0..2 {
+                    queue.push_bot(ShuffleCardIntoDrawAction {
+                        class: CardClass::Dazed,
+                        is_free: false,
+                    });
+                }
+            }
+            Action::Attack => queue.push_bot(DamageAction::from_monster(13, this)),
+            Action::None => unreachable!(),
+        }
+    }
+    fn roll_next_action(&mut self, r: &mut Rand, _info: &MonsterInfo) {
+        let next = if r.random_range(0..100) < 20 && !self.history.last(Action::Attack) {
+            Action::Attack
+        } else {
+            Action::Repulse
+        };
+        self.history.add(next);
+        self.action = next;
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::None => unreachable!(),
+            Action::Repulse => Intent::Debuff,
+            Action::Attack => Intent::Attack(13, 1),
+        }
+    }
+}
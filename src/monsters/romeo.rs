@@ -0,0 +1,100 @@
+use crate::{
+    actions::{damage::DamageAction, gain_status::GainStatusAction},
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    move_history::MoveHistory,
+    queue::ActionQueue,
+    status::Status,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    Mock,
+    AgonizingSlash,
+    CrossSlash,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct Romeo {
+    action: Action,
+    history: MoveHistory<Action>,
+}
+
+impl Romeo {
+    pub fn new() -> Self {
+        Self {
+            action: Action::Mock,
+            history: MoveHistory::new(),
+        }
+    }
+}
+
+impl MonsterBehavior for Romeo {
+    fn name(&self) -> &'static str {
+        "romeo"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (37, 41)
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        match self.action {
+            Action::Mock => {}
+            Action::AgonizingSlash => {
+                queue.push_bot(DamageAction::from_monster(12, this));
+                queue.push_bot(GainStatusAction {
+                    status: Status::Weak,
+                    amount: 3,
+                    target: CreatureRef::player(),
+                });
+            }
+            Action::CrossSlash => queue.push_bot(DamageAction::from_monster(17, this)),
+        }
+        self.history.add(self.action);
+    }
+    fn roll_next_action(&mut self, _: &mut Rand, _info: &MonsterInfo) {
+        if self.history.last(Action::Mock) || self.history.last_two(Action::CrossSlash) {
+            self.action = Action::AgonizingSlash;
+        } else if self.history.last(Action::AgonizingSlash) || self.history.last(Action::CrossSlash)
+        {
+            self.action = Action::CrossSlash;
+        }
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::Mock => Intent::Unknown,
+            Action::AgonizingSlash => Intent::AttackDebuff(12, 1),
+            Action::CrossSlash => Intent::Attack(17, 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        actions::increase_max_hp::IncreaseMaxHPAction, assert_matches, combat::EndTurnStep,
+        game::GameBuilder,
+    };
+
+    #[test]
+    fn test_moves() {
+        let mut g = GameBuilder::default().build_combat_with_monster(Romeo::new());
+        g.run_action(IncreaseMaxHPAction(999));
+        assert_matches!(g.monsters[0].behavior.get_intent(), Intent::Unknown);
+        g.step_test(EndTurnStep);
+        for _ in 0..2 {
+            assert_matches!(
+                g.monsters[0].behavior.get_intent(),
+                Intent::AttackDebuff(12, 1)
+            );
+            g.step_test(EndTurnStep);
+            assert_eq!(g.player.get_status(Status::Weak), Some(3));
+            for _ in 0..2 {
+                assert_matches!(g.monsters[0].behavior.get_intent(), Intent::Attack(17, 1));
+                g.step_test(EndTurnStep);
+            }
+        }
+    }
+}
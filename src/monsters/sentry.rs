@@ -6,12 +6,13 @@ use crate::{
     queue::ActionQueue,
 };
 
-#[derive(PartialEq, Eq, Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 enum Action {
     Attack,
     Debuff,
 }
 
+#[derive(PartialEq, Eq, Hash, Clone)]
 pub struct Sentry {
     action: Action,
 }
@@ -80,8 +81,8 @@ mod tests {
         assert_matches!(g.monsters[0].behavior.get_intent(), Intent::Debuff);
         g.step_test(EndTurnStep);
         assert_eq!(g.discard_pile.len(), 2);
-        assert_eq!(g.discard_pile[0].borrow().class, CardClass::Dazed);
-        assert_eq!(g.discard_pile[1].borrow().class, CardClass::Dazed);
+        assert_eq!(g.cards[g.discard_pile[0]].class, CardClass::Dazed);
+        assert_eq!(g.cards[g.discard_pile[1]].class, CardClass::Dazed);
 
         assert_matches!(g.monsters[0].behavior.get_intent(), Intent::Attack(10, 1));
         g.step_test(EndTurnStep);
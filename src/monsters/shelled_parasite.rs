@@ -0,0 +1,191 @@
+use rand::RngExt;
+
+use crate::{
+    actions::{
+        block::BlockAction, damage::DamageAction, gain_status::GainStatusAction,
+        vampire::MonsterVampireAction,
+    },
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    move_history::MoveHistory,
+    queue::ActionQueue,
+    status::Status,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    Start,
+    DoubleStrike,
+    Suck,
+    Fell,
+    Stunned,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct ShelledParasite {
+    action: Action,
+    history: MoveHistory<Action>,
+}
+
+impl ShelledParasite {
+    pub fn new() -> Self {
+        Self {
+            action: Action::Start,
+            history: MoveHistory::new(),
+        }
+    }
+}
+
+impl MonsterBehavior for ShelledParasite {
+    fn name(&self) -> &'static str {
+        "shelled parasite"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (70, 75)
+    }
+
+    fn pre_combat(&self, queue: &mut ActionQueue, this: CreatureRef, _: &mut Rand) {
+        queue.push_bot(GainStatusAction {
+            status: Status::PlatedArmor,
+            amount: 14,
+            target: this,
+        });
+        queue.push_bot(BlockAction::monster(this, 14));
+    }
+
+    // stunned once its armor breaks
+    fn on_lose_status(&mut self, _this: CreatureRef, status: Status) {
+        if status == Status::PlatedArmor {
+            self.action = Action::Stunned;
+            self.history.add(Action::Stunned);
+        }
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        match self.action {
+            Action::DoubleStrike => {
+                for _ in 0..2 {
+                    queue.push_bot(DamageAction::from_monster(7, this));
+                }
+            }
+            Action::Suck => {
+                queue.push_bot(DamageAction::from_monster(12, this));
+                queue.push_bot(MonsterVampireAction(this));
+            }
+            Action::Fell => {
+                queue.push_bot(DamageAction::from_monster(21, this));
+                queue.push_bot(GainStatusAction {
+                    status: Status::Frail,
+                    amount: 2,
+                    target: CreatureRef::player(),
+                });
+            }
+            Action::Stunned => {}
+            Action::Start => unreachable!(),
+        }
+    }
+    fn roll_next_action(&mut self, r: &mut Rand, _info: &MonsterInfo) {
+        let next = match self.action {
+            Action::Start | Action::Stunned => Action::Fell,
+            _ => {
+                let mut num = r.random_range(0..100);
+                if num < 20 && self.history.last(Action::Fell) {
+                    num = r.random_range(20..100);
+                }
+                if num < 20 {
+                    Action::Fell
+                } else if num < 60 {
+                    if self.history.last_two(Action::DoubleStrike) {
+                        Action::Suck
+                    } else {
+                        Action::DoubleStrike
+                    }
+                } else if self.history.last_two(Action::Suck) {
+                    Action::DoubleStrike
+                } else {
+                    Action::Suck
+                }
+            }
+        };
+        self.history.add(next);
+        self.action = next;
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::Start => unreachable!(),
+            Action::DoubleStrike => Intent::Attack(7, 2),
+            Action::Suck => Intent::AttackBuff(12, 1),
+            Action::Fell => Intent::AttackDebuff(21, 1),
+            Action::Stunned => Intent::Stun,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_matches, cards::CardClass, combat::EndTurnStep, game::GameBuilder, relic::RelicClass,
+    };
+
+    #[test]
+    fn test_basic() {
+        let mut g = GameBuilder::default().build_combat_with_monster(ShelledParasite::new());
+        assert_eq!(g.monsters[0].creature.block, 14);
+        assert_eq!(
+            g.monsters[0].creature.get_status(Status::PlatedArmor),
+            Some(14)
+        );
+        assert_matches!(
+            g.monsters[0].behavior.get_intent(),
+            Intent::AttackDebuff(21, 1)
+        );
+        g.player.cur_hp = 50;
+        g.step_test(EndTurnStep);
+        assert_eq!(g.player.cur_hp, 50 - 21);
+        assert_eq!(g.player.get_status(Status::Frail), Some(2));
+        assert_eq!(g.monsters[0].creature.block, 14);
+    }
+
+    #[test]
+    fn test_armor_break() {
+        let mut g = GameBuilder::default().build_combat_with_monster(ShelledParasite::new());
+        g.monsters[0].creature.block = 0;
+        g.monsters[0].creature.set_status(Status::PlatedArmor, 2);
+        g.play_card(CardClass::Strike, Some(CreatureRef::monster(0)));
+        assert_eq!(
+            g.monsters[0].creature.get_status(Status::PlatedArmor),
+            Some(1)
+        );
+        assert_matches!(
+            g.monsters[0].behavior.get_intent(),
+            Intent::AttackDebuff(21, 1)
+        );
+        g.play_card(CardClass::Strike, Some(CreatureRef::monster(0)));
+        assert!(!g.monsters[0].creature.has_status(Status::PlatedArmor));
+        assert_matches!(g.monsters[0].behavior.get_intent(), Intent::Stun);
+        let hp = g.player.cur_hp;
+        g.step_test(EndTurnStep);
+        assert_eq!(g.player.cur_hp, hp);
+        assert_matches!(
+            g.monsters[0].behavior.get_intent(),
+            Intent::AttackDebuff(21, 1)
+        );
+    }
+
+    #[test]
+    fn test_suck() {
+        let mut g = GameBuilder::default()
+            .add_relic(RelicClass::RunicPyramid)
+            .build_combat_with_monster(ShelledParasite::new());
+        g.monsters[0].behavior = Box::new(ShelledParasite {
+            action: Action::Suck,
+            history: MoveHistory::new(),
+        });
+        g.monsters[0].creature.cur_hp = 50;
+        g.player.block = 5;
+        g.step_test(EndTurnStep);
+        assert_eq!(g.monsters[0].creature.cur_hp, 57);
+    }
+}
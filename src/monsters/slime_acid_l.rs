@@ -16,7 +16,7 @@ use crate::{
     status::Status,
 };
 
-#[derive(PartialEq, Eq, Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 enum Action {
     Start,
     CorrisiveSpit,
@@ -25,6 +25,7 @@ enum Action {
     Split,
 }
 
+#[derive(PartialEq, Eq, Hash, Clone)]
 pub struct SlimeAcidL {
     action: Action,
     history: MoveHistory<Action>,
@@ -47,7 +48,7 @@ impl MonsterBehavior for SlimeAcidL {
         (68, 72)
     }
 
-    fn on_take_damage(&mut self, _: CreatureRef, this_creature: &mut Creature) {
+    fn on_take_damage(&mut self, _: CreatureRef, this_creature: &mut Creature, _rng: &mut Rand) {
         if this_creature.cur_hp <= this_creature.max_hp / 2 {
             self.action = Action::Split;
         }
@@ -145,13 +146,12 @@ mod tests {
         assert_matches!(g.monsters[0].behavior.get_intent(), Intent::Unknown);
         g.step_test(EndTurnStep);
         assert_eq!(player_hp, g.player.cur_hp);
-        assert_eq!(g.monsters.len(), 3);
-        assert!(!g.monsters[0].creature.is_actionable());
+        assert_eq!(g.monsters.len(), 2);
+        assert!(g.monsters[0].creature.is_actionable());
         assert!(g.monsters[1].creature.is_actionable());
-        assert!(g.monsters[2].creature.is_actionable());
+        assert_eq!(g.monsters[0].creature.cur_hp, 25);
         assert_eq!(g.monsters[1].creature.cur_hp, 25);
-        assert_eq!(g.monsters[2].creature.cur_hp, 25);
+        assert_eq!(g.monsters[0].creature.name, "acid slime M");
         assert_eq!(g.monsters[1].creature.name, "acid slime M");
-        assert_eq!(g.monsters[2].creature.name, "acid slime M");
     }
 }
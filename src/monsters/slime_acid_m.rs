@@ -13,7 +13,7 @@ use crate::{
     status::Status,
 };
 
-#[derive(PartialEq, Eq, Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 enum Action {
     None,
     Attack,
@@ -21,6 +21,7 @@ enum Action {
     Weaken,
 }
 
+#[derive(PartialEq, Eq, Hash, Clone)]
 pub struct SlimeAcidM {
     action: Action,
     history: MoveHistory<Action>,
@@ -127,7 +128,7 @@ mod tests {
         for _ in 0..50 {
             g.player.cur_hp = 50;
             g.step_test(EndTurnStep);
-            if !g.discard_pile.is_empty() && g.discard_pile[0].borrow().class == CardClass::Slimed {
+            if !g.discard_pile.is_empty() && g.cards[g.discard_pile[0]].class == CardClass::Slimed {
                 found_slimed = true;
                 break;
             }
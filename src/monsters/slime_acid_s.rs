@@ -6,13 +6,14 @@ use crate::{
     status::Status,
 };
 
-#[derive(PartialEq, Eq, Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 enum Action {
     None,
     Attack,
     Weaken,
 }
 
+#[derive(PartialEq, Eq, Hash, Clone)]
 pub struct SlimeAcidS {
     action: Action,
 }
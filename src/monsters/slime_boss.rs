@@ -11,7 +11,7 @@ use crate::{
     queue::ActionQueue,
 };
 
-#[derive(PartialEq, Eq, Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 enum Action {
     GoopSpray,
     Preparing,
@@ -19,6 +19,7 @@ enum Action {
     Split,
 }
 
+#[derive(PartialEq, Eq, Hash, Clone)]
 pub struct SlimeBoss {
     action: Action,
 }
@@ -39,7 +40,7 @@ impl MonsterBehavior for SlimeBoss {
         (150, 150)
     }
 
-    fn on_take_damage(&mut self, _: CreatureRef, this_creature: &mut Creature) {
+    fn on_take_damage(&mut self, _: CreatureRef, this_creature: &mut Creature, _rng: &mut Rand) {
         if this_creature.cur_hp <= this_creature.max_hp / 2 {
             self.action = Action::Split;
         }
@@ -96,12 +97,11 @@ mod tests {
         assert_matches!(g.monsters[0].behavior.get_intent(), Intent::Unknown);
         g.step_test(EndTurnStep);
         assert_eq!(player_hp, g.player.cur_hp);
-        assert_eq!(g.monsters.len(), 3);
-        assert!(!g.monsters[0].creature.is_actionable());
+        assert_eq!(g.monsters.len(), 2);
+        assert!(g.monsters[0].creature.is_actionable());
         assert!(g.monsters[1].creature.is_actionable());
-        assert!(g.monsters[2].creature.is_actionable());
+        assert_eq!(g.monsters[0].creature.cur_hp, 75);
         assert_eq!(g.monsters[1].creature.cur_hp, 75);
-        assert_eq!(g.monsters[2].creature.cur_hp, 75);
         assert_eq!(
             g.get_actionable_monsters_in_order()
                 .iter()
@@ -13,13 +13,14 @@ use crate::{
     status::Status,
 };
 
-#[derive(PartialEq, Eq, Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 enum Action {
     None,
     Slime,
     Frail,
 }
 
+#[derive(PartialEq, Eq, Hash, Clone)]
 pub struct SlimeSpikeM {
     action: Action,
     history: MoveHistory<Action>,
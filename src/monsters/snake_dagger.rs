@@ -0,0 +1,67 @@
+use crate::{
+    actions::{
+        create_card_in_discard::CreateCardInDiscardAction, damage::DamageAction,
+        suicide::SuicideAction,
+    },
+    cards::CardClass,
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    queue::ActionQueue,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    None,
+    Wound,
+    Explode,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct SnakeDagger {
+    action: Action,
+}
+
+impl SnakeDagger {
+    pub fn new() -> Self {
+        Self {
+            action: Action::None,
+        }
+    }
+}
+
+impl MonsterBehavior for SnakeDagger {
+    fn name(&self) -> &'static str {
+        "snake dagger"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (20, 25)
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        match self.action {
+            Action::Wound => {
+                queue.push_bot(DamageAction::from_monster(9, this));
+                queue.push_bot(CreateCardInDiscardAction(CardClass::Wound));
+            }
+            Action::Explode => {
+                queue.push_bot(DamageAction::from_monster(25, this));
+                queue.push_bot(SuicideAction(this));
+            }
+            Action::None => unreachable!(),
+        }
+    }
+    fn roll_next_action(&mut self, _: &mut Rand, _info: &MonsterInfo) {
+        self.action = match self.action {
+            Action::None => Action::Wound,
+            Action::Wound | Action::Explode => Action::Explode,
+        };
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::None => unreachable!(),
+            Action::Wound => Intent::AttackDebuff(9, 1),
+            Action::Explode => Intent::Attack(25, 1),
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use rand::RngExt;
+
+use crate::{
+    actions::{damage::DamageAction, gain_status::GainStatusAction},
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    move_history::MoveHistory,
+    queue::ActionQueue,
+    status::Status,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    None,
+    Chomp,
+    Spores,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct SnakePlant {
+    action: Action,
+    history: MoveHistory<Action>,
+}
+
+impl SnakePlant {
+    pub fn new() -> Self {
+        Self {
+            action: Action::None,
+            history: MoveHistory::new(),
+        }
+    }
+}
+
+impl MonsterBehavior for SnakePlant {
+    fn name(&self) -> &'static str {
+        "snake plant"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (78, 82)
+    }
+
+    fn pre_combat(&self, queue: &mut ActionQueue, this: CreatureRef, _: &mut Rand) {
+        queue.push_bot(GainStatusAction {
+            status: Status::Malleable,
+            amount: 3,
+            target: this,
+        });
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        match self.action {
+            Action::Chomp => {
+                for _ in 0..3 {
+                    queue.push_bot(DamageAction::from_monster(8, this));
+                }
+            }
+            Action::Spores => {
+                queue.push_bot(GainStatusAction {
+                    status: Status::Frail,
+                    amount: 2,
+                    target: CreatureRef::player(),
+                });
+                queue.push_bot(GainStatusAction {
+                    status: Status::Weak,
+                    amount: 2,
+                    target: CreatureRef::player(),
+                });
+            }
+            Action::None => unreachable!(),
+        }
+    }
+    fn roll_next_action(&mut self, r: &mut Rand, _info: &MonsterInfo) {
+        let next = if r.random_range(0..100) < 65 {
+            if self.history.last_two(Action::Chomp) {
+                Action::Spores
+            } else {
+                Action::Chomp
+            }
+        } else if self.history.last(Action::Spores) || self.history.last_last(Action::Spores) {
+            Action::Chomp
+        } else {
+            Action::Spores
+        };
+        self.history.add(next);
+        self.action = next;
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::None => unreachable!(),
+            Action::Chomp => Intent::Attack(8, 3),
+            Action::Spores => Intent::StrongDebuff,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cards::CardClass, combat::EndTurnStep, game::GameBuilder};
+
+    #[test]
+    fn test_malleable() {
+        let mut g = GameBuilder::default().build_combat_with_monster(SnakePlant::new());
+        let hp = g.monsters[0].creature.cur_hp;
+        g.play_card(CardClass::TwinStrike, Some(CreatureRef::monster(0)));
+        // the second hit is blocked by the first one's malleable block
+        assert_eq!(g.monsters[0].creature.cur_hp, hp - 5 - 2);
+        assert_eq!(g.monsters[0].creature.block, 4);
+        assert_eq!(
+            g.monsters[0].creature.get_status(Status::Malleable),
+            Some(5)
+        );
+
+        g.player.cur_hp = g.player.max_hp;
+        g.step_test(EndTurnStep);
+        assert_eq!(
+            g.monsters[0].creature.get_status(Status::Malleable),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn test_spores_not_twice_in_three_turns() {
+        let mut g = GameBuilder::default()
+            .seed(0)
+            .build_combat_with_monster(SnakePlant::new());
+        let mut last_spores = -10;
+        for turn in 0..30 {
+            if matches!(g.monsters[0].behavior.get_intent(), Intent::StrongDebuff) {
+                assert!(turn - last_spores > 2);
+                last_spores = turn;
+            }
+            g.player.cur_hp = g.player.max_hp;
+            g.step_test(EndTurnStep);
+        }
+    }
+}
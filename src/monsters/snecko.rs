@@ -0,0 +1,105 @@
+use rand::RngExt;
+
+use crate::{
+    actions::{damage::DamageAction, gain_status::GainStatusAction},
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    move_history::MoveHistory,
+    queue::ActionQueue,
+    status::Status,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    None,
+    Glare,
+    Bite,
+    TailWhip,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct Snecko {
+    action: Action,
+    history: MoveHistory<Action>,
+}
+
+impl Snecko {
+    pub fn new() -> Self {
+        Self {
+            action: Action::None,
+            history: MoveHistory::new(),
+        }
+    }
+}
+
+impl MonsterBehavior for Snecko {
+    fn name(&self) -> &'static str {
+        "snecko"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (120, 125)
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        match self.action {
+            Action::Glare => queue.push_bot(GainStatusAction {
+                status: Status::Confusion,
+                amount: 1,
+                target: CreatureRef::player(),
+            }),
+            Action::Bite => queue.push_bot(DamageAction::from_monster(18, this)),
+            Action::TailWhip => {
+                queue.push_bot(DamageAction::from_monster(10, this));
+                queue.push_bot(GainStatusAction {
+                    status: Status::Vulnerable,
+                    amount: 2,
+                    target: CreatureRef::player(),
+                });
+                queue.push_bot(GainStatusAction {
+                    status: Status::Weak,
+                    amount: 2,
+                    target: CreatureRef::player(),
+                });
+            }
+            Action::None => unreachable!(),
+        }
+    }
+    fn roll_next_action(&mut self, r: &mut Rand, _info: &MonsterInfo) {
+        let next = if self.action == Action::None {
+            Action::Glare
+        } else if r.random_range(0..100) < 40 || self.history.last_two(Action::Bite) {
+            Action::TailWhip
+        } else {
+            Action::Bite
+        };
+        self.history.add(next);
+        self.action = next;
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::None => unreachable!(),
+            Action::Glare => Intent::StrongDebuff,
+            Action::Bite => Intent::Attack(18, 1),
+            Action::TailWhip => Intent::AttackDebuff(10, 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_matches, combat::EndTurnStep, game::GameBuilder};
+
+    #[test]
+    fn test_glare() {
+        let mut g = GameBuilder::default().build_combat_with_monster(Snecko::new());
+        assert_matches!(g.monsters[0].behavior.get_intent(), Intent::StrongDebuff);
+        g.step_test(EndTurnStep);
+        assert!(g.player.has_status(Status::Confusion));
+        assert_matches!(
+            g.monsters[0].behavior.get_intent(),
+            Intent::Attack(18, 1) | Intent::AttackDebuff(10, 1)
+        );
+    }
+}
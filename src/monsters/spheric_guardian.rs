@@ -0,0 +1,142 @@
+use crate::{
+    actions::{block::BlockAction, damage::DamageAction, gain_status::GainStatusAction},
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    queue::ActionQueue,
+    status::Status,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    Start,
+    Activate,
+    AttackDebuff,
+    Slam,
+    Harden,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct SphericGuardian {
+    action: Action,
+}
+
+impl SphericGuardian {
+    pub fn new() -> Self {
+        Self {
+            action: Action::Start,
+        }
+    }
+}
+
+impl MonsterBehavior for SphericGuardian {
+    fn name(&self) -> &'static str {
+        "spheric guardian"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (20, 20)
+    }
+
+    fn pre_combat(&self, queue: &mut ActionQueue, this: CreatureRef, _: &mut Rand) {
+        queue.push_bot(GainStatusAction {
+            status: Status::Barricade,
+            amount: 1,
+            target: this,
+        });
+        queue.push_bot(GainStatusAction {
+            status: Status::Artifact,
+            amount: 3,
+            target: this,
+        });
+        queue.push_bot(BlockAction::monster(this, 40));
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        match self.action {
+            Action::Activate => queue.push_bot(BlockAction::monster(this, 35)),
+            Action::AttackDebuff => {
+                queue.push_bot(DamageAction::from_monster(11, this));
+                queue.push_bot(GainStatusAction {
+                    status: Status::Frail,
+                    amount: 5,
+                    target: CreatureRef::player(),
+                });
+            }
+            Action::Slam => {
+                for _ in 0..2 {
+                    queue.push_bot(DamageAction::from_monster(11, this));
+                }
+            }
+            Action::Harden => {
+                queue.push_bot(BlockAction::monster(this, 15));
+                queue.push_bot(DamageAction::from_monster(11, this));
+            }
+            Action::Start => unreachable!(),
+        }
+    }
+    fn roll_next_action(&mut self, _: &mut Rand, _info: &MonsterInfo) {
+        self.action = match self.action {
+            Action::Start => Action::Activate,
+            Action::Activate => Action::AttackDebuff,
+            Action::AttackDebuff | Action::Harden => Action::Slam,
+            Action::Slam => Action::Harden,
+        };
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::Start => unreachable!(),
+            Action::Activate => Intent::Defend,
+            Action::AttackDebuff => Intent::AttackDebuff(11, 1),
+            Action::Slam => Intent::Attack(11, 2),
+            Action::Harden => Intent::AttackDefend(11, 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_matches, cards::CardClass, combat::EndTurnStep, game::GameBuilder, potion::Potion,
+    };
+
+    #[test]
+    fn test_basic() {
+        let mut g = GameBuilder::default().build_combat_with_monster(SphericGuardian::new());
+        assert_eq!(g.monsters[0].creature.block, 40);
+        assert_eq!(g.monsters[0].creature.get_status(Status::Artifact), Some(3));
+        assert_matches!(g.monsters[0].behavior.get_intent(), Intent::Defend);
+        g.step_test(EndTurnStep);
+        // barricade keeps block between turns
+        assert_eq!(g.monsters[0].creature.block, 75);
+        assert_matches!(
+            g.monsters[0].behavior.get_intent(),
+            Intent::AttackDebuff(11, 1)
+        );
+        g.step_test(EndTurnStep);
+        assert_eq!(g.player.get_status(Status::Frail), Some(5));
+        for _ in 0..2 {
+            assert_matches!(g.monsters[0].behavior.get_intent(), Intent::Attack(11, 2));
+            g.player.cur_hp = 50;
+            g.step_test(EndTurnStep);
+            assert_eq!(g.player.cur_hp, 50 - 22);
+            assert_matches!(
+                g.monsters[0].behavior.get_intent(),
+                Intent::AttackDefend(11, 1)
+            );
+            g.step_test(EndTurnStep);
+        }
+        assert_eq!(g.monsters[0].creature.block, 105);
+    }
+
+    #[test]
+    fn test_artifact() {
+        let mut g = GameBuilder::default().build_combat_with_monster(SphericGuardian::new());
+        g.throw_potion(Potion::Weak, Some(CreatureRef::monster(0)));
+        g.play_card(CardClass::Bash, Some(CreatureRef::monster(0)));
+        assert_eq!(g.monsters[0].creature.get_status(Status::Artifact), Some(1));
+        assert!(!g.monsters[0].creature.has_status(Status::Weak));
+        assert!(!g.monsters[0].creature.has_status(Status::Vulnerable));
+        assert_eq!(g.monsters[0].creature.cur_hp, 20);
+    }
+}
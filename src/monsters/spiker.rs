@@ -0,0 +1,102 @@
+use rand::RngExt;
+
+use crate::{
+    actions::{damage::DamageAction, gain_status::GainStatusAction},
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    move_history::MoveHistory,
+    queue::ActionQueue,
+    status::Status,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    None,
+    Cut,
+    Spike,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct Spiker {
+    action: Action,
+    history: MoveHistory<Action>,
+    num_spikes: i32,
+}
+
+impl Spiker {
+    pub fn new() -> Self {
+        Self {
+            action: Action::None,
+            history: MoveHistory::new(),
+            num_spikes: 0,
+        }
+    }
+}
+
+impl MonsterBehavior for Spiker {
+    fn name(&self) -> &'static str {
+        "spiker"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (44, 60)
+    }
+
+    fn pre_combat(&self, queue: &mut ActionQueue, this: CreatureRef, _: &mut Rand) {
+        queue.push_bot(GainStatusAction {
+            status: Status::Thorns,
+            amount: 7,
+            target: this,
+        });
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        match self.action {
+            Action::Cut => queue.push_bot(DamageAction::from_monster(9, this)),
+            Action::Spike => queue.push_bot(GainStatusAction {
+                status: Status::Thorns,
+                amount: 2,
+                target: this,
+            }),
+            Action::None => unreachable!(),
+        }
+    }
+    fn roll_next_action(&mut self, r: &mut Rand, _info: &MonsterInfo) {
+        let num = r.random_range(0..100);
+        let next = if self.num_spikes > 5 || (num < 50 && !self.history.last(Action::Cut)) {
+            Action::Cut
+        } else {
+            self.num_spikes += 1;
+            Action::Spike
+        };
+        self.history.add(next);
+        self.action = next;
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::None => unreachable!(),
+            Action::Cut => Intent::Attack(9, 1),
+            Action::Spike => Intent::Buff,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{combat::EndTurnStep, game::GameBuilder};
+
+    #[test]
+    fn test_spikes_stop_after_six() {
+        let mut g = GameBuilder::default().build_combat_with_monster(Spiker::new());
+        g.player.max_hp = 999;
+        for _ in 0..20 {
+            g.player.cur_hp = g.player.max_hp;
+            g.step_test(EndTurnStep);
+        }
+        assert_eq!(
+            g.monsters[0].creature.get_status(Status::Thorns),
+            Some(7 + 6 * 2)
+        );
+    }
+}
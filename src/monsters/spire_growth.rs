@@ -0,0 +1,103 @@
+use rand::RngExt;
+
+use crate::{
+    actions::{damage::DamageAction, gain_status::GainStatusAction},
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    move_history::MoveHistory,
+    queue::ActionQueue,
+    status::Status,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    None,
+    QuickTackle,
+    Smash,
+    Constrict,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct SpireGrowth {
+    action: Action,
+    history: MoveHistory<Action>,
+}
+
+impl SpireGrowth {
+    pub fn new() -> Self {
+        Self {
+            action: Action::None,
+            history: MoveHistory::new(),
+        }
+    }
+}
+
+impl MonsterBehavior for SpireGrowth {
+    fn name(&self) -> &'static str {
+        "spire growth"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (190, 190)
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        match self.action {
+            Action::QuickTackle => queue.push_bot(DamageAction::from_monster(18, this)),
+            Action::Smash => queue.push_bot(DamageAction::from_monster(25, this)),
+            Action::Constrict => queue.push_bot(GainStatusAction {
+                status: Status::Constricted,
+                amount: 12,
+                target: CreatureRef::player(),
+            }),
+            Action::None => unreachable!(),
+        }
+    }
+    fn roll_next_action(&mut self, r: &mut Rand, info: &MonsterInfo) {
+        let num = r.random_range(0..100);
+        let can_constrict = !info.player_constricted && !self.history.last(Action::Constrict);
+        let next = if can_constrict {
+            Action::Constrict
+        } else if num < 50 && !self.history.last_two(Action::QuickTackle) {
+            Action::QuickTackle
+        } else if !self.history.last_two(Action::Smash) {
+            Action::Smash
+        } else {
+            Action::QuickTackle
+        };
+        self.history.add(next);
+        self.action = next;
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::None => unreachable!(),
+            Action::QuickTackle => Intent::Attack(18, 1),
+            Action::Smash => Intent::Attack(25, 1),
+            Action::Constrict => Intent::StrongDebuff,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_matches, combat::EndTurnStep, game::GameBuilder};
+
+    #[test]
+    fn test_constrict() {
+        let mut g = GameBuilder::default().build_combat_with_monster(SpireGrowth::new());
+        g.player.max_hp = 999;
+        g.player.cur_hp = 999;
+        assert_matches!(g.monsters[0].behavior.get_intent(), Intent::StrongDebuff);
+        g.step_test(EndTurnStep);
+        assert_eq!(g.player.get_status(Status::Constricted), Some(12));
+        assert_matches!(g.monsters[0].behavior.get_intent(), Intent::Attack(..));
+        g.player.cur_hp = 999;
+        g.player.block = 0;
+        let Intent::Attack(damage, _) = g.monsters[0].behavior.get_intent() else {
+            unreachable!()
+        };
+        g.step_test(EndTurnStep);
+        assert_eq!(g.player.cur_hp, 999 - 12 - damage);
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{
+    actions::{
+        create_card_in_discard::CreateCardInDiscardAction, damage::DamageAction,
+        gain_status::GainStatusAction,
+    },
+    cards::CardClass,
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    queue::ActionQueue,
+    status::Status,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct Taskmaster;
+
+impl Taskmaster {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl MonsterBehavior for Taskmaster {
+    fn name(&self) -> &'static str {
+        "taskmaster"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (57, 64)
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        queue.push_bot(DamageAction::from_monster(7, this));
+        for _ in 0..3 {
+            queue.push_bot(CreateCardInDiscardAction(CardClass::Wound));
+        }
+        queue.push_bot(GainStatusAction {
+            status: Status::Strength,
+            amount: 1,
+            target: this,
+        });
+    }
+    fn roll_next_action(&mut self, _: &mut Rand, _info: &MonsterInfo) {}
+
+    fn get_intent(&self) -> Intent {
+        Intent::AttackDebuff(7, 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{combat::EndTurnStep, game::GameBuilder};
+
+    #[test]
+    fn test_scouring_whip() {
+        let mut g = GameBuilder::default().build_combat_with_monster(Taskmaster::new());
+        g.player.cur_hp = 50;
+        g.step_test(EndTurnStep);
+        assert_eq!(g.player.cur_hp, 43);
+        assert_eq!(g.monsters[0].creature.get_status(Status::Strength), Some(1));
+        let draw = g.draw_pile.get_all();
+        let wounds = g
+            .hand
+            .iter()
+            .chain(&g.discard_pile)
+            .chain(draw.iter().copied())
+            .filter(|c| g.cards[**c].class == CardClass::Wound)
+            .count();
+        assert_eq!(wounds, 3);
+        g.step_test(EndTurnStep);
+        assert_eq!(g.player.cur_hp, 35);
+    }
+}
@@ -6,6 +6,7 @@ use crate::{
     status::Status,
 };
 
+#[derive(PartialEq, Eq, Hash, Clone)]
 pub struct NoopMonster {
     max_hp: i32,
 }
@@ -34,6 +35,7 @@ impl MonsterBehavior for NoopMonster {
     fn take_turn(&mut self, _: CreatureRef, _: &mut ActionQueue, _: &MonsterInfo) {}
 }
 
+#[derive(PartialEq, Eq, Hash, Clone)]
 pub struct AttackMonster {
     attack: i32,
     attack_count: i32,
@@ -83,6 +85,7 @@ impl MonsterBehavior for AttackMonster {
     }
 }
 
+#[derive(PartialEq, Eq, Hash, Clone)]
 pub struct IntentMonster {
     intent: Intent,
 }
@@ -108,6 +111,7 @@ impl MonsterBehavior for IntentMonster {
     fn take_turn(&mut self, _: CreatureRef, _: &mut ActionQueue, _: &MonsterInfo) {}
 }
 
+#[derive(PartialEq, Eq, Hash, Clone)]
 #[allow(dead_code)]
 pub struct ApplyStatusMonster {
     pub status: Status,
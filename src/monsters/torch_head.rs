@@ -0,0 +1,33 @@
+use crate::{
+    actions::damage::DamageAction,
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    queue::ActionQueue,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct TorchHead;
+
+impl TorchHead {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl MonsterBehavior for TorchHead {
+    fn name(&self) -> &'static str {
+        "torch head"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (40, 45)
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        queue.push_bot(DamageAction::from_monster(7, this));
+    }
+    fn roll_next_action(&mut self, _: &mut Rand, _info: &MonsterInfo) {}
+
+    fn get_intent(&self) -> Intent {
+        Intent::Attack(7, 1)
+    }
+}
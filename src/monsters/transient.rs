@@ -0,0 +1,99 @@
+use crate::{
+    actions::{damage::DamageAction, gain_status::GainStatusAction},
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    queue::ActionQueue,
+    status::Status,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct Transient {
+    num_turns: i32,
+}
+
+impl Transient {
+    pub fn new() -> Self {
+        Self { num_turns: 0 }
+    }
+    fn damage(&self) -> i32 {
+        30 + 10 * self.num_turns
+    }
+}
+
+impl MonsterBehavior for Transient {
+    fn name(&self) -> &'static str {
+        "transient"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (999, 999)
+    }
+
+    fn pre_combat(&self, queue: &mut ActionQueue, this: CreatureRef, _: &mut Rand) {
+        queue.push_bot(GainStatusAction {
+            status: Status::Fading,
+            amount: 6,
+            target: this,
+        });
+        queue.push_bot(GainStatusAction {
+            status: Status::Shifting,
+            amount: 1,
+            target: this,
+        });
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        queue.push_bot(DamageAction::from_monster(self.damage(), this));
+    }
+    fn roll_next_action(&mut self, _: &mut Rand, _info: &MonsterInfo) {
+        self.num_turns += 1;
+    }
+
+    fn get_intent(&self) -> Intent {
+        Intent::Attack(self.damage(), 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        cards::CardClass, combat::EndTurnStep, game::GameBuilder, monsters::test::NoopMonster,
+    };
+
+    #[test]
+    fn test_damage_grows_then_fades() {
+        let mut g =
+            GameBuilder::default().build_combat_with_monsters(Transient::new(), NoopMonster::new());
+        g.player.max_hp = 999;
+        for damage in [40, 50, 60, 70, 80] {
+            assert_eq!(
+                g.monsters[0].behavior.get_intent(),
+                Intent::Attack(damage, 1)
+            );
+            g.player.cur_hp = 999;
+            g.step_test(EndTurnStep);
+            assert_eq!(g.player.cur_hp, 999 - damage);
+        }
+        assert!(g.monsters[0].creature.is_actionable());
+        g.player.cur_hp = 999;
+        g.step_test(EndTurnStep);
+        assert!(!g.monsters[0].creature.is_actionable());
+        assert_eq!(g.player.cur_hp, 999);
+    }
+
+    #[test]
+    fn test_shifting() {
+        let mut g =
+            GameBuilder::default().build_combat_with_monsters(Transient::new(), NoopMonster::new());
+        g.player.max_hp = 999;
+        g.player.cur_hp = 999;
+        g.play_card(CardClass::Strike, Some(CreatureRef::monster(0)));
+        assert_eq!(
+            g.monsters[0].creature.get_status(Status::Strength),
+            Some(-6)
+        );
+        g.step_test(EndTurnStep);
+        assert_eq!(g.player.cur_hp, 999 - 34);
+        assert_eq!(g.monsters[0].creature.get_status(Status::Strength), None);
+    }
+}
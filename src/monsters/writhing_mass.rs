@@ -0,0 +1,238 @@
+use rand::RngExt;
+
+use crate::{
+    actions::{
+        add_card_class_to_master_deck::AddCardClassToMasterDeckAction, block::BlockAction,
+        damage::DamageAction, gain_status::GainStatusAction,
+    },
+    cards::CardClass,
+    creature::Creature,
+    game::{CreatureRef, Rand},
+    monster::{Intent, MonsterBehavior, MonsterInfo},
+    move_history::MoveHistory,
+    queue::ActionQueue,
+    status::Status,
+};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+enum Action {
+    None,
+    BigHit,
+    MultiHit,
+    AttackBlock,
+    AttackDebuff,
+    Implant,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct WrithingMass {
+    action: Action,
+    history: MoveHistory<Action>,
+    implanted: bool,
+}
+
+impl WrithingMass {
+    pub fn new() -> Self {
+        Self {
+            action: Action::None,
+            history: MoveHistory::new(),
+            implanted: false,
+        }
+    }
+
+    fn roll(&mut self, r: &mut Rand) {
+        let next = if self.action == Action::None {
+            let num = r.random_range(0..100);
+            if num < 33 {
+                Action::MultiHit
+            } else if num < 66 {
+                Action::AttackBlock
+            } else {
+                Action::AttackDebuff
+            }
+        } else {
+            let mut num = r.random_range(0..100);
+            loop {
+                if num < 10 {
+                    if !self.history.last(Action::BigHit) {
+                        break Action::BigHit;
+                    }
+                    num = r.random_range(10..100);
+                } else if num < 20 {
+                    if !self.implanted && !self.history.last(Action::Implant) {
+                        break Action::Implant;
+                    }
+                    if r.random_bool(0.1) {
+                        break Action::BigHit;
+                    }
+                    num = r.random_range(20..100);
+                } else if num < 40 {
+                    if !self.history.last(Action::AttackDebuff) {
+                        break Action::AttackDebuff;
+                    }
+                    num = if r.random_bool(0.4) {
+                        r.random_range(0..20)
+                    } else {
+                        r.random_range(40..100)
+                    };
+                } else if num < 70 {
+                    if !self.history.last(Action::MultiHit) {
+                        break Action::MultiHit;
+                    }
+                    if r.random_bool(0.3) {
+                        break Action::AttackBlock;
+                    }
+                    num = r.random_range(0..40);
+                } else {
+                    if !self.history.last(Action::AttackBlock) {
+                        break Action::AttackBlock;
+                    }
+                    num = r.random_range(0..70);
+                }
+            }
+        };
+        self.history.add(next);
+        self.action = next;
+    }
+}
+
+impl MonsterBehavior for WrithingMass {
+    fn name(&self) -> &'static str {
+        "writhing mass"
+    }
+    fn hp_range(&self) -> (i32, i32) {
+        (175, 175)
+    }
+
+    fn pre_combat(&self, queue: &mut ActionQueue, this: CreatureRef, _: &mut Rand) {
+        queue.push_bot(GainStatusAction {
+            status: Status::Reactive,
+            amount: 1,
+            target: this,
+        });
+        queue.push_bot(GainStatusAction {
+            status: Status::Malleable,
+            amount: 3,
+            target: this,
+        });
+    }
+
+    // reactive: every hit it survives picks a new move
+    fn on_take_damage(&mut self, _this: CreatureRef, this_creature: &mut Creature, rng: &mut Rand) {
+        if this_creature.has_status(Status::Reactive) && this_creature.cur_hp > 0 {
+            self.roll(rng);
+        }
+    }
+
+    fn take_turn(&mut self, this: CreatureRef, queue: &mut ActionQueue, _: &MonsterInfo) {
+        let player = CreatureRef::player();
+        match self.action {
+            Action::BigHit => queue.push_bot(DamageAction::from_monster(38, this)),
+            Action::MultiHit => {
+                for _ in 0..3 {
+                    queue.push_bot(DamageAction::from_monster(9, this));
+                }
+            }
+            Action::AttackBlock => {
+                queue.push_bot(DamageAction::from_monster(16, this));
+                queue.push_bot(BlockAction::monster(this, 16));
+            }
+            Action::AttackDebuff => {
+                queue.push_bot(DamageAction::from_monster(12, this));
+                queue.push_bot(GainStatusAction {
+                    status: Status::Weak,
+                    amount: 2,
+                    target: player,
+                });
+                queue.push_bot(GainStatusAction {
+                    status: Status::Vulnerable,
+                    amount: 2,
+                    target: player,
+                });
+            }
+            Action::Implant => {
+                self.implanted = true;
+                queue.push_bot(AddCardClassToMasterDeckAction(CardClass::Parasite));
+            }
+            Action::None => unreachable!(),
+        }
+    }
+    fn roll_next_action(&mut self, r: &mut Rand, _info: &MonsterInfo) {
+        self.roll(r);
+    }
+
+    fn get_intent(&self) -> Intent {
+        match self.action {
+            Action::None => unreachable!(),
+            Action::BigHit => Intent::Attack(38, 1),
+            Action::MultiHit => Intent::Attack(9, 3),
+            Action::AttackBlock => Intent::AttackDefend(16, 1),
+            Action::AttackDebuff => Intent::AttackDebuff(12, 1),
+            Action::Implant => Intent::StrongDebuff,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cards::CardClass, combat::EndTurnStep, game::GameBuilder};
+
+    #[test]
+    fn test_reactive_rerolls() {
+        let mut g = GameBuilder::default()
+            .seed(0)
+            .build_combat_with_monster(WrithingMass::new());
+        let mut changed = false;
+        for _ in 0..10 {
+            let before = g.monsters[0].behavior.get_intent();
+            g.monsters[0].creature.block = 0;
+            g.energy = 3;
+            g.play_card(CardClass::Strike, Some(CreatureRef::monster(0)));
+            changed |= before != g.monsters[0].behavior.get_intent();
+        }
+        assert!(changed);
+    }
+
+    #[test]
+    fn test_malleable_resets_every_round() {
+        let mut g = GameBuilder::default()
+            .seed(0)
+            .build_combat_with_monster(WrithingMass::new());
+        for _ in 0..3 {
+            // keep the second hit from being fully blocked, which wouldn't grow it
+            g.monsters[0].creature.block = 0;
+            g.player.remove_status(Status::Weak);
+            g.play_card(CardClass::TwinStrike, Some(CreatureRef::monster(0)));
+            assert_eq!(
+                g.monsters[0].creature.get_status(Status::Malleable),
+                Some(5)
+            );
+            g.player.cur_hp = g.player.max_hp;
+            g.step_test(EndTurnStep);
+            assert_eq!(
+                g.monsters[0].creature.get_status(Status::Malleable),
+                Some(3)
+            );
+        }
+    }
+
+    #[test]
+    fn test_implant() {
+        let mut g = GameBuilder::default()
+            .seed(0)
+            .build_combat_with_monster(WrithingMass::new());
+        g.player.max_hp = 999;
+        let mut m = WrithingMass::new();
+        m.action = Action::Implant;
+        g.monsters[0].behavior = Box::new(m);
+        g.step_test(EndTurnStep);
+        assert_eq!(
+            g.master_deck
+                .iter()
+                .filter(|c| g.cards[*c].class == CardClass::Parasite)
+                .count(),
+            1
+        );
+    }
+}
@@ -36,7 +36,7 @@ type PotionBehavior = fn(bool, Option<CreatureRef>, &mut Game);
 
 macro_rules! p {
     ($($name:ident => ($rarity:expr, $has_target:expr, $behavior:expr)),+,) => {
-        #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
         pub enum Potion {
             $(
                 $name,
@@ -487,7 +487,7 @@ mod tests {
         assert_eq!(g.hand.len(), 3);
 
         for c in &g.hand {
-            let c = c.borrow();
+            let c = &g.cards[c];
             assert_ne!(c.class, CardClass::Reaper);
             assert_eq!(c.class.ty(), CardType::Attack);
             assert_eq!(c.class.color(), CardColor::Red);
@@ -504,8 +504,8 @@ mod tests {
             g.add_cards_to_hand(CardClass::Strike, 10);
             g.throw_potion(Potion::Attack, None);
             g.step(0);
-            if g.discard_pile[0].borrow().class != CardClass::Whirlwind {
-                assert_eq!(g.discard_pile[0].borrow().get_temporary_cost(), None);
+            if g.cards[g.discard_pile[0]].class != CardClass::Whirlwind {
+                assert_eq!(g.cards[g.discard_pile[0]].get_temporary_cost(), None);
             }
         }
     }
@@ -522,7 +522,7 @@ mod tests {
         assert_eq!(g.hand.len(), 3);
 
         for c in &g.hand {
-            let c = c.borrow();
+            let c = &g.cards[c];
             assert_eq!(c.class.ty(), CardType::Skill);
             assert_eq!(c.class.color(), CardColor::Red);
             if let CardCost::Cost { temporary_cost, .. } = c.cost {
@@ -543,7 +543,7 @@ mod tests {
         assert_eq!(g.hand.len(), 3);
 
         for c in &g.hand {
-            let c = c.borrow();
+            let c = &g.cards[c];
             assert_eq!(c.class.ty(), CardType::Power);
             assert_eq!(c.class.color(), CardColor::Red);
             if let CardCost::Cost { temporary_cost, .. } = c.cost {
@@ -622,26 +622,26 @@ mod tests {
         g.step_test(GambleStep { hand_index: 0 });
         g.step_test(GambleEndStep);
         assert_eq!(g.hand.len(), 2);
-        assert_eq!(g.hand[0].borrow().class, CardClass::Defend);
-        assert_eq!(g.hand[1].borrow().class, CardClass::Inflame);
+        assert_eq!(g.cards[g.hand[0]].class, CardClass::Defend);
+        assert_eq!(g.cards[g.hand[1]].class, CardClass::Inflame);
         assert_eq!(g.discard_pile.len(), 1);
-        assert_eq!(g.discard_pile[0].borrow().class, CardClass::Strike);
+        assert_eq!(g.cards[g.discard_pile[0]].class, CardClass::Strike);
     }
 
     #[test]
     fn test_snecko_oil() {
         let mut g = GameBuilder::default().build_combat();
         let c = g.new_card(CardClass::Strike);
-        c.borrow_mut().set_cost(1, Some(2));
-        c.borrow_mut().set_free_to_play_once();
-        g.draw_pile.push_top(c);
+        g.update_card(c, |c| c.set_cost(1, Some(2)));
+        g.update_card(c, |c| c.set_free_to_play_once());
+        g.draw_pile.push_top(c, &g.cards);
         g.throw_potion(Potion::Snecko, None);
         assert_eq!(g.hand.len(), 1);
         if let CardCost::Cost {
             base_cost,
             temporary_cost,
             free_to_play_once,
-        } = g.hand[0].borrow().cost
+        } = g.cards[g.hand[0]].cost
         {
             assert!((0..=3).contains(&base_cost));
             assert!(temporary_cost.is_none());
@@ -661,7 +661,7 @@ mod tests {
             g.throw_potion(Potion::Snecko, None);
             assert_eq!(g.hand.len(), 10);
             for c in &g.hand {
-                match c.borrow().cost {
+                match g.cards[c].cost {
                     CardCost::Cost {
                         base_cost,
                         temporary_cost,
@@ -693,7 +693,7 @@ mod tests {
         g.add_card_to_discard_pile(CardClass::Strike);
         g.throw_potion(Potion::Memories, None);
         assert_eq!(g.hand.len(), 1);
-        assert_eq!(g.hand[0].borrow().get_temporary_cost(), Some(0));
+        assert_eq!(g.cards[g.hand[0]].get_temporary_cost(), Some(0));
         assert_eq!(g.discard_pile.len(), 0);
 
         g.clear_all_piles();
@@ -706,8 +706,8 @@ mod tests {
         });
         g.step_test(ChooseMemoriesEndStep);
         assert_eq!(g.hand.len(), 1);
-        assert_eq!(g.hand[0].borrow().class, CardClass::Defend);
-        assert_eq!(g.hand[0].borrow().get_temporary_cost(), Some(0));
+        assert_eq!(g.cards[g.hand[0]].class, CardClass::Defend);
+        assert_eq!(g.cards[g.hand[0]].get_temporary_cost(), Some(0));
         assert_eq!(g.discard_pile.len(), 1);
 
         g.clear_all_piles();
@@ -720,8 +720,8 @@ mod tests {
         });
         g.step_test(ChooseMemoriesEndStep);
         assert_eq!(g.hand.len(), 1);
-        assert_eq!(g.hand[0].borrow().class, CardClass::Defend);
-        assert_eq!(g.hand[0].borrow().get_temporary_cost(), Some(0));
+        assert_eq!(g.cards[g.hand[0]].class, CardClass::Defend);
+        assert_eq!(g.cards[g.hand[0]].get_temporary_cost(), Some(0));
         assert_eq!(g.discard_pile.len(), 1);
 
         g.clear_all_piles();
@@ -742,8 +742,8 @@ mod tests {
         }
         g.throw_potion(Potion::Memories, None);
         assert_eq!(g.hand.len(), 10);
-        assert_eq!(g.hand[9].borrow().class, CardClass::Defend);
-        assert_eq!(g.hand[9].borrow().get_temporary_cost(), Some(0));
+        assert_eq!(g.cards[g.hand[9]].class, CardClass::Defend);
+        assert_eq!(g.cards[g.hand[9]].get_temporary_cost(), Some(0));
         assert_eq!(g.discard_pile.len(), 1);
 
         g.clear_all_piles();
@@ -754,10 +754,10 @@ mod tests {
         }
         g.throw_potion(Potion::Memories, None);
         assert_eq!(g.hand.len(), 10);
-        assert_eq!(g.hand[8].borrow().class, CardClass::Defend);
-        assert_eq!(g.hand[8].borrow().get_temporary_cost(), Some(0));
-        assert_eq!(g.hand[9].borrow().class, CardClass::Defend);
-        assert_eq!(g.hand[9].borrow().get_temporary_cost(), Some(0));
+        assert_eq!(g.cards[g.hand[8]].class, CardClass::Defend);
+        assert_eq!(g.cards[g.hand[8]].get_temporary_cost(), Some(0));
+        assert_eq!(g.cards[g.hand[9]].class, CardClass::Defend);
+        assert_eq!(g.cards[g.hand[9]].get_temporary_cost(), Some(0));
         assert_eq!(g.discard_pile.len(), 0);
 
         g.clear_all_piles();
@@ -778,10 +778,10 @@ mod tests {
         });
         g.step_test(ChooseMemoriesEndStep);
         assert_eq!(g.hand.len(), 10);
-        assert_eq!(g.hand[8].borrow().class, CardClass::FlameBarrier);
-        assert_eq!(g.hand[8].borrow().get_temporary_cost(), Some(0));
-        assert_eq!(g.hand[9].borrow().class, CardClass::Inflame);
-        assert_eq!(g.hand[9].borrow().get_temporary_cost(), Some(0));
+        assert_eq!(g.cards[g.hand[8]].class, CardClass::FlameBarrier);
+        assert_eq!(g.cards[g.hand[8]].get_temporary_cost(), Some(0));
+        assert_eq!(g.cards[g.hand[9]].class, CardClass::Inflame);
+        assert_eq!(g.cards[g.hand[9]].get_temporary_cost(), Some(0));
         assert_eq!(g.discard_pile.len(), 1);
 
         g.clear_all_piles();
@@ -798,8 +798,8 @@ mod tests {
         });
         g.step_test(ChooseMemoriesEndStep);
         assert_eq!(g.hand.len(), 10);
-        assert_eq!(g.hand[9].borrow().class, CardClass::FlameBarrier);
-        assert_eq!(g.hand[9].borrow().get_temporary_cost(), Some(0));
+        assert_eq!(g.cards[g.hand[9]].class, CardClass::FlameBarrier);
+        assert_eq!(g.cards[g.hand[9]].get_temporary_cost(), Some(0));
         assert_eq!(g.discard_pile.len(), 2);
     }
 
@@ -1,35 +1,178 @@
-use crate::action::Action;
+use std::{
+    collections::VecDeque,
+    hash::{Hash, Hasher},
+};
+
+use crate::{action::Action, game::CreatureRef, relic::RelicClass, status::Status, trace::Tracer};
+
+pub type ActionId = u32;
+
+// What queued actions when no action was running, or on behalf of the running
+// action, for tracing.
+#[derive(Debug, Clone, Copy)]
+pub enum Cause {
+    Relic(RelicClass),
+    Status(CreatureRef, Status),
+    Monster(CreatureRef),
+}
+
+#[derive(Clone)]
+struct QueuedAction {
+    id: ActionId,
+    parent: Option<ActionId>,
+    action: Box<dyn Action>,
+}
 
 #[derive(Default)]
 pub struct ActionQueue {
-    queue: Vec<Box<dyn Action>>,
+    queue: VecDeque<QueuedAction>,
     debug: bool,
+    next_id: ActionId,
+    // the action popped last, until it finished running
+    running: Option<ActionId>,
+    // innermost last, with the id once something was queued under it
+    causes: Vec<(Cause, Option<ActionId>)>,
+    tracer: Option<Tracer>,
+}
+
+impl std::fmt::Debug for ActionQueue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("action queue: ")?;
+        f.debug_list()
+            .entries(self.queue.iter().map(|q| &q.action))
+            .finish()
+    }
+}
+
+// Compared by what's queued, ids and causes are only there for tracing.
+impl PartialEq for ActionQueue {
+    fn eq(&self, other: &Self) -> bool {
+        self.queue.len() == other.queue.len()
+            && self
+                .queue
+                .iter()
+                .zip(&other.queue)
+                .all(|(a, b)| a.action == b.action)
+    }
+}
+
+impl Eq for ActionQueue {}
+
+impl Hash for ActionQueue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.queue.len().hash(state);
+        for q in &self.queue {
+            q.action.hash(state);
+        }
+    }
+}
+
+// a copy doesn't trace, the tracer stays with the original
+impl Clone for ActionQueue {
+    fn clone(&self) -> Self {
+        Self {
+            queue: self.queue.clone(),
+            debug: self.debug,
+            next_id: self.next_id,
+            running: self.running,
+            causes: self.causes.clone(),
+            tracer: None,
+        }
+    }
 }
 
 impl ActionQueue {
     pub fn set_debug(&mut self) {
         self.debug = true;
     }
-    pub fn push_bot<A: Action + 'static>(&mut self, a: A) {
-        if self.debug {
-            println!("push_bot {a:?}");
+    pub fn set_tracer(&mut self, tracer: Tracer) {
+        self.tracer = Some(tracer);
+    }
+    pub fn take_tracer(&mut self) -> Option<Tracer> {
+        self.tracer.take()
+    }
+    pub fn tracer_mut(&mut self) -> Option<&mut Tracer> {
+        self.tracer.as_mut()
+    }
+    fn new_id(&mut self) -> ActionId {
+        self.next_id += 1;
+        self.next_id
+    }
+    // The id new actions get as their parent right now: the innermost cause,
+    // or the running action. Gives ids to causes that didn't queue anything
+    // yet.
+    pub fn current_parent(&mut self) -> Option<ActionId> {
+        let mut parent = self.running;
+        for i in 0..self.causes.len() {
+            let id = match self.causes[i].1 {
+                Some(id) => id,
+                None => {
+                    let id = self.new_id();
+                    self.causes[i].1 = Some(id);
+                    if let Some(t) = &mut self.tracer {
+                        t.record(id, parent, format!("{:?}", self.causes[i].0));
+                    }
+                    id
+                }
+            };
+            parent = Some(id);
         }
-        self.queue.insert(0, Box::new(a));
+        parent
     }
-    pub fn push_top<A: Action + 'static>(&mut self, a: A) {
+    // Runs `f` with everything it queues attributed to `cause`.
+    pub fn with_cause<R, F: FnOnce(&mut ActionQueue) -> R>(&mut self, cause: Cause, f: F) -> R {
+        self.causes.push((cause, None));
+        let r = f(self);
+        self.causes.pop();
+        r
+    }
+    fn push(&mut self, parent: Option<ActionId>, a: Box<dyn Action>, top: bool) {
+        let id = self.new_id();
         if self.debug {
-            println!("push_top {a:?}");
+            let side = if top { "top" } else { "bot" };
+            match parent {
+                Some(p) => println!("push_{side} #{id} (from #{p}) {a:?}"),
+                None => println!("push_{side} #{id} {a:?}"),
+            }
         }
-        self.queue.push(Box::new(a));
+        let q = QueuedAction {
+            id,
+            parent,
+            action: a,
+        };
+        if top {
+            self.queue.push_back(q);
+        } else {
+            self.queue.push_front(q);
+        }
+    }
+    pub fn push_bot<A: Action + 'static>(&mut self, a: A) {
+        let parent = self.current_parent();
+        self.push(parent, Box::new(a), false);
+    }
+    pub fn push_top<A: Action + 'static>(&mut self, a: A) {
+        let parent = self.current_parent();
+        self.push(parent, Box::new(a), true);
     }
+    // for actions queued on behalf of something that isn't running anymore
+    pub fn push_bot_with_parent<A: Action + 'static>(&mut self, parent: Option<ActionId>, a: A) {
+        self.push(parent, Box::new(a), false);
+    }
+    // The next action to run. Actions queued until `finish_running` are its
+    // children.
     pub fn pop(&mut self) -> Option<Box<dyn Action>> {
-        let a = self.queue.pop();
-        if let Some(a) = &a
-            && self.debug
-        {
-            println!("pop {a:?}");
+        let q = self.queue.pop_back()?;
+        if self.debug {
+            println!("pop #{} {:?}", q.id, q.action);
         }
-        a
+        if let Some(t) = &mut self.tracer {
+            t.record(q.id, q.parent, format!("{:?}", q.action));
+        }
+        self.running = Some(q.id);
+        Some(q.action)
+    }
+    pub fn finish_running(&mut self) {
+        self.running = None;
     }
     pub fn is_empty(&self) -> bool {
         self.queue.is_empty()
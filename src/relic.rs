@@ -43,12 +43,14 @@ use crate::{
         TransformChosenCardsGameState,
     },
     potion::random_potion_weighted,
-    queue::ActionQueue,
+    queue::{ActionQueue, Cause},
     rewards::{Rewards, RewardsGameState},
     state::{GameState, GameStateManager},
     status::Status,
 };
 
+use std::collections::VecDeque;
+
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum RelicRarity {
     Starter,
@@ -62,7 +64,7 @@ pub enum RelicRarity {
 
 macro_rules! r {
     ($($name:ident => $rarity:expr),+,) => {
-        #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
         pub enum RelicClass {
             $(
                 $name,
@@ -273,15 +275,15 @@ impl RelicClass {
             BottledFlame => game
                 .master_deck
                 .iter()
-                .any(|c| c.borrow().class.ty() == CardType::Attack),
+                .any(|c| game.cards[c].class.ty() == CardType::Attack),
             BottledLightning => game
                 .master_deck
                 .iter()
-                .any(|c| c.borrow().class.ty() == CardType::Skill),
+                .any(|c| game.cards[c].class.ty() == CardType::Skill),
             BottledTornado => game
                 .master_deck
                 .iter()
-                .any(|c| c.borrow().class.ty() == CardType::Power),
+                .any(|c| game.cards[c].class.ty() == CardType::Power),
             _ => true,
         }
     }
@@ -289,7 +291,8 @@ impl RelicClass {
 
 type RelicCallback = fn(&mut i32, &mut ActionQueue);
 type RelicEquipCallback = fn(&mut i32, &mut ActionQueue, &mut GameStateManager);
-type RelicCardCallback = fn(&mut i32, &mut ActionQueue, &mut Vec<PlayCardAction>, &PlayCardAction);
+type RelicCardCallback =
+    fn(&mut i32, &mut ActionQueue, &mut VecDeque<PlayCardAction>, &PlayCardAction);
 
 impl RelicClass {
     pub fn on_equip(&self) -> Option<RelicEquipCallback> {
@@ -498,7 +501,7 @@ fn inc_wrap(v: &mut i32, max: i32) -> bool {
 fn pocketwatch_card_played(
     v: &mut i32,
     _: &mut ActionQueue,
-    _: &mut Vec<PlayCardAction>,
+    _: &mut VecDeque<PlayCardAction>,
     _: &PlayCardAction,
 ) {
     *v += 1;
@@ -559,7 +562,7 @@ fn dollys_mirror(_: &mut i32, _: &mut ActionQueue, state: &mut GameStateManager)
     state.push_state(ChooseDuplicateCardInMasterGameState);
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct OrreryGameState;
 
 impl GameState for OrreryGameState {
@@ -576,7 +579,7 @@ fn orrery(_: &mut i32, _: &mut ActionQueue, state: &mut GameStateManager) {
     state.push_state(OrreryGameState);
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CauldronGameState;
 
 impl GameState for CauldronGameState {
@@ -595,7 +598,7 @@ fn cauldron(_: &mut i32, _: &mut ActionQueue, state: &mut GameStateManager) {
     state.push_state(CauldronGameState);
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CallingBellGameState;
 
 impl GameState for CallingBellGameState {
@@ -618,18 +621,18 @@ fn calling_bell(_: &mut i32, _: &mut ActionQueue, state: &mut GameStateManager)
     state.push_state(CallingBellGameState);
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct PandorasBoxGameState;
 
 impl GameState for PandorasBoxGameState {
     fn run(&self, game: &mut Game) {
         for i in (0..game.master_deck.len()).rev() {
             if matches!(
-                game.master_deck[i].borrow().class,
+                game.cards[game.master_deck[i]].class,
                 CardClass::Strike | CardClass::Defend
             ) {
                 let c = game.master_deck.remove(i);
-                game.chosen_cards.push(c);
+                game.chosen_cards.push(c, &game.cards);
             }
         }
         game.state
@@ -666,7 +669,7 @@ fn bottled_tornado(_: &mut i32, _: &mut ActionQueue, state: &mut GameStateManage
     });
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TinyHouseGameState;
 
 impl GameState for TinyHouseGameState {
@@ -700,23 +703,23 @@ fn necronomicon_unequip(_: &mut i32, queue: &mut ActionQueue) {
 
 fn necronomicon(
     v: &mut i32,
-    _: &mut ActionQueue,
-    card_queue: &mut Vec<PlayCardAction>,
+    queue: &mut ActionQueue,
+    card_queue: &mut VecDeque<PlayCardAction>,
     play: &PlayCardAction,
 ) {
-    if *v == 1 && play.card.borrow().class.ty() == CardType::Attack && play.cost >= 2 {
+    if *v == 1 && play.class.ty() == CardType::Attack && play.cost >= 2 {
         *v = 0;
-        card_queue.push(PlayCardAction::duplicated(play));
+        card_queue.push_back(PlayCardAction::duplicated(play, queue.current_parent()));
     }
 }
 
 fn pen_nib(
     v: &mut i32,
     queue: &mut ActionQueue,
-    _: &mut Vec<PlayCardAction>,
+    _: &mut VecDeque<PlayCardAction>,
     play: &PlayCardAction,
 ) {
-    if play.card.borrow().class.ty() == CardType::Attack {
+    if play.class.ty() == CardType::Attack {
         inc_wrap(v, 10);
         if *v == 9 {
             queue.push_bot(GainStatusAction {
@@ -731,10 +734,10 @@ fn pen_nib(
 fn kunai(
     v: &mut i32,
     queue: &mut ActionQueue,
-    _: &mut Vec<PlayCardAction>,
+    _: &mut VecDeque<PlayCardAction>,
     play_card: &PlayCardAction,
 ) {
-    if play_card.card.borrow().class.ty() == CardType::Attack && inc_wrap(v, 3) {
+    if play_card.class.ty() == CardType::Attack && inc_wrap(v, 3) {
         queue.push_bot(GainStatusAction {
             status: Status::Dexterity,
             amount: 1,
@@ -746,10 +749,10 @@ fn kunai(
 fn shruiken(
     v: &mut i32,
     queue: &mut ActionQueue,
-    _: &mut Vec<PlayCardAction>,
+    _: &mut VecDeque<PlayCardAction>,
     play_card: &PlayCardAction,
 ) {
-    if play_card.card.borrow().class.ty() == CardType::Attack && inc_wrap(v, 3) {
+    if play_card.class.ty() == CardType::Attack && inc_wrap(v, 3) {
         queue.push_bot(GainStatusAction {
             status: Status::Strength,
             amount: 1,
@@ -761,7 +764,7 @@ fn shruiken(
 fn ink_bottle(
     v: &mut i32,
     queue: &mut ActionQueue,
-    _: &mut Vec<PlayCardAction>,
+    _: &mut VecDeque<PlayCardAction>,
     _: &PlayCardAction,
 ) {
     if inc_wrap(v, 10) {
@@ -772,10 +775,10 @@ fn ink_bottle(
 fn mummified_hand(
     _: &mut i32,
     queue: &mut ActionQueue,
-    _: &mut Vec<PlayCardAction>,
+    _: &mut VecDeque<PlayCardAction>,
     play: &PlayCardAction,
 ) {
-    if play.card.borrow().class.ty() == CardType::Power {
+    if play.class.ty() == CardType::Power {
         queue.push_bot(DiscountRandomCardInHandAction());
     }
 }
@@ -783,10 +786,10 @@ fn mummified_hand(
 fn orange_pellets(
     v: &mut i32,
     queue: &mut ActionQueue,
-    _: &mut Vec<PlayCardAction>,
+    _: &mut VecDeque<PlayCardAction>,
     play: &PlayCardAction,
 ) {
-    let mask = match play.card.borrow().class.ty() {
+    let mask = match play.class.ty() {
         CardType::Attack => 0b001,
         CardType::Skill => 0b010,
         CardType::Power => 0b100,
@@ -795,17 +798,17 @@ fn orange_pellets(
     *v |= mask;
     if *v == 0b111 {
         *v = 0;
-        queue.push_bot(RemoveAllDebuffsAction());
+        queue.push_bot(RemoveAllDebuffsAction(CreatureRef::player()));
     }
 }
 
 fn ornamental_fan(
     v: &mut i32,
     queue: &mut ActionQueue,
-    _: &mut Vec<PlayCardAction>,
+    _: &mut VecDeque<PlayCardAction>,
     play: &PlayCardAction,
 ) {
-    if play.card.borrow().class.ty() == CardType::Attack && inc_wrap(v, 3) {
+    if play.class.ty() == CardType::Attack && inc_wrap(v, 3) {
         queue.push_bot(BlockAction::player_flat_amount(4));
     }
 }
@@ -813,10 +816,10 @@ fn ornamental_fan(
 fn nunchaku(
     v: &mut i32,
     queue: &mut ActionQueue,
-    _: &mut Vec<PlayCardAction>,
+    _: &mut VecDeque<PlayCardAction>,
     play: &PlayCardAction,
 ) {
-    if play.card.borrow().class.ty() == CardType::Attack && inc_wrap(v, 10) {
+    if play.class.ty() == CardType::Attack && inc_wrap(v, 10) {
         queue.push_bot(GainEnergyAction(1));
     }
 }
@@ -824,10 +827,10 @@ fn nunchaku(
 fn bird_faced_urn(
     _: &mut i32,
     queue: &mut ActionQueue,
-    _: &mut Vec<PlayCardAction>,
+    _: &mut VecDeque<PlayCardAction>,
     play: &PlayCardAction,
 ) {
-    if play.card.borrow().class.ty() == CardType::Power {
+    if play.class.ty() == CardType::Power {
         queue.push_bot(HealAction {
             target: CreatureRef::player(),
             amount: 2,
@@ -838,10 +841,10 @@ fn bird_faced_urn(
 fn letter_opener(
     v: &mut i32,
     queue: &mut ActionQueue,
-    _: &mut Vec<PlayCardAction>,
+    _: &mut VecDeque<PlayCardAction>,
     play: &PlayCardAction,
 ) {
-    if play.card.borrow().class.ty() == CardType::Skill && inc_wrap(v, 3) {
+    if play.class.ty() == CardType::Skill && inc_wrap(v, 3) {
         queue.push_bot(DamageAllMonstersAction::thorns(5));
     }
 }
@@ -1109,10 +1112,10 @@ fn brimstone(_: &mut i32, queue: &mut ActionQueue) {
 fn art_of_war_card_played(
     v: &mut i32,
     _: &mut ActionQueue,
-    _: &mut Vec<PlayCardAction>,
+    _: &mut VecDeque<PlayCardAction>,
     play: &PlayCardAction,
 ) {
-    if play.card.borrow().class.ty() == CardType::Attack {
+    if play.class.ty() == CardType::Attack {
         *v = 0;
     }
 }
@@ -1127,10 +1130,10 @@ fn art_of_war(v: &mut i32, queue: &mut ActionQueue) {
 fn blue_candle(
     _: &mut i32,
     queue: &mut ActionQueue,
-    _: &mut Vec<PlayCardAction>,
+    _: &mut VecDeque<PlayCardAction>,
     play: &PlayCardAction,
 ) {
-    if play.card.borrow().class.ty() == CardType::Curse {
+    if play.class.ty() == CardType::Curse {
         queue.push_bot(DamageAction::lose_hp(1, CreatureRef::player()));
     }
 }
@@ -1203,6 +1206,7 @@ fn whetstone(_: &mut i32, queue: &mut ActionQueue, _: &mut GameStateManager) {
     queue.push_bot(UpgradeTwoRandomInMasterAction(Some(CardType::Attack)));
 }
 
+#[derive(PartialEq, Eq, Hash, Clone)]
 pub struct Relic {
     class: RelicClass,
     value: i32,
@@ -1224,7 +1228,7 @@ macro_rules! trigger {
     ($name:ident) => {
         pub fn $name(&mut self, queue: &mut ActionQueue) {
             if let Some(f) = self.class.$name() {
-                f(&mut self.value, queue)
+                queue.with_cause(Cause::Relic(self.class), |queue| f(&mut self.value, queue))
             }
         }
     };
@@ -1233,7 +1237,9 @@ macro_rules! trigger_equip {
     ($name:ident) => {
         pub fn $name(&mut self, queue: &mut ActionQueue, state: &mut GameStateManager) {
             if let Some(f) = self.class.$name() {
-                f(&mut self.value, queue, state)
+                queue.with_cause(Cause::Relic(self.class), |queue| {
+                    f(&mut self.value, queue, state)
+                })
             }
         }
     };
@@ -1243,11 +1249,13 @@ macro_rules! trigger_card {
         pub fn $name(
             &mut self,
             queue: &mut ActionQueue,
-            card_queue: &mut Vec<PlayCardAction>,
+            card_queue: &mut VecDeque<PlayCardAction>,
             play: &PlayCardAction,
         ) {
             if let Some(f) = self.class.$name() {
-                f(&mut self.value, queue, card_queue, play)
+                queue.with_cause(Cause::Relic(self.class), |queue| {
+                    f(&mut self.value, queue, card_queue, play)
+                })
             }
         }
     };
@@ -1926,12 +1934,12 @@ mod tests {
             assert_eq!(
                 g.master_deck
                     .iter()
-                    .map(|c| c.borrow().upgrade_count)
+                    .map(|c| g.cards[c].upgrade_count)
                     .sum::<i32>(),
                 3
             );
             assert!(g.master_deck.iter().any(
-                |c| c.borrow().class == CardClass::Strike && c.borrow().upgrade_count == 0
+                |c| g.cards[c].class == CardClass::Strike && g.cards[c].upgrade_count == 0
             ));
         }
         {
@@ -1949,18 +1957,18 @@ mod tests {
                 assert_eq!(
                     g.master_deck
                         .iter()
-                        .map(|c| c.borrow().upgrade_count)
+                        .map(|c| g.cards[c].upgrade_count)
                         .sum::<i32>(),
                     2
                 );
                 assert!(g.master_deck.iter().any(
-                    |c| c.borrow().class == CardClass::Strike && c.borrow().upgrade_count == 0
+                    |c| g.cards[c].class == CardClass::Strike && g.cards[c].upgrade_count == 0
                 ));
                 for c in &g.master_deck {
-                    if c.borrow().upgrade_count != 0 {
+                    if g.cards[c].upgrade_count != 0 {
                         continue;
                     }
-                    match c.borrow().class {
+                    match g.cards[c].class {
                         CardClass::Defend => found_unupgraded_defend = true,
                         CardClass::ShrugItOff => found_unupgraded_shrug = true,
                         CardClass::BandageUp => found_unupgraded_bandage = true,
@@ -1986,7 +1994,7 @@ mod tests {
                 .add_card(CardClass::Bash)
                 .build_combat();
             g.add_relic(RelicClass::Whetstone);
-            assert_eq!(g.master_deck[0].borrow().upgrade_count, 1);
+            assert_eq!(g.cards[g.master_deck[0]].upgrade_count, 1);
         }
         {
             let mut g = GameBuilder::default()
@@ -1996,7 +2004,7 @@ mod tests {
                 .build_combat();
             g.add_relic(RelicClass::Whetstone);
             for c in &g.master_deck {
-                let c = c.borrow();
+                let c = &g.cards[c];
                 match c.class {
                     CardClass::Defend => assert_eq!(c.upgrade_count, 0),
                     CardClass::Strike => assert_eq!(c.upgrade_count, 1),
@@ -2383,7 +2391,7 @@ mod tests {
         g.play_card(CardClass::Apparition, None);
         g.play_card(CardClass::Bloodletting, None);
         g.add_card_to_hand(CardClass::BloodForBlood);
-        assert_eq!(g.hand[0].borrow().get_base_cost(), 4);
+        assert_eq!(g.cards[g.hand[0]].get_base_cost(), 4);
     }
 
     #[test]
@@ -2502,27 +2510,23 @@ mod tests {
         g.add_card_to_draw_pile(CardClass::Strike);
 
         g.step_test(EndTurnStep);
-        assert_eq!(g.hand[0].borrow().upgrade_count, 1);
+        assert_eq!(g.cards[g.hand[0]].upgrade_count, 1);
 
         g.step_test(EndTurnStep);
-        assert_eq!(g.hand[0].borrow().upgrade_count, 1);
+        assert_eq!(g.cards[g.hand[0]].upgrade_count, 1);
 
         g.add_card_to_draw_pile(CardClass::SearingBlow);
         g.step_test(EndTurnStep);
-        assert_eq!(g.get_hand_card(CardClass::Strike).borrow().upgrade_count, 1);
+        assert_eq!(g.cards[g.get_hand_card(CardClass::Strike)].upgrade_count, 1);
         assert_eq!(
-            g.get_hand_card(CardClass::SearingBlow)
-                .borrow()
-                .upgrade_count,
+            g.cards[g.get_hand_card(CardClass::SearingBlow)].upgrade_count,
             1
         );
 
         g.step_test(EndTurnStep);
-        assert_eq!(g.get_hand_card(CardClass::Strike).borrow().upgrade_count, 1);
+        assert_eq!(g.cards[g.get_hand_card(CardClass::Strike)].upgrade_count, 1);
         assert_eq!(
-            g.get_hand_card(CardClass::SearingBlow)
-                .borrow()
-                .upgrade_count,
+            g.cards[g.get_hand_card(CardClass::SearingBlow)].upgrade_count,
             2
         );
 
@@ -2535,13 +2539,13 @@ mod tests {
             g.add_card_to_draw_pile(CardClass::Strike);
             g.step_test(EndTurnStep);
             assert_eq!(
-                g.hand.iter().map(|c| c.borrow().upgrade_count).sum::<i32>(),
+                g.hand.iter().map(|c| g.cards[c].upgrade_count).sum::<i32>(),
                 2
             );
-            if g.get_hand_card(CardClass::Strike).borrow().upgrade_count == 1 {
+            if g.cards[g.get_hand_card(CardClass::Strike)].upgrade_count == 1 {
                 found_upgraded_strike = true;
             }
-            if g.get_hand_card(CardClass::Defend).borrow().upgrade_count == 1 {
+            if g.cards[g.get_hand_card(CardClass::Defend)].upgrade_count == 1 {
                 found_upgraded_defend = true;
             }
             if found_upgraded_defend && found_upgraded_strike {
@@ -2578,7 +2582,7 @@ mod tests {
                 .add_relic(RelicClass::WarpedTongs)
                 .add_cards(CardClass::Strike, 10)
                 .build_combat();
-            assert!(g.hand.iter().all(|c| c.borrow().upgrade_count == 0));
+            assert!(g.hand.iter().all(|c| g.cards[c].upgrade_count == 0));
         }
         {
             let g = GameBuilder::default()
@@ -2586,7 +2590,7 @@ mod tests {
                 .add_relic(RelicClass::GamblingChip)
                 .add_cards(CardClass::Strike, 10)
                 .build_combat();
-            assert!(g.hand.iter().any(|c| c.borrow().upgrade_count != 0));
+            assert!(g.hand.iter().any(|c| g.cards[c].upgrade_count != 0));
         }
     }
 
@@ -2655,7 +2659,7 @@ mod tests {
             .add_relic(RelicClass::Necronomicon)
             .build_combat();
         assert_eq!(g.master_deck.len(), 1);
-        assert_eq!(g.master_deck[0].borrow().class, CardClass::Necronomicurse);
+        assert_eq!(g.cards[g.master_deck[0]].class, CardClass::Necronomicurse);
         g.remove_relic(RelicClass::Necronomicon);
         assert_eq!(g.master_deck.len(), 0);
 
@@ -2679,9 +2683,9 @@ mod tests {
             .add_relic(RelicClass::Enchiridion)
             .add_card(CardClass::Strike)
             .build_combat();
-        assert_eq!(g.hand[0].borrow().class.ty(), CardType::Power);
-        assert_eq!(g.hand[0].borrow().class.color(), CardColor::Red);
-        assert_eq!(g.hand[0].borrow().get_temporary_cost(), Some(0));
+        assert_eq!(g.cards[g.hand[0]].class.ty(), CardType::Power);
+        assert_eq!(g.cards[g.hand[0]].class.color(), CardColor::Red);
+        assert_eq!(g.cards[g.hand[0]].get_temporary_cost(), Some(0));
     }
 
     #[test]
@@ -2788,29 +2792,29 @@ mod tests {
 
         g.add_card_to_hand(CardClass::Strike);
         g.play_card(CardClass::Berserk, None);
-        assert_eq!(g.hand[0].borrow().get_temporary_cost(), Some(0));
+        assert_eq!(g.cards[g.hand[0]].get_temporary_cost(), Some(0));
 
         for _ in 0..10 {
             g.clear_all_piles();
             g.add_card_to_hand(CardClass::Strike);
-            g.hand[0].borrow_mut().set_free_to_play_once();
+            g.update_card(g.hand[0], |c| c.set_free_to_play_once());
             g.add_card_to_hand(CardClass::Defend);
             g.play_card(CardClass::Berserk, None);
-            assert_eq!(g.hand[1].borrow().get_temporary_cost(), Some(0));
+            assert_eq!(g.cards[g.hand[1]].get_temporary_cost(), Some(0));
 
             g.clear_all_piles();
             g.add_card_to_hand(CardClass::Strike);
-            g.hand[0].borrow_mut().set_temporary_cost(0);
+            g.update_card(g.hand[0], |c| c.set_temporary_cost(0));
             g.add_card_to_hand(CardClass::Defend);
             g.play_card(CardClass::Berserk, None);
-            assert_eq!(g.hand[1].borrow().get_temporary_cost(), Some(0));
+            assert_eq!(g.cards[g.hand[1]].get_temporary_cost(), Some(0));
 
             g.clear_all_piles();
             g.add_card_to_hand(CardClass::Strike);
-            g.hand[0].borrow_mut().set_cost(0, None);
+            g.update_card(g.hand[0], |c| c.set_cost(0, None));
             g.add_card_to_hand(CardClass::Defend);
             g.play_card(CardClass::Berserk, None);
-            assert_eq!(g.hand[1].borrow().get_temporary_cost(), Some(0));
+            assert_eq!(g.cards[g.hand[1]].get_temporary_cost(), Some(0));
         }
 
         let mut discount_0 = false;
@@ -2821,13 +2825,13 @@ mod tests {
             g.add_card_to_hand(CardClass::Defend);
             g.play_card(CardClass::Berserk, None);
             assert_ne!(
-                g.hand[0].borrow().get_temporary_cost(),
-                g.hand[1].borrow().get_temporary_cost()
+                g.cards[g.hand[0]].get_temporary_cost(),
+                g.cards[g.hand[1]].get_temporary_cost()
             );
-            if g.hand[0].borrow().get_temporary_cost() == Some(0) {
+            if g.cards[g.hand[0]].get_temporary_cost() == Some(0) {
                 discount_0 = true;
             }
-            if g.hand[1].borrow().get_temporary_cost() == Some(0) {
+            if g.cards[g.hand[1]].get_temporary_cost() == Some(0) {
                 discount_1 = true;
             }
             if discount_0 && discount_1 {
@@ -3151,10 +3155,10 @@ mod tests {
                 .build_combat();
             assert_eq!(g.valid_steps().len(), 3);
             g.step(0);
-            if g.hand[0].borrow().class != CardClass::Transmutation {
-                assert_eq!(g.hand[0].borrow().get_temporary_cost(), None);
+            if g.cards[g.hand[0]].class != CardClass::Transmutation {
+                assert_eq!(g.cards[g.hand[0]].get_temporary_cost(), None);
             }
-            assert_eq!(g.hand[0].borrow().class.color(), CardColor::Colorless);
+            assert_eq!(g.cards[g.hand[0]].class.color(), CardColor::Colorless);
         }
     }
 
@@ -3170,7 +3174,7 @@ mod tests {
         assert_eq!(g.hand.len(), 10);
         g.hand
             .iter()
-            .for_each(|c| assert_eq!(c.borrow().class, CardClass::Strike));
+            .for_each(|c| assert_eq!(g.cards[c].class, CardClass::Strike));
     }
 
     #[test]
@@ -3234,7 +3238,7 @@ mod tests {
             assert_ne!(valid_steps[1], valid_steps[3]);
             assert_ne!(valid_steps[2], valid_steps[3]);
             g.step(1);
-            if g.hand[0].borrow().class == CardClass::TwinStrike {
+            if g.cards[g.hand[0]].class == CardClass::TwinStrike {
                 found_twin = true;
                 break;
             }
@@ -3267,16 +3271,16 @@ mod tests {
                 g.draw_pile
                     .get_all()
                     .into_iter()
-                    .filter(|c| c.borrow().class == CardClass::BloodForBlood)
+                    .filter(|c| g.cards[*c].class == CardClass::BloodForBlood)
                     .count(),
                 i
             );
             assert_eq!(
-                g.draw_pile.top(&mut g.rng).borrow().class,
+                g.cards[g.draw_pile.top(&mut g.rng)].class,
                 CardClass::Strike
             );
             for c in g.draw_pile.get_all() {
-                assert_eq!(c.borrow().upgrade_count, 0);
+                assert_eq!(g.cards[c].upgrade_count, 0);
             }
         }
     }
@@ -3288,13 +3292,13 @@ mod tests {
             .build_combat();
         g.play_card(CardClass::Offering, None);
         assert_eq!(g.hand.len(), 1);
-        assert_eq!(g.hand[0].borrow().class.color(), CardColor::Red);
+        assert_eq!(g.cards[g.hand[0]].class.color(), CardColor::Red);
 
         g.clear_all_piles();
         g.add_card_to_hand(CardClass::Apparition);
         g.step_test(EndTurnStep);
         assert_eq!(g.hand.len(), 1);
-        assert_eq!(g.hand[0].borrow().class.color(), CardColor::Red);
+        assert_eq!(g.cards[g.hand[0]].class.color(), CardColor::Red);
     }
 
     #[test]
@@ -3306,7 +3310,7 @@ mod tests {
         g.step_test(EndTurnStep);
         assert_eq!(g.hand.len(), 2);
         for c in &g.hand {
-            assert_eq!(c.borrow().class, CardClass::Wound);
+            assert_eq!(g.cards[c].class, CardClass::Wound);
         }
     }
 
@@ -3492,22 +3496,22 @@ mod tests {
             let mut g = GameBuilder::default()
                 .add_card(CardClass::RitualDagger)
                 .build();
-            g.master_deck[0].borrow_mut().base_increase = 5;
+            g.update_card(g.master_deck[0], |c| c.base_increase = 5);
             g.run_action(GainRelicAction(RelicClass::DollysMirror));
             g.step_test(DuplicateCardInMasterStep { master_index: 0 });
-            assert_ne!(g.master_deck[0].borrow().id, g.master_deck[1].borrow().id);
+            assert_ne!(g.cards[g.master_deck[0]].id, g.cards[g.master_deck[1]].id);
             assert_eq!(
-                g.master_deck[0].borrow().base_increase,
-                g.master_deck[1].borrow().base_increase
+                g.cards[g.master_deck[0]].base_increase,
+                g.cards[g.master_deck[1]].base_increase
             );
         }
         {
             let mut g = GameBuilder::default().add_card(CardClass::Strike).build();
-            g.master_deck[0].borrow_mut().is_bottled = true;
+            g.update_card(g.master_deck[0], |c| c.is_bottled = true);
             g.run_action(GainRelicAction(RelicClass::DollysMirror));
             g.step_test(DuplicateCardInMasterStep { master_index: 0 });
-            assert!(g.master_deck[0].borrow().is_bottled);
-            assert!(!g.master_deck[1].borrow().is_bottled);
+            assert!(g.cards[g.master_deck[0]].is_bottled);
+            assert!(!g.cards[g.master_deck[1]].is_bottled);
         }
     }
 
@@ -3530,12 +3534,12 @@ mod tests {
             .add_relic(RelicClass::MoltenEgg)
             .build();
         g.run_action(AddCardClassToMasterDeckAction(CardClass::Anger));
-        assert_eq!(g.master_deck[0].borrow().upgrade_count, 1);
+        assert_eq!(g.cards[g.master_deck[0]].upgrade_count, 1);
         g.run_action(AddCardClassToMasterDeckAction(CardClass::Defend));
-        assert_eq!(g.master_deck[1].borrow().upgrade_count, 0);
+        assert_eq!(g.cards[g.master_deck[1]].upgrade_count, 0);
         let c = g.new_card_upgraded(CardClass::SearingBlow);
         g.run_action(AddCardToMasterDeckAction(c));
-        assert_eq!(g.master_deck[2].borrow().upgrade_count, 1);
+        assert_eq!(g.cards[g.master_deck[2]].upgrade_count, 1);
     }
 
     #[test]
@@ -3544,9 +3548,9 @@ mod tests {
             .add_relic(RelicClass::ToxicEgg)
             .build();
         g.run_action(AddCardClassToMasterDeckAction(CardClass::Defend));
-        assert_eq!(g.master_deck[0].borrow().upgrade_count, 1);
+        assert_eq!(g.cards[g.master_deck[0]].upgrade_count, 1);
         g.run_action(AddCardClassToMasterDeckAction(CardClass::DemonForm));
-        assert_eq!(g.master_deck[1].borrow().upgrade_count, 0);
+        assert_eq!(g.cards[g.master_deck[1]].upgrade_count, 0);
     }
 
     #[test]
@@ -3555,9 +3559,9 @@ mod tests {
             .add_relic(RelicClass::FrozenEgg)
             .build();
         g.run_action(AddCardClassToMasterDeckAction(CardClass::Defend));
-        assert_eq!(g.master_deck[0].borrow().upgrade_count, 0);
+        assert_eq!(g.cards[g.master_deck[0]].upgrade_count, 0);
         g.run_action(AddCardClassToMasterDeckAction(CardClass::DemonForm));
-        assert_eq!(g.master_deck[1].borrow().upgrade_count, 1);
+        assert_eq!(g.cards[g.master_deck[1]].upgrade_count, 1);
     }
 
     #[test]
@@ -3624,7 +3628,7 @@ mod tests {
             num_cards_remaining: 1,
         });
         assert_eq!(g.master_deck.len(), 1);
-        assert_eq!(g.master_deck[0].borrow().class, CardClass::Defend);
+        assert_eq!(g.cards[g.master_deck[0]].class, CardClass::Defend);
     }
 
     #[test]
@@ -3724,9 +3728,9 @@ mod tests {
             .build();
         g.run_action(GainRelicAction(RelicClass::PandorasBox));
         assert_eq!(g.master_deck.len(), 5);
-        assert_eq!(g.master_deck[0].borrow().class, CardClass::Bash);
+        assert_eq!(g.cards[g.master_deck[0]].class, CardClass::Bash);
         for i in 1..5 {
-            assert_ne!(g.master_deck[i].borrow().class.rarity(), CardRarity::Basic);
+            assert_ne!(g.cards[g.master_deck[i]].class.rarity(), CardRarity::Basic);
         }
     }
 
@@ -3742,8 +3746,8 @@ mod tests {
                 .build();
             g.run_action(GainRelicAction(RelicClass::Astrolabe));
             for c in g.master_deck.iter().skip(1) {
-                assert_ne!(c.borrow().class.rarity(), CardRarity::Basic);
-                assert_eq!(c.borrow().upgrade_count, 1);
+                assert_ne!(g.cards[c].class.rarity(), CardRarity::Basic);
+                assert_eq!(g.cards[c].upgrade_count, 1);
             }
         }
         {
@@ -3763,8 +3767,8 @@ mod tests {
                 });
             }
             for c in g.master_deck.iter().skip(1) {
-                assert_ne!(c.borrow().class.rarity(), CardRarity::Basic);
-                assert_eq!(c.borrow().upgrade_count, 1);
+                assert_ne!(g.cards[c].class.rarity(), CardRarity::Basic);
+                assert_eq!(g.cards[c].upgrade_count, 1);
             }
         }
     }
@@ -3790,7 +3794,7 @@ mod tests {
         g.step_test(ChooseBottledCardStep { master_index: 0 });
 
         g.step_test(AscendStep::new(0, 0));
-        assert_eq!(g.hand[0].borrow().class, CardClass::Strike);
+        assert_eq!(g.cards[g.hand[0]].class, CardClass::Strike);
     }
 
     #[test]
@@ -3814,7 +3818,7 @@ mod tests {
         g.step_test(ChooseBottledCardStep { master_index: 2 });
 
         g.step_test(AscendStep::new(0, 0));
-        assert_eq!(g.hand[0].borrow().class, CardClass::Defend);
+        assert_eq!(g.cards[g.hand[0]].class, CardClass::Defend);
     }
 
     #[test]
@@ -3838,7 +3842,7 @@ mod tests {
         g.step_test(ChooseBottledCardStep { master_index: 5 });
 
         g.step_test(AscendStep::new(0, 0));
-        assert_eq!(g.hand[0].borrow().class, CardClass::DemonForm);
+        assert_eq!(g.cards[g.hand[0]].class, CardClass::DemonForm);
     }
 
     #[test]
@@ -3900,9 +3904,9 @@ mod tests {
             .build_combat();
         for _ in 0..10 {
             g.hand.clear();
-            let class = g.draw_pile.get_all().last().unwrap().borrow().class;
+            let class = g.cards[*g.draw_pile.get_all().last().unwrap()].class;
             g.run_action(DrawAction(1));
-            assert_eq!(g.hand[0].borrow().class, class);
+            assert_eq!(g.cards[g.hand[0]].class, class);
         }
     }
 
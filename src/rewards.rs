@@ -13,7 +13,7 @@ use crate::{
     step::Step,
 };
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub enum RewardType {
     Monster,
     Elite,
@@ -25,7 +25,7 @@ pub enum RewardType {
     },
 }
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone)]
 pub struct Rewards {
     pub gold: i32,
     pub stolen_gold: i32,
@@ -61,7 +61,7 @@ impl Rewards {
                     CardRarity::Rare => random_rare_red(&mut game.rng),
                     CardRarity::Basic | CardRarity::Special | CardRarity::Curse => panic!(),
                 };
-                if cards.iter().all(|c| c.borrow().class != class) {
+                if cards.iter().all(|c| game.cards[c].class != class) {
                     break;
                 }
             }
@@ -95,12 +95,11 @@ impl Rewards {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct RewardsGameState;
 
 impl GameState for RewardsGameState {
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
+    fn valid_steps(&self, game: &Game, steps: &mut Steps) -> bool {
         if !game.has_relic(RelicClass::Ectoplasm) {
             if game.rewards.gold != 0 {
                 steps.push(GoldRewardStep);
@@ -132,7 +131,7 @@ impl GameState for RewardsGameState {
             steps.push(SapphireKeyStep);
         }
         steps.push(RewardExitStep);
-        Some(steps)
+        true
     }
 }
 
@@ -210,13 +209,12 @@ impl Step for CardRewardStep {
     fn description(&self, game: &Game) -> String {
         let all = game.rewards.cards[self.pack_index]
             .iter()
-            .map(|c| format!("{:?}", c.borrow()))
+            .map(|c| format!("{:?}", &game.cards[c]))
             .collect::<Vec<_>>()
             .join(", ");
         format!(
             "gain {:?} out of {}",
-            game.rewards.cards[self.pack_index][self.card_index].borrow(),
-            all
+            &game.cards[game.rewards.cards[self.pack_index][self.card_index]], all
         )
     }
 }
@@ -238,7 +236,7 @@ impl Step for SingingBowlStep {
     fn description(&self, game: &Game) -> String {
         let all = game.rewards.cards[self.pack_index]
             .iter()
-            .map(|c| format!("{:?}", c.borrow()))
+            .map(|c| format!("{:?}", &game.cards[c]))
             .collect::<Vec<_>>()
             .join(", ");
         format!("skip for +2 max hp out of {}", all)
@@ -303,7 +301,7 @@ impl Step for RewardExitStep {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct BossRewardGameState;
 
 impl GameState for BossRewardGameState {
@@ -313,15 +311,14 @@ impl GameState for BossRewardGameState {
             game.boss_rewards.push(r);
         }
     }
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
+    fn valid_steps(&self, game: &Game, steps: &mut Steps) -> bool {
         for i in 0..game.boss_rewards.len() {
             steps.push(BossRewardChooseStep {
                 boss_reward_index: i,
             });
         }
         steps.push(BossRewardSkipStep);
-        Some(steps)
+        true
     }
 }
 
@@ -413,12 +410,12 @@ mod tests {
         assert_eq!(g.gold, gold);
         assert!(gold >= 10);
         assert!(gold <= 20);
-        let class = g.rewards.cards[0][0].borrow().class;
+        let class = g.cards[g.rewards.cards[0][0]].class;
         g.step_test(CardRewardStep {
             pack_index: 0,
             card_index: 0,
         });
-        assert_eq!(g.master_deck[0].borrow().class, class);
+        assert_eq!(g.cards[g.master_deck[0]].class, class);
         assert_eq!(
             g.valid_steps(),
             vec![Box::new(RewardExitStep) as Box<dyn Step>]
@@ -513,7 +510,7 @@ mod tests {
                     .cards
                     .iter()
                     .flatten()
-                    .all(|c| c.borrow().class.rarity() != CardRarity::Rare)
+                    .all(|c| g.cards[c].class.rarity() != CardRarity::Rare)
             );
             g.step_test(RewardExitStep);
             g.step_test(AscendStep::new(0, 1));
@@ -523,7 +520,7 @@ mod tests {
                 .cards
                 .iter()
                 .flatten()
-                .any(|c| c.borrow().class.rarity() == CardRarity::Rare);
+                .any(|c| g.cards[c].class.rarity() == CardRarity::Rare);
             if found_rare {
                 break;
             }
@@ -544,7 +541,7 @@ mod tests {
                 .cards
                 .iter()
                 .flatten()
-                .any(|c| c.borrow().class.rarity() == CardRarity::Rare);
+                .any(|c| g.cards[c].class.rarity() == CardRarity::Rare);
             if found_rare {
                 break;
             }
@@ -726,7 +723,7 @@ mod tests {
         assert_eq!(g.rewards.potions.len(), 0);
         assert_eq!(g.rewards.cards.len(), 0);
         assert_eq!(g.master_deck.len(), 1);
-        assert_eq!(g.master_deck[0].borrow().class, CardClass::CurseOfTheBell);
+        assert_eq!(g.cards[g.master_deck[0]].class, CardClass::CurseOfTheBell);
         assert_eq!(g.rewards.relics[0].rarity(), RelicRarity::Common);
         assert_eq!(g.rewards.relics[1].rarity(), RelicRarity::Uncommon);
         assert_eq!(g.rewards.relics[2].rarity(), RelicRarity::Rare);
@@ -755,7 +752,7 @@ mod tests {
         assert_eq!(
             g.master_deck
                 .iter()
-                .map(|c| c.borrow().upgrade_count)
+                .map(|c| g.cards[c].upgrade_count)
                 .sum::<i32>(),
             1
         );
@@ -25,7 +25,7 @@ fn break_maw_bank(game: &mut Game) {
     }
 }
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct Shop {
     pub cards: Vec<(CardClass, i32)>,
     pub relics: Vec<(RelicClass, i32)>,
@@ -234,12 +234,11 @@ impl Shop {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ShopGameState;
 
 impl GameState for ShopGameState {
-    fn valid_steps(&self, game: &Game) -> Option<Steps> {
-        let mut steps = Steps::default();
+    fn valid_steps(&self, game: &Game, steps: &mut Steps) -> bool {
         for (i, (_, price)) in game.shop.cards.iter().enumerate() {
             if game.gold >= *price {
                 steps.push(ShopBuyCardStep { shop_index: i });
@@ -261,7 +260,7 @@ impl GameState for ShopGameState {
             steps.push(ShopRemoveCardStep);
         }
         steps.push(ShopExitStep);
-        Some(steps)
+        true
     }
 }
 
@@ -590,7 +589,7 @@ mod tests {
         assert_eq!(g.shop.cards.len(), 6);
         assert_eq!(g.gold, 1000 - 100);
         assert_eq!(g.master_deck.len(), 1);
-        assert_eq!(g.master_deck[0].borrow().class, CardClass::WildStrike);
+        assert_eq!(g.cards[g.master_deck[0]].class, CardClass::WildStrike);
 
         g.gold = 1000;
         g.shop.potions[0] = (Potion::Fire, 50);
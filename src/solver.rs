@@ -0,0 +1,559 @@
+use std::{
+    any::Any,
+    time::{Duration, Instant},
+};
+
+use rand::{RngExt, SeedableRng};
+
+use crate::{
+    agent::{Evaluator, HeuristicEvaluator, legal_actions},
+    encoding::{self, END_TURN_ACTION},
+    game::{AscendStep, CombatType, GameStatus, Rand},
+    gym::Env,
+    map::{MAP_HEIGHT, RoomType},
+    transposition::{StateOptions, TranspositionTable},
+};
+
+#[derive(Debug, Clone)]
+pub struct Recommendation {
+    // structured action, see `encoding`
+    pub action: usize,
+    pub value: f32,
+    // what the solver expects to happen if this action is taken
+    pub outcome: String,
+}
+
+// Recommends actions for the current state of `env`, best first. `env`
+// itself is never stepped, only forks of it.
+pub trait Solver {
+    fn solve(&mut self, env: &Env) -> Vec<Recommendation>;
+}
+
+fn describe_state(env: &Env) -> String {
+    let game = env.game();
+    match game.status {
+        GameStatus::Defeat => return "defeat".to_string(),
+        GameStatus::Victory => return "victory".to_string(),
+        GameStatus::Aborted { limit, .. } => return format!("aborted ({limit:?})"),
+        GameStatus::Combat => {}
+    }
+    if game.in_combat == CombatType::None {
+        return format!(
+            "hp {}/{}, floor {}",
+            game.player.cur_hp, game.player.max_hp, game.floor
+        );
+    }
+    let monster_hp = game
+        .get_actionable_monsters_in_order()
+        .iter()
+        .map(|c| game.get_creature(*c).cur_hp)
+        .sum::<i32>();
+    format!(
+        "hp {}, block {}, monster hp {}",
+        game.player.cur_hp, game.player.block, monster_hp
+    )
+}
+
+// Searches every sequence of plays until the end of the current turn and
+// scores where they end up with the greedy heuristic. Draws during the turn
+// come from one sampled future. Orders of plays that end up in the same state
+// are only searched once.
+pub struct OneTurnSolver {
+    pub max_nodes: usize,
+    pub seed: u64,
+}
+
+impl OneTurnSolver {
+    // `env` is the state after taking `action`, each child is forked from it.
+    fn search(
+        &self,
+        env: &Env,
+        action: usize,
+        turn: i32,
+        nodes: &mut usize,
+        table: &mut TranspositionTable<(f32, String)>,
+    ) -> (f32, String) {
+        *nodes += 1;
+        if env.is_done()
+            || env.game().in_combat == CombatType::None
+            || env.game().turn != turn
+            || action == END_TURN_ACTION
+            || *nodes >= self.max_nodes
+        {
+            return (HeuristicEvaluator.evaluate(env), describe_state(env));
+        }
+        if let Some(r) = table.get(env.game()) {
+            return r.clone();
+        }
+        let mut best: Option<(f32, String)> = None;
+        for a in legal_actions(env) {
+            let r = self.search(&Self::child(env, a), a, turn, nodes, table);
+            if best.as_ref().is_none_or(|b| r.0 > b.0) {
+                best = Some(r);
+            }
+        }
+        let best = best.unwrap();
+        table.insert(env.game(), best.clone());
+        best
+    }
+
+    fn child(env: &Env, action: usize) -> Env {
+        let mut child = env.fork();
+        child.step(action);
+        child
+    }
+}
+
+impl Solver for OneTurnSolver {
+    fn solve(&mut self, env: &Env) -> Vec<Recommendation> {
+        let mut root = env.fork();
+        root.reseed_rng(self.seed);
+        let turn = root.game().turn;
+        let mut nodes = 0;
+        // the rng position is part of the state, the same plays in another
+        // order can draw different cards
+        let mut table = TranspositionTable::new(StateOptions { include_rng: true });
+        let mut recs = legal_actions(&root)
+            .into_iter()
+            .map(|a| {
+                let (value, outcome) =
+                    self.search(&Self::child(&root, a), a, turn, &mut nodes, &mut table);
+                Recommendation {
+                    action: a,
+                    value,
+                    outcome,
+                }
+            })
+            .collect::<Vec<_>>();
+        recs.sort_by(|a, b| b.value.total_cmp(&a.value));
+        recs
+    }
+}
+
+// The greedy heuristic mapped to roughly a win probability, for MCTS.
+pub struct SquashedHeuristic;
+
+impl Evaluator for SquashedHeuristic {
+    fn evaluate(&self, env: &Env) -> f32 {
+        1.0 / (1.0 + (-HeuristicEvaluator.evaluate(env) / 20.0).exp())
+    }
+}
+
+#[derive(Clone)]
+struct Node {
+    action: usize,
+    visits: u32,
+    total: f32,
+    children: Vec<Node>,
+}
+
+impl Node {
+    fn new(action: usize) -> Self {
+        Self {
+            action,
+            visits: 0,
+            total: 0.0,
+            children: vec![],
+        }
+    }
+
+    fn mean(&self) -> f32 {
+        self.total / self.visits as f32
+    }
+}
+
+// Open loop UCT: the tree is over action sequences, and every simulation
+// samples a different future by reseeding the game's rng. Leaves are scored
+// by `evaluator`, which should return values in [0, 1].
+pub struct MctsSolver<E: Evaluator> {
+    pub budget: Duration,
+    // stops after this many simulations even with time left, so that
+    // searches can be reproduced
+    pub max_simulations: Option<u32>,
+    pub evaluator: E,
+    pub exploration: f32,
+    pub seed: u64,
+}
+
+impl<E: Evaluator> MctsSolver<E> {
+    fn simulate(&self, node: &mut Node, env: &mut Env) -> f32 {
+        let v = if env.is_done() {
+            self.evaluator.evaluate(env)
+        } else {
+            let legal = legal_actions(env);
+            let untried = legal
+                .iter()
+                .copied()
+                .find(|&a| !node.children.iter().any(|c| c.action == a));
+            if let Some(a) = untried {
+                env.step(a);
+                let v = self.evaluator.evaluate(env);
+                let mut child = Node::new(a);
+                child.visits = 1;
+                child.total = v;
+                node.children.push(child);
+                v
+            } else {
+                let ln = (node.visits.max(1) as f32).ln();
+                let ucb = |c: &Node| c.mean() + self.exploration * (ln / c.visits as f32).sqrt();
+                let child = node
+                    .children
+                    .iter_mut()
+                    .filter(|c| legal.contains(&c.action))
+                    .max_by(|a, b| ucb(a).total_cmp(&ucb(b)))
+                    .unwrap();
+                env.step(child.action);
+                self.simulate(child, env)
+            }
+        };
+        node.visits += 1;
+        node.total += v;
+        v
+    }
+}
+
+impl<E: Evaluator> Solver for MctsSolver<E> {
+    fn solve(&mut self, env: &Env) -> Vec<Recommendation> {
+        if env.is_done() {
+            return vec![];
+        }
+        let start = Instant::now();
+        let mut rng = Rand::seed_from_u64(self.seed);
+        let mut root = Node::new(usize::MAX);
+        while root.visits == 0
+            || (start.elapsed() < self.budget
+                && self.max_simulations.is_none_or(|m| root.visits < m))
+        {
+            let mut fork = env.fork();
+            fork.reseed_rng(rng.random());
+            self.simulate(&mut root, &mut fork);
+        }
+        root.children.sort_by_key(|c| std::cmp::Reverse(c.visits));
+        root.children
+            .iter()
+            .map(|c| Recommendation {
+                action: c.action,
+                value: c.mean(),
+                outcome: format!(
+                    "expected value {:.3} over {} simulations",
+                    c.mean(),
+                    c.visits
+                ),
+            })
+            .collect()
+    }
+}
+
+// Perfect information Monte Carlo: runs `solver` on several copies of the
+// game, each with the draw pile fixed to a different sampled order, and
+// averages the value of each action.
+pub struct PimcSolver<S: Solver> {
+    pub samples: usize,
+    pub solver: S,
+    pub seed: u64,
+}
+
+impl<S: Solver> Solver for PimcSolver<S> {
+    fn solve(&mut self, env: &Env) -> Vec<Recommendation> {
+        let mut rng = Rand::seed_from_u64(self.seed);
+        let mut totals = Vec::<(usize, f32, usize)>::new();
+        for _ in 0..self.samples {
+            let mut fork = env.fork();
+            fork.reseed_rng(rng.random());
+            fork.determinize_draw_pile();
+            for r in self.solver.solve(&fork) {
+                match totals.iter_mut().find(|t| t.0 == r.action) {
+                    Some(t) => {
+                        t.1 += r.value;
+                        t.2 += 1;
+                    }
+                    None => totals.push((r.action, r.value, 1)),
+                }
+            }
+        }
+        let mut recs = totals
+            .into_iter()
+            .map(|(action, total, count)| Recommendation {
+                action,
+                value: total / count as f32,
+                outcome: format!(
+                    "average value {:.3} over {count} sampled draw orders",
+                    total / count as f32
+                ),
+            })
+            .collect::<Vec<_>>();
+        recs.sort_by(|a, b| b.value.total_cmp(&a.value));
+        recs
+    }
+}
+
+// Scores each reachable map node by the best path from it to the top of the
+// act, weighting rooms by the player's current hp and gold.
+pub struct RouteSolver;
+
+impl RouteSolver {
+    fn room_value(ty: RoomType, hp_fraction: f32, gold: i32) -> f32 {
+        match ty {
+            RoomType::Monster => 1.0,
+            RoomType::Elite => {
+                if hp_fraction > 0.6 {
+                    2.5
+                } else {
+                    -1.0
+                }
+            }
+            RoomType::Event => 1.0,
+            RoomType::Campfire => {
+                if hp_fraction < 0.5 {
+                    2.5
+                } else {
+                    1.0
+                }
+            }
+            RoomType::Shop => {
+                if gold >= 150 {
+                    2.0
+                } else {
+                    0.5
+                }
+            }
+            RoomType::Treasure => 2.0,
+            RoomType::Boss | RoomType::BossTreasure => 0.0,
+        }
+    }
+
+    // `memo` holds the best route from each node visited so far, paths
+    // share most of their nodes.
+    fn best_route(
+        env: &Env,
+        x: usize,
+        y: usize,
+        memo: &mut [Vec<Option<(f32, String)>>],
+    ) -> (f32, String) {
+        if let Some(r) = &memo[x][y] {
+            return r.clone();
+        }
+        let game = env.game();
+        let node = &game.map.nodes[x][y];
+        let hp_fraction = game.player.cur_hp as f32 / game.player.max_hp as f32;
+        let (value, ch) = match node.ty {
+            Some(ty) => (Self::room_value(ty, hp_fraction, game.gold), ty.char()),
+            None => (0.0, ' '),
+        };
+        let mut best = (0.0, String::new());
+        if y + 1 < MAP_HEIGHT {
+            for (i, &e) in node.edges.iter().enumerate() {
+                let r = Self::best_route(env, e, y + 1, memo);
+                if i == 0 || r.0 > best.0 {
+                    best = r;
+                }
+            }
+        }
+        let r = (value + best.0, format!("{ch}{}", best.1));
+        memo[x][y] = Some(r.clone());
+        r
+    }
+}
+
+impl Solver for RouteSolver {
+    fn solve(&mut self, env: &Env) -> Vec<Recommendation> {
+        let steps = env.game().valid_steps();
+        let actions = encoding::encode_steps(&steps);
+        let mut memo = env
+            .game()
+            .map
+            .nodes
+            .iter()
+            .map(|column| vec![None; column.len()])
+            .collect::<Vec<_>>();
+        let mut recs = vec![];
+        for (step, action) in steps.iter().zip(actions) {
+            let step = step.as_ref() as &dyn Any;
+            if let Some(ascend) = step.downcast_ref::<AscendStep>() {
+                let (x, y) = ascend.pos();
+                let (value, route) = Self::best_route(env, x, y, &mut memo);
+                recs.push(Recommendation {
+                    action,
+                    value,
+                    outcome: format!("route {route}, score {value:.1}"),
+                });
+            }
+        }
+        recs.sort_by(|a, b| b.value.total_cmp(&a.value));
+        recs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        cards::CardClass,
+        gym::{EnvConfig, Episode},
+        monsters::Combat,
+    };
+
+    fn combat_env(deck: Vec<(CardClass, bool)>) -> Env {
+        let mut env = Env::default();
+        env.reset(
+            0,
+            EnvConfig {
+                episode: Episode::Combat {
+                    combat: Combat::Cultist,
+                    deck,
+                    relics: vec![],
+                },
+                rewards: Default::default(),
+                limits: Default::default(),
+            },
+        );
+        env
+    }
+
+    #[test]
+    fn test_one_turn_finds_kill() {
+        let env = combat_env(vec![
+            (CardClass::Defend, false),
+            (CardClass::Defend, false),
+            (CardClass::Defend, false),
+            (CardClass::Defend, false),
+            (CardClass::DebugKillAll, false),
+        ]);
+        let features = encoding::encode(env.game());
+        let recs = OneTurnSolver {
+            max_nodes: 1000,
+            seed: 0,
+        }
+        .solve(&env);
+        assert_eq!(recs.len(), legal_actions(&env).len());
+        let steps = env.game().valid_steps();
+        let kill = steps
+            .iter()
+            .position(|s| s.description(env.game()).contains("DebugKillAll"))
+            .unwrap();
+        let kill = encoding::encode_steps(&steps)[kill];
+        // every line that plays the card wins, so the kill ties for best
+        assert!(recs[0].value >= 1e6);
+        let kill_rec = recs.iter().find(|r| r.action == kill).unwrap();
+        assert_eq!(kill_rec.value, recs[0].value);
+        assert!(recs.iter().any(|r| r.value < recs[0].value));
+        // the original game is untouched
+        assert_eq!(encoding::encode(env.game()), features);
+    }
+
+    #[test]
+    fn test_one_turn_transpositions() {
+        let env = combat_env(vec![
+            (CardClass::Strike, false),
+            (CardClass::Strike, false),
+            (CardClass::Defend, false),
+            (CardClass::Defend, false),
+            (CardClass::Defend, false),
+        ]);
+        let solver = OneTurnSolver {
+            max_nodes: 100000,
+            seed: 0,
+        };
+        let mut table = TranspositionTable::new(StateOptions { include_rng: true });
+        let mut nodes = 0;
+        let turn = env.game().turn;
+        for a in legal_actions(&env) {
+            solver.search(
+                &OneTurnSolver::child(&env, a),
+                a,
+                turn,
+                &mut nodes,
+                &mut table,
+            );
+        }
+        // Strike then Defend finds what Defend then Strike stored, or the reverse
+        assert!(table.hits() > 0);
+        assert!(nodes < solver.max_nodes);
+    }
+
+    #[test]
+    fn test_mcts_finds_kill() {
+        let env = combat_env(vec![
+            (CardClass::Strike, false),
+            (CardClass::Strike, false),
+            (CardClass::Defend, false),
+            (CardClass::Defend, false),
+            (CardClass::DebugKillAll, false),
+        ]);
+        let recs = MctsSolver {
+            budget: Duration::MAX,
+            max_simulations: Some(200),
+            evaluator: SquashedHeuristic,
+            exploration: 1.0,
+            seed: 0,
+        }
+        .solve(&env);
+        assert_eq!(recs.len(), legal_actions(&env).len());
+        let steps = env.game().valid_steps();
+        let kill = steps
+            .iter()
+            .position(|s| s.description(env.game()).contains("DebugKillAll"))
+            .unwrap();
+        assert_eq!(recs[0].action, encoding::encode_steps(&steps)[kill]);
+        assert!(recs[0].value > recs[1].value);
+    }
+
+    #[test]
+    fn test_pimc() {
+        let env = combat_env(vec![
+            (CardClass::Strike, false),
+            (CardClass::Strike, false),
+            (CardClass::Defend, false),
+            (CardClass::Defend, false),
+            (CardClass::Defend, false),
+            (CardClass::Defend, false),
+            (CardClass::DebugKillAll, false),
+        ]);
+        let recs = PimcSolver {
+            samples: 3,
+            solver: OneTurnSolver {
+                max_nodes: 200,
+                seed: 0,
+            },
+            seed: 0,
+        }
+        .solve(&env);
+        assert_eq!(recs.len(), legal_actions(&env).len());
+        assert!(recs.windows(2).all(|w| w[0].value >= w[1].value));
+    }
+
+    #[test]
+    fn test_route() {
+        let mut env = Env::default();
+        env.reset(
+            1,
+            EnvConfig {
+                episode: Episode::FullRun { last_floor: None },
+                rewards: Default::default(),
+                limits: Default::default(),
+            },
+        );
+        // skip neow
+        while !env
+            .game()
+            .valid_steps()
+            .iter()
+            .any(|s| (s.as_ref() as &dyn Any).is::<AscendStep>())
+        {
+            let a = legal_actions(&env)[0];
+            env.step(a);
+        }
+        let recs = RouteSolver.solve(&env);
+        assert!(!recs.is_empty());
+        for r in &recs {
+            assert!(r.outcome.starts_with("route "));
+            assert!(encoding::decode_action(env.game(), r.action).is_some());
+        }
+        assert!(recs[0].value >= recs[recs.len() - 1].value);
+        assert!(
+            RouteSolver
+                .solve(&combat_env(vec![(CardClass::Strike, false)]))
+                .is_empty()
+        );
+    }
+}
@@ -1,25 +1,90 @@
 use crate::{game::Game, step::Step};
 
-use std::fmt::Debug;
+use dyn_clone::DynClone;
+use dyn_eq::DynEq;
+use dyn_hash::DynHash;
+use std::{
+    fmt::Debug,
+    hash::{Hash, Hasher},
+};
 
-#[derive(Default, Debug)]
-pub struct Steps {
-    pub steps: Vec<Box<dyn Step>>,
+// Where a state puts its valid steps. Depending on what the caller needs the
+// steps are collected, only counted, visited in place or only the one at a
+// given index is kept, so enumerating them doesn't have to allocate.
+pub struct Steps<'a> {
+    mode: StepsMode<'a>,
+    len: usize,
 }
 
-impl Steps {
+enum StepsMode<'a> {
+    Collect(Vec<Box<dyn Step>>),
+    Count,
+    Visit(&'a mut dyn FnMut(&dyn Step)),
+    Select(usize, Option<Box<dyn Step>>),
+}
+
+impl<'a> Steps<'a> {
+    pub fn collect() -> Self {
+        Self::with_mode(StepsMode::Collect(Vec::new()))
+    }
+    pub fn count() -> Self {
+        Self::with_mode(StepsMode::Count)
+    }
+    pub fn visit(f: &'a mut dyn FnMut(&dyn Step)) -> Self {
+        Self::with_mode(StepsMode::Visit(f))
+    }
+    pub fn select(index: usize) -> Self {
+        Self::with_mode(StepsMode::Select(index, None))
+    }
+    fn with_mode(mode: StepsMode<'a>) -> Self {
+        Self { mode, len: 0 }
+    }
     pub fn push<T: Step>(&mut self, step: T) {
-        self.steps.push(Box::new(step));
+        match &mut self.mode {
+            StepsMode::Collect(steps) => steps.push(Box::new(step)),
+            StepsMode::Count => {}
+            StepsMode::Visit(f) => f(&step),
+            StepsMode::Select(i, selected) => {
+                if *i == self.len {
+                    *selected = Some(Box::new(step));
+                }
+            }
+        }
+        self.len += 1;
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn into_steps(self) -> Vec<Box<dyn Step>> {
+        match self.mode {
+            StepsMode::Collect(steps) => steps,
+            _ => panic!("steps weren't collected"),
+        }
+    }
+    pub fn into_selected(self) -> Option<Box<dyn Step>> {
+        match self.mode {
+            StepsMode::Select(_, selected) => selected,
+            _ => panic!("no step was selected"),
+        }
     }
 }
 
-pub trait GameState: Debug {
+pub trait GameState: Debug + DynClone + DynEq + DynHash + Send {
     fn run(&self, _: &mut Game) {}
-    fn valid_steps(&self, _: &Game) -> Option<Steps> {
-        None
+    // Pushes the steps the player can choose from. Returns false if the
+    // state doesn't wait for the player.
+    fn valid_steps(&self, _: &Game, _: &mut Steps) -> bool {
+        false
     }
 }
 
+dyn_clone::clone_trait_object!(GameState);
+dyn_eq::eq_trait_object!(GameState);
+dyn_hash::hash_trait_object!(GameState);
+
 #[derive(Eq, PartialEq, Debug)]
 pub struct ContinueStep;
 
@@ -34,7 +99,7 @@ impl Step for ContinueStep {
     }
 }
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct GameStateManager {
     stack: Vec<Box<dyn GameState>>,
     debug: bool,
@@ -46,6 +111,21 @@ impl std::fmt::Debug for GameStateManager {
     }
 }
 
+// compared by the stack of states
+impl PartialEq for GameStateManager {
+    fn eq(&self, other: &Self) -> bool {
+        self.stack == other.stack
+    }
+}
+
+impl Eq for GameStateManager {}
+
+impl Hash for GameStateManager {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.stack.hash(state);
+    }
+}
+
 impl GameStateManager {
     pub fn clear(&mut self) {
         self.stack.clear();
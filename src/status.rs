@@ -21,6 +21,14 @@ macro_rules! s {
                 }
             }
         }
+        impl Status {
+            pub const ALL: &'static [Self] = &[$(Self::$name,)+];
+            pub const COUNT: usize = Self::ALL.len();
+
+            pub fn all() -> Vec<Self> {
+                Self::ALL.to_vec()
+            }
+        }
     };
 }
 
@@ -38,6 +46,8 @@ s!(
     LoseDexterity => Debuff,
     LoseStrength => Debuff,
     GainStrength => Debuff,
+    Hex => Debuff,
+    Constricted => Debuff,
 
     Vigor => Buff,
     RegenPlayer => Buff,
@@ -86,6 +96,18 @@ s!(
     ModeShift => Buff,
     SharpHide => Buff,
     StolenGold => Buff,
+    Flight => Buff,
+    Malleable => Buff,
+    PainfulStabs => Buff,
+    Regrow => Buff,
+    StrengthUp => Buff,
+    Explosive => Buff,
+    Fading => Buff,
+    Shifting => Buff,
+    Reactive => Buff,
+    Slow => Buff,
+    Curiosity => Buff,
+    Unawakened => Buff,
 );
 
 impl Status {
@@ -104,6 +126,10 @@ impl Status {
         use Status::*;
         matches!(self, NoDraw | Confusion | Barricade | Corruption)
     }
+    // counts up from 0 instead of going away at 0
+    pub fn can_be_zero(&self) -> bool {
+        matches!(self, Status::Slow)
+    }
     pub fn is_debuff(&self, amount: i32) -> bool {
         match self.ty() {
             StatusType::Amount => amount < 0,
@@ -429,7 +455,7 @@ mod tests {
                 .add_cards(CardClass::Strike, 10)
                 .build_combat();
             for c in &g.hand {
-                let c = c.borrow();
+                let c = &g.cards[c];
                 match c.cost {
                     CardCost::Cost {
                         base_cost,
@@ -458,9 +484,14 @@ mod tests {
             .add_player_status(Confusion, 1)
             .add_cards(CardClass::Strike, 10)
             .build_combat();
-        for c in g.draw_pile.get_all() {
-            let mut c = c.borrow_mut();
-            match &mut c.cost {
+        for c in g
+            .draw_pile
+            .get_all()
+            .into_iter()
+            .copied()
+            .collect::<Vec<_>>()
+        {
+            g.update_card(c, |c| match &mut c.cost {
                 CardCost::Cost {
                     base_cost: _,
                     temporary_cost,
@@ -470,11 +501,11 @@ mod tests {
                     *free_to_play_once = true;
                 }
                 _ => unreachable!(),
-            }
+            });
         }
         g.run_action(DrawAction(2));
         for c in &g.hand {
-            let c = c.borrow();
+            let c = &g.cards[c];
             match c.cost {
                 CardCost::Cost {
                     base_cost: _,
@@ -840,8 +871,8 @@ mod tests {
         });
         assert_eq!(g.monsters[0].creature.cur_hp, hp - 8 - 8 - 5 - 8 - 10);
         assert_eq!(g.discard_pile.len(), 1);
-        let c = g.discard_pile.pop().unwrap();
-        g.hand.push(c);
+        let c = g.discard_pile.pop(&g.cards).unwrap();
+        g.hand.push(c, &g.cards);
         g.step_test(PlayCardStep {
             hand_index: 0,
             target: Some(0),
@@ -1049,13 +1080,13 @@ mod tests {
             .add_card(CardClass::Strike)
             .build_combat();
         assert_eq!(g.hand.len(), 3);
-        assert_eq!(g.hand[0].borrow().class.color(), CardColor::Colorless);
-        assert_eq!(g.hand[1].borrow().class.color(), CardColor::Colorless);
-        assert_eq!(g.hand[2].borrow().class, CardClass::Strike);
+        assert_eq!(g.cards[g.hand[0]].class.color(), CardColor::Colorless);
+        assert_eq!(g.cards[g.hand[1]].class.color(), CardColor::Colorless);
+        assert_eq!(g.cards[g.hand[2]].class, CardClass::Strike);
         g.step_test(EndTurnStep);
         assert_eq!(g.hand.len(), 5);
-        assert_eq!(g.hand[0].borrow().class.color(), CardColor::Colorless);
-        assert_eq!(g.hand[1].borrow().class.color(), CardColor::Colorless);
+        assert_eq!(g.cards[g.hand[0]].class.color(), CardColor::Colorless);
+        assert_eq!(g.cards[g.hand[1]].class.color(), CardColor::Colorless);
     }
 
     #[test]
@@ -3,7 +3,7 @@ use crate::game::Game;
 use dyn_eq::DynEq;
 use std::fmt::Debug;
 
-pub trait Step: DynEq + Debug {
+pub trait Step: DynEq + Debug + Send {
     fn should_pop_state(&self) -> bool;
     fn run(&self, game: &mut Game);
     fn description(&self, game: &Game) -> String;
@@ -0,0 +1,151 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use crate::queue::ActionId;
+
+// Collects every action that runs, and every card play, relic, status and
+// monster move that queued actions, with what caused it. `flush` writes them
+// out as an indented tree, each line under its cause:
+//
+//   # floor 1
+//   play card #3 on monster 0
+//     damage 6 hp monster 0 (Attack { source: player, on_fatal: None })
+//     clear current card
+//     discard card #3
+//   Status(player, Metallicize)
+//     block 3 player
+pub struct Tracer {
+    out: Box<dyn Write + Send>,
+    // in the order they ran (or queued something, for causes)
+    nodes: Vec<(ActionId, Option<ActionId>, String)>,
+}
+
+impl Tracer {
+    pub fn new<W: Write + Send + 'static>(out: W) -> Self {
+        Self {
+            out: Box::new(out),
+            nodes: Vec::new(),
+        }
+    }
+
+    pub fn create<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(Self::new(BufWriter::new(File::create(path)?)))
+    }
+
+    pub fn record(&mut self, id: ActionId, parent: Option<ActionId>, label: String) {
+        self.nodes.push((id, parent, label));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    // Writes everything recorded since the last flush under `header`. Causes
+    // that were written by an earlier flush start new roots.
+    pub fn flush(&mut self, header: &str) -> io::Result<()> {
+        let nodes = std::mem::take(&mut self.nodes);
+        let index = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (n.0, i))
+            .collect::<HashMap<_, _>>();
+        let mut roots = Vec::new();
+        let mut children = vec![Vec::new(); nodes.len()];
+        for (i, (_, parent, _)) in nodes.iter().enumerate() {
+            match parent.and_then(|p| index.get(&p)) {
+                Some(&p) => children[p].push(i),
+                None => roots.push(i),
+            }
+        }
+        writeln!(self.out, "# {header}")?;
+        let mut stack = roots.into_iter().rev().map(|i| (i, 0)).collect::<Vec<_>>();
+        while let Some((i, depth)) = stack.pop() {
+            writeln!(self.out, "{:indent$}{}", "", nodes[i].2, indent = depth * 2)?;
+            stack.extend(children[i].iter().rev().map(|&c| (c, depth + 1)));
+        }
+        self.out.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::{
+        actions::exhaust_card::ExhaustCardAction,
+        cards::CardClass,
+        combat::EndTurnStep,
+        game::{CreatureRef, GameBuilder},
+        monsters::test::NoopMonster,
+        relic::RelicClass,
+        status::Status,
+    };
+
+    #[derive(Clone, Default)]
+    struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn traced(buf: &SharedBuf) -> String {
+        String::from_utf8(buf.0.lock().unwrap().clone()).unwrap()
+    }
+
+    #[test]
+    fn test_nested_triggers() {
+        let buf = SharedBuf::default();
+        let mut g = GameBuilder::default()
+            .add_player_status(Status::FeelNoPain, 3)
+            .add_player_status(Status::DarkEmbrace, 1)
+            .add_player_status(Status::Evolve, 1)
+            .build_combat_with_monster(NoopMonster::new());
+        g.add_card_to_draw_pile(CardClass::Strike);
+        g.add_card_to_draw_pile(CardClass::Wound);
+        g.action_queue.set_tracer(Tracer::new(buf.clone()));
+        let c = g.new_card(CardClass::Defend);
+        g.run_action(ExhaustCardAction(c));
+        g.flush_trace().unwrap();
+        assert_eq!(
+            traced(&buf),
+            "# floor 0\nexhaust card #2\n  block 3 player\n  draw 1\n    draw 1\n"
+        );
+    }
+
+    #[test]
+    fn test_causes() {
+        let buf = SharedBuf::default();
+        let mut g = GameBuilder::default()
+            .add_relic(RelicClass::Orichalcum)
+            .add_player_status(Status::Metallicize, 2)
+            .build_combat_with_monster(NoopMonster::new());
+        g.action_queue.set_tracer(Tracer::new(buf.clone()));
+        g.play_card(CardClass::Strike, Some(CreatureRef::monster(0)));
+        g.step_test(EndTurnStep);
+        g.flush_trace().unwrap();
+        let trace = traced(&buf);
+        assert!(trace.starts_with(
+            "# floor 0\n\
+             play card #0 on monster 0\n  \
+               damage 6 hp monster 0 (Attack { source: player, on_fatal: None })\n  \
+               clear current card\n  \
+               discard card #0\n\
+             Relic(Orichalcum)\n  \
+               orichalcum 6\n    \
+                 block 6 player\n\
+             Status(player, Metallicize)\n  \
+               block 2 player\n"
+        ));
+    }
+}
@@ -0,0 +1,365 @@
+use std::{
+    fs::File,
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+use crate::{
+    encoding,
+    game::{CombatType, Game, GameStatus},
+};
+
+// file layout, all little endian:
+//   header: MAGIC, encoding version (u32), layout hash (u64),
+//     num features (u32), action space size (u32)
+//   rows: features (f32 each), legal action bitmask (ceil(actions / 8) bytes),
+//     chosen action (u32), combat result (i8), run result (i8), final floor (i32)
+const MAGIC: &[u8; 8] = b"SLAYTRAJ";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Header {
+    pub version: u32,
+    pub layout_hash: u64,
+    pub num_features: u32,
+    pub action_space_size: u32,
+}
+
+impl Header {
+    fn current() -> Self {
+        Self {
+            version: encoding::ENCODING_VERSION,
+            layout_hash: encoding::layout_hash(),
+            num_features: encoding::num_features() as u32,
+            action_space_size: encoding::ACTION_SPACE_SIZE as u32,
+        }
+    }
+
+    fn mask_bytes(&self) -> usize {
+        (self.action_space_size as usize).div_ceil(8)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    // not known, e.g. the session ended early or the decision was outside combat
+    Unknown,
+    Won,
+    Lost,
+}
+
+impl Outcome {
+    fn to_i8(self) -> i8 {
+        match self {
+            Outcome::Unknown => 0,
+            Outcome::Won => 1,
+            Outcome::Lost => -1,
+        }
+    }
+
+    fn from_i8(v: i8) -> io::Result<Self> {
+        match v {
+            0 => Ok(Outcome::Unknown),
+            1 => Ok(Outcome::Won),
+            -1 => Ok(Outcome::Lost),
+            _ => Err(invalid_data(format!("invalid outcome {v}"))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    pub features: Vec<f32>,
+    pub legal_actions: Vec<bool>,
+    pub action: u32,
+    pub combat_result: Outcome,
+    pub run_result: Outcome,
+    pub final_floor: i32,
+}
+
+fn invalid_data(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+// The recorder of a game. A copy of the game isn't recorded, the recorder stays
+// with the original.
+#[derive(Default)]
+pub struct RecorderSlot(pub Option<Recorder>);
+
+impl Clone for RecorderSlot {
+    fn clone(&self) -> Self {
+        Self(None)
+    }
+}
+
+// Collects one row per decision. Outcomes are only known later, so rows are
+// held until the run ends (or `finish()` is called) and then written out.
+pub struct Recorder {
+    out: Box<dyn Write + Send>,
+    header: Header,
+    pending: Vec<Row>,
+    combat_start: Option<usize>,
+    rows_written: usize,
+}
+
+impl Recorder {
+    pub fn new<W: Write + Send + 'static>(out: W) -> io::Result<Self> {
+        let mut r = Self {
+            out: Box::new(out),
+            header: Header::current(),
+            pending: Vec::new(),
+            combat_start: None,
+            rows_written: 0,
+        };
+        r.write_header()?;
+        Ok(r)
+    }
+
+    pub fn create<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::new(BufWriter::new(File::create(path)?))
+    }
+
+    pub fn rows_written(&self) -> usize {
+        self.rows_written
+    }
+
+    fn write_header(&mut self) -> io::Result<()> {
+        let h = self.header;
+        self.out.write_all(MAGIC)?;
+        self.out.write_all(&h.version.to_le_bytes())?;
+        self.out.write_all(&h.layout_hash.to_le_bytes())?;
+        self.out.write_all(&h.num_features.to_le_bytes())?;
+        self.out.write_all(&h.action_space_size.to_le_bytes())
+    }
+
+    pub fn record_decision(&mut self, game: &Game, chosen: usize) {
+        if game.in_combat != CombatType::None && self.combat_start.is_none() {
+            self.combat_start = Some(self.pending.len());
+        }
+        let actions = encoding::encode_valid_steps(game);
+        let mut legal_actions = vec![false; encoding::ACTION_SPACE_SIZE];
+        for &a in &actions {
+            legal_actions[a] = true;
+        }
+        self.pending.push(Row {
+            features: encoding::encode(game),
+            legal_actions,
+            action: actions[chosen] as u32,
+            combat_result: Outcome::Unknown,
+            run_result: Outcome::Unknown,
+            final_floor: 0,
+        });
+    }
+
+    fn end_combat(&mut self, result: Outcome) {
+        if let Some(start) = self.combat_start.take() {
+            for row in &mut self.pending[start..] {
+                row.combat_result = result;
+            }
+        }
+    }
+
+    pub fn after_step(&mut self, game: &Game) -> io::Result<()> {
+        match game.status {
+            GameStatus::Defeat | GameStatus::Victory | GameStatus::Aborted { .. } => {
+                self.finish(game)
+            }
+            GameStatus::Combat => {
+                if game.in_combat == CombatType::None {
+                    self.end_combat(Outcome::Won);
+                }
+                Ok(())
+            }
+        }
+    }
+
+    // Attaches the run outcome so far to all pending rows and writes them.
+    pub fn finish(&mut self, game: &Game) -> io::Result<()> {
+        let run_result = match game.status {
+            GameStatus::Defeat => Outcome::Lost,
+            GameStatus::Victory => Outcome::Won,
+            GameStatus::Combat | GameStatus::Aborted { .. } => Outcome::Unknown,
+        };
+        if run_result == Outcome::Lost {
+            self.end_combat(Outcome::Lost);
+        }
+        self.combat_start = None;
+        for mut row in std::mem::take(&mut self.pending) {
+            row.run_result = run_result;
+            row.final_floor = game.floor;
+            self.write_row(&row)?;
+        }
+        self.out.flush()
+    }
+
+    fn write_row(&mut self, row: &Row) -> io::Result<()> {
+        for f in &row.features {
+            self.out.write_all(&f.to_le_bytes())?;
+        }
+        let mut mask = vec![0u8; self.header.mask_bytes()];
+        for (i, _) in row.legal_actions.iter().enumerate().filter(|(_, l)| **l) {
+            mask[i / 8] |= 1 << (i % 8);
+        }
+        self.out.write_all(&mask)?;
+        self.out.write_all(&row.action.to_le_bytes())?;
+        self.out
+            .write_all(&row.combat_result.to_i8().to_le_bytes())?;
+        self.out.write_all(&row.run_result.to_i8().to_le_bytes())?;
+        self.out.write_all(&row.final_floor.to_le_bytes())?;
+        self.rows_written += 1;
+        Ok(())
+    }
+}
+
+fn read_array<const N: usize, R: Read>(r: &mut R) -> io::Result<[u8; N]> {
+    let mut buf = [0; N];
+    r.read_exact(&mut buf)?;
+    Ok(buf)
+}
+
+pub fn read_header<R: Read>(r: &mut R) -> io::Result<Header> {
+    if &read_array::<8, _>(r)? != MAGIC {
+        return Err(invalid_data("not a trajectory file".to_string()));
+    }
+    Ok(Header {
+        version: u32::from_le_bytes(read_array(r)?),
+        layout_hash: u64::from_le_bytes(read_array(r)?),
+        num_features: u32::from_le_bytes(read_array(r)?),
+        action_space_size: u32::from_le_bytes(read_array(r)?),
+    })
+}
+
+// Reads the next row, or None at the end of the file.
+pub fn read_row<R: Read>(r: &mut R, header: &Header) -> io::Result<Option<Row>> {
+    let mut first = [0; 4];
+    match r.read_exact(&mut first) {
+        Ok(()) => {}
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e),
+    }
+    let mut features = Vec::with_capacity(header.num_features as usize);
+    features.push(f32::from_le_bytes(first));
+    for _ in 1..header.num_features {
+        features.push(f32::from_le_bytes(read_array(r)?));
+    }
+    let mut mask = vec![0u8; header.mask_bytes()];
+    r.read_exact(&mut mask)?;
+    let legal_actions = (0..header.action_space_size as usize)
+        .map(|i| mask[i / 8] & (1 << (i % 8)) != 0)
+        .collect();
+    Ok(Some(Row {
+        features,
+        legal_actions,
+        action: u32::from_le_bytes(read_array(r)?),
+        combat_result: Outcome::from_i8(i8::from_le_bytes(read_array(r)?))?,
+        run_result: Outcome::from_i8(i8::from_le_bytes(read_array(r)?))?,
+        final_floor: i32::from_le_bytes(read_array(r)?),
+    }))
+}
+
+// Reads a whole file, rejecting it if it was written with a different layout.
+pub fn read_file<P: AsRef<Path>>(path: P) -> io::Result<Vec<Row>> {
+    let mut r = BufReader::new(File::open(path)?);
+    let header = read_header(&mut r)?;
+    if header != Header::current() {
+        return Err(invalid_data(format!(
+            "trajectory layout {header:?} doesn't match current layout {:?}",
+            Header::current()
+        )));
+    }
+    let mut rows = Vec::new();
+    while let Some(row) = read_row(&mut r, &header)? {
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::{
+        cards::CardClass,
+        game::GameBuilder,
+        monsters::test::{AttackMonster, NoopMonster},
+    };
+
+    #[derive(Clone, Default)]
+    struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn read_all(buf: &SharedBuf) -> Vec<Row> {
+        let data = buf.0.lock().unwrap();
+        let mut r = &data[..];
+        let header = read_header(&mut r).unwrap();
+        assert_eq!(header, Header::current());
+        let mut rows = Vec::new();
+        while let Some(row) = read_row(&mut r, &header).unwrap() {
+            rows.push(row);
+        }
+        rows
+    }
+
+    #[test]
+    fn test_won_combat() {
+        let buf = SharedBuf::default();
+        let mut g = GameBuilder::default()
+            .add_cards(CardClass::Defend, 4)
+            .add_card(CardClass::DebugKillAll)
+            .build_combat_with_monster(NoopMonster::new());
+        g.recorder.0 = Some(Recorder::new(buf.clone()).unwrap());
+        g.step(0);
+        let kill = g
+            .valid_steps()
+            .iter()
+            .position(|s| s.description(&g).contains("DebugKillAll"))
+            .unwrap();
+        g.step(kill);
+        assert_eq!(g.in_combat, CombatType::None);
+        assert!(!buf.0.lock().unwrap().is_empty());
+        let mut recorder = g.recorder.0.take().unwrap();
+        recorder.finish(&g).unwrap();
+        assert_eq!(recorder.rows_written(), 2);
+
+        let rows = read_all(&buf);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].action, 0);
+        assert!(rows[1].legal_actions[rows[1].action as usize]);
+        for row in &rows {
+            assert_eq!(row.features.len(), encoding::num_features());
+            assert_eq!(row.combat_result, Outcome::Won);
+            assert_eq!(row.run_result, Outcome::Unknown);
+        }
+    }
+
+    #[test]
+    fn test_lost_run() {
+        let buf = SharedBuf::default();
+        let mut g = GameBuilder::default()
+            .set_player_hp(1)
+            .build_combat_with_monster(AttackMonster::new(10));
+        g.recorder.0 = Some(Recorder::new(buf.clone()).unwrap());
+        g.step(0);
+        assert!(matches!(g.status, GameStatus::Defeat));
+        let rows = read_all(&buf);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].combat_result, Outcome::Lost);
+        assert_eq!(rows[0].run_result, Outcome::Lost);
+        assert_eq!(rows[0].final_floor, g.floor);
+    }
+
+    #[test]
+    fn test_bad_header() {
+        let mut r = &b"NOTATRAJECTORY"[..];
+        assert!(read_header(&mut r).is_err());
+    }
+}
@@ -0,0 +1,357 @@
+use std::{
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+};
+
+use rand::Rng;
+
+use crate::{
+    card::{Card, CardRef},
+    game::Game,
+};
+
+// Hashing and comparing combat states, so that search can recognize a state
+// it already reached through a different order of plays, e.g. Defend then
+// Strike vs Strike then Defend.
+//
+// Piles are compared as multisets of what the cards are, not which cards they
+// are, and the draw pile up to swapping cards that are drawn the same way.
+// Piles, creatures and monster behaviors keep their hashes up to date as they
+// change, so taking the hash only combines those with the scalars on `Game`.
+//
+// Pending choices, actions and cards to play are compared as they are, which
+// names cards by reference, so states in the middle of resolving something
+// only match if they got there with the same cards.
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StateOptions {
+    // whether states must also be at the same rng position to be equal
+    pub include_rng: bool,
+}
+
+// splitmix64's finalizer
+pub fn mix(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9e3779b97f4a7c15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d049bb133111eb);
+    x ^ (x >> 31)
+}
+
+pub fn key<T: Hash + ?Sized>(t: &T) -> u64 {
+    let mut h = DefaultHasher::new();
+    t.hash(&mut h);
+    h.finish()
+}
+
+fn sorted_cards<'a>(game: &'a Game, pile: &[CardRef]) -> Vec<&'a Card> {
+    let mut cards = pile.iter().map(|c| &game.cards[c]).collect::<Vec<_>>();
+    cards.sort();
+    cards
+}
+
+impl Game {
+    fn state_scalars(&self) -> impl Hash + Eq + '_ {
+        (
+            (&self.status, self.in_combat, self.turn, self.energy),
+            (self.num_cards_played_this_turn, self.num_times_took_damage),
+            (self.draw_per_turn, self.gold, self.smoke_bombed),
+            self.should_add_extra_decay_status,
+            (&self.relics, &self.potions),
+        )
+    }
+
+    fn state_pending(&self) -> impl Hash + Eq + '_ {
+        (&self.state, &self.action_queue, &self.card_queue)
+    }
+
+    // Equal states (see `state_eq`) have equal hashes.
+    pub fn state_hash(&self, options: StateOptions) -> u64 {
+        let card_key = |c: &CardRef| self.cards.key(*c);
+        let mut h = DefaultHasher::new();
+        self.state_scalars().hash(&mut h);
+        self.player.state_hash().hash(&mut h);
+        for m in &self.monsters {
+            m.creature.state_hash().hash(&mut h);
+            m.behavior_hash().hash(&mut h);
+            m.held_card.as_ref().map(card_key).hash(&mut h);
+        }
+        for pile in [
+            &self.hand,
+            &self.discard_pile,
+            &self.exhaust_pile,
+            &self.chosen_cards,
+        ] {
+            pile.hash().hash(&mut h);
+        }
+        self.draw_pile.hash().hash(&mut h);
+        self.cur_card.as_ref().map(card_key).hash(&mut h);
+        self.state_pending().hash(&mut h);
+        if options.include_rng {
+            self.rng.clone().next_u64().hash(&mut h);
+        }
+        h.finish()
+    }
+
+    // Whether the rest of the game plays out the same from both states given
+    // the same choices (and the same rng, with `include_rng`).
+    pub fn state_eq(&self, other: &Game, options: StateOptions) -> bool {
+        let same_pile = |a: &[CardRef], b: &[CardRef]| {
+            a.len() == b.len() && sorted_cards(self, a) == sorted_cards(other, b)
+        };
+        self.state_scalars() == other.state_scalars()
+            && self.player.state_eq(&other.player)
+            && self.monsters.len() == other.monsters.len()
+            && self.monsters.iter().zip(&other.monsters).all(|(a, b)| {
+                a.creature.state_eq(&b.creature)
+                    && a.behavior == b.behavior
+                    && a.held_card.map(|c| &self.cards[c]) == b.held_card.map(|c| &other.cards[c])
+            })
+            && same_pile(&self.hand, &other.hand)
+            && same_pile(&self.discard_pile, &other.discard_pile)
+            && same_pile(&self.exhaust_pile, &other.exhaust_pile)
+            && same_pile(&self.chosen_cards, &other.chosen_cards)
+            && self.draw_pile.interchangeable_runs(|c| &self.cards[c])
+                == other.draw_pile.interchangeable_runs(|c| &other.cards[c])
+            && self.cur_card.map(|c| &self.cards[c]) == other.cur_card.map(|c| &other.cards[c])
+            && self.state_pending() == other.state_pending()
+            && (!options.include_rng || self.rng == other.rng)
+    }
+}
+
+// What a search found for the states it has visited. Lookups go by
+// `state_hash` and are confirmed with `state_eq`, so a collision is only a
+// miss.
+pub struct TranspositionTable<T> {
+    options: StateOptions,
+    entries: HashMap<u64, Vec<(Game, T)>>,
+    hits: usize,
+}
+
+impl<T> TranspositionTable<T> {
+    pub fn new(options: StateOptions) -> Self {
+        Self {
+            options,
+            entries: HashMap::new(),
+            hits: 0,
+        }
+    }
+
+    pub fn get(&mut self, game: &Game) -> Option<&T> {
+        let found = self
+            .entries
+            .get(&game.state_hash(self.options))?
+            .iter()
+            .find(|(g, _)| g.state_eq(game, self.options))?;
+        self.hits += 1;
+        Some(&found.1)
+    }
+
+    pub fn insert(&mut self, game: &Game, value: T) {
+        self.entries
+            .entry(game.state_hash(self.options))
+            .or_default()
+            .push((game.clone(), value));
+    }
+
+    #[cfg(test)]
+    pub fn len(&self) -> usize {
+        self.entries.values().map(Vec::len).sum()
+    }
+
+    #[cfg(test)]
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::{StateOptions, TranspositionTable};
+    use crate::{
+        agent::legal_actions,
+        card::CardRef,
+        cards::CardClass,
+        combat::{EndTurnStep, PlayCardStep},
+        game::{Game, GameBuilder, Rand},
+        gym::{Env, EnvConfig, Episode},
+        monsters::test::AttackMonster,
+        rng::rand_slice,
+        status::Status,
+    };
+
+    fn new_game() -> Game {
+        GameBuilder::default()
+            .seed(0)
+            .add_card(CardClass::Strike)
+            .add_card(CardClass::Defend)
+            .add_card(CardClass::Defend)
+            .add_cards(CardClass::Bash, 2)
+            .build_combat_with_monster(AttackMonster::new(5))
+    }
+
+    fn play(g: &mut Game, class: CardClass) {
+        let hand_index = g
+            .hand
+            .iter()
+            .position(|c| g.cards[c].class == class)
+            .unwrap();
+        let target = g.cards[g.hand[hand_index]].has_target().then_some(0);
+        g.step_test(PlayCardStep { hand_index, target });
+    }
+
+    fn assert_same(a: &Game, b: &Game, options: StateOptions) {
+        assert!(a.state_eq(b, options));
+        assert_eq!(a.state_hash(options), b.state_hash(options));
+    }
+
+    #[test]
+    fn test_transposition() {
+        let mut a = new_game();
+        let mut b = new_game();
+        assert_same(&a, &b, StateOptions { include_rng: true });
+        play(&mut a, CardClass::Strike);
+        play(&mut a, CardClass::Defend);
+        play(&mut b, CardClass::Defend);
+        assert!(!a.state_eq(&b, StateOptions::default()));
+        assert_ne!(
+            a.state_hash(StateOptions::default()),
+            b.state_hash(StateOptions::default())
+        );
+        play(&mut b, CardClass::Strike);
+        assert_same(&a, &b, StateOptions { include_rng: true });
+        for g in [&mut a, &mut b] {
+            g.step_test(EndTurnStep);
+        }
+        assert_same(&a, &b, StateOptions { include_rng: true });
+    }
+
+    #[test]
+    fn test_rng() {
+        let a = new_game();
+        let mut b = new_game();
+        b.rng = Rand::seed_from_u64(1);
+        assert_same(&a, &b, StateOptions::default());
+        assert!(!a.state_eq(&b, StateOptions { include_rng: true }));
+    }
+
+    #[test]
+    fn test_status_order() {
+        let mut a = new_game();
+        let mut b = new_game();
+        a.player.set_status(Status::Metallicize, 1);
+        a.player.set_status(Status::Strength, 2);
+        b.player.set_status(Status::Strength, 2);
+        b.player.set_status(Status::Metallicize, 1);
+        // statuses trigger in the order they were gained
+        assert!(!a.state_eq(&b, StateOptions::default()));
+        b.player.remove_status(Status::Metallicize);
+        b.player.set_status(Status::Strength, 3);
+        b.player.remove_status(Status::Strength);
+        b.player.set_status(Status::Metallicize, 1);
+        b.player.set_status(Status::Strength, 2);
+        assert_same(&a, &b, StateOptions::default());
+    }
+
+    #[test]
+    fn test_draw_pile() {
+        let mut a = new_game();
+        let mut b = new_game();
+        let strike = a.new_card(CardClass::Strike);
+        let defend = a.new_card(CardClass::Defend);
+        a.draw_pile.push_top(strike, &a.cards);
+        a.draw_pile.push_top(defend, &a.cards);
+        let defend = b.new_card(CardClass::Defend);
+        let strike = b.new_card(CardClass::Strike);
+        b.draw_pile.push_top(defend, &b.cards);
+        b.draw_pile.push_top(strike, &b.cards);
+        assert!(!a.state_eq(&b, StateOptions::default()));
+
+        let mut a = new_game();
+        let mut b = new_game();
+        for (g, classes) in [
+            (&mut a, [CardClass::Strike, CardClass::Defend]),
+            (&mut b, [CardClass::Defend, CardClass::Strike]),
+        ] {
+            for class in classes {
+                let c = g.new_card(class);
+                let rng = &mut g.rng;
+                g.draw_pile.shuffle_in_one(c, rng, &g.cards);
+            }
+        }
+        // the first card shuffled into an empty pile is drawn first
+        assert!(!a.state_eq(&b, StateOptions::default()));
+        for g in [&mut a, &mut b] {
+            let rng = &mut g.rng;
+            g.draw_pile.shuffle_all(rng);
+        }
+        assert_same(&a, &b, StateOptions::default());
+    }
+
+    #[test]
+    fn test_table() {
+        let options = StateOptions { include_rng: true };
+        let mut table = TranspositionTable::new(options);
+        let mut a = new_game();
+        let mut b = new_game();
+        play(&mut a, CardClass::Strike);
+        play(&mut a, CardClass::Defend);
+        table.insert(&a, "strike, defend");
+        play(&mut b, CardClass::Defend);
+        assert_eq!(table.get(&b), None);
+        play(&mut b, CardClass::Strike);
+        assert_eq!(table.get(&b), Some(&"strike, defend"));
+        assert_eq!(table.len(), 1);
+        assert_eq!(table.hits(), 1);
+    }
+
+    fn assert_hashes_up_to_date(g: &Game) {
+        let pile_hash = |pile: &[CardRef]| {
+            pile.iter()
+                .fold(0u64, |h, &c| h.wrapping_add(g.cards.key(c)))
+        };
+        for pile in [&g.hand, &g.discard_pile, &g.exhaust_pile, &g.chosen_cards] {
+            assert_eq!(pile.hash(), pile_hash(pile));
+        }
+        assert_eq!(
+            g.draw_pile.hash(),
+            pile_hash(
+                &g.draw_pile
+                    .get_all()
+                    .into_iter()
+                    .copied()
+                    .collect::<Vec<_>>()
+            )
+        );
+        assert_eq!(g.player.state_hash(), g.player.recomputed_state_hash());
+        for m in &g.monsters {
+            assert_eq!(m.creature.state_hash(), m.creature.recomputed_state_hash());
+            assert_eq!(m.behavior_hash(), m.behavior.state_hash());
+        }
+    }
+
+    #[test]
+    fn test_hashes_kept_up_to_date() {
+        for seed in 0..100 {
+            let mut env = Env::default();
+            env.reset(
+                seed,
+                EnvConfig {
+                    episode: Episode::FullRun { last_floor: None },
+                    rewards: Default::default(),
+                    limits: Default::default(),
+                },
+            );
+            let mut rng = Rand::seed_from_u64(seed);
+            for _ in 0..2000 {
+                if env.is_done() {
+                    break;
+                }
+                let a = rand_slice(&mut rng, &legal_actions(&env));
+                env.step(a);
+                assert_hashes_up_to_date(env.game());
+            }
+        }
+    }
+}
@@ -0,0 +1,364 @@
+use std::{
+    fs::File,
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+use rand::{RngExt, SeedableRng};
+
+use crate::{
+    agent::{self, Agent, Evaluator, GreedyAgent},
+    encoding,
+    game::{GameStatus, Rand},
+    gym::{Env, EnvConfig, Episode},
+    monsters::Combat,
+};
+
+const MAGIC: &[u8; 8] = b"SLAYVALU";
+
+// What a combat state is worth: 0 for a loss, 0.5 to 1 for a win depending on
+// how much hp is left.
+pub fn combat_return(env: &Env) -> f32 {
+    let game = env.game();
+    if matches!(game.status, GameStatus::Defeat) {
+        0.0
+    } else {
+        0.5 + 0.5 * game.player.cur_hp as f32 / game.player.max_hp as f32
+    }
+}
+
+// Linear model over the state encoding. Each feature is divided by the
+// largest magnitude seen for it during training, which is stored alongside
+// the weights.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearValue {
+    pub weights: Vec<f32>,
+    pub bias: f32,
+    pub scales: Vec<f32>,
+}
+
+impl LinearValue {
+    pub fn new() -> Self {
+        let n = encoding::num_features();
+        Self {
+            weights: vec![0.0; n],
+            bias: 0.5,
+            scales: vec![1.0; n],
+        }
+    }
+
+    fn scaled(&self, features: &[f32]) -> Vec<f32> {
+        features
+            .iter()
+            .zip(&self.scales)
+            .map(|(f, s)| f / s)
+            .collect()
+    }
+
+    fn predict_scaled(&self, x: &[f32]) -> f32 {
+        self.bias + x.iter().zip(&self.weights).map(|(x, w)| x * w).sum::<f32>()
+    }
+
+    pub fn predict(&self, features: &[f32]) -> f32 {
+        self.predict_scaled(&self.scaled(features))
+    }
+
+    // Grows scales to cover `features`, adjusting weights (and eligibility
+    // traces) so predictions don't change.
+    fn update_scales(&mut self, features: &[f32], traces: &mut [f32]) {
+        for (i, f) in features.iter().enumerate() {
+            let f = f.abs();
+            if f > self.scales[i] {
+                let ratio = f / self.scales[i];
+                self.weights[i] *= ratio;
+                traces[i] /= ratio;
+                self.scales[i] = f;
+            }
+        }
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut w = BufWriter::new(File::create(path)?);
+        w.write_all(MAGIC)?;
+        w.write_all(&encoding::layout_hash().to_le_bytes())?;
+        w.write_all(&(self.weights.len() as u32).to_le_bytes())?;
+        w.write_all(&self.bias.to_le_bytes())?;
+        for v in self.weights.iter().chain(&self.scales) {
+            w.write_all(&v.to_le_bytes())?;
+        }
+        w.flush()
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let mut r = BufReader::new(File::open(path)?);
+        let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg.to_string());
+        let mut magic = [0; 8];
+        r.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(invalid("not a value function file"));
+        }
+        let mut buf8 = [0; 8];
+        r.read_exact(&mut buf8)?;
+        if u64::from_le_bytes(buf8) != encoding::layout_hash() {
+            return Err(invalid("weights were trained with a different encoding"));
+        }
+        let mut buf4 = [0; 4];
+        r.read_exact(&mut buf4)?;
+        let n = u32::from_le_bytes(buf4) as usize;
+        let mut read_f32 = || -> io::Result<f32> {
+            r.read_exact(&mut buf4)?;
+            Ok(f32::from_le_bytes(buf4))
+        };
+        let bias = read_f32()?;
+        let weights = (0..n).map(|_| read_f32()).collect::<io::Result<_>>()?;
+        let scales = (0..n).map(|_| read_f32()).collect::<io::Result<_>>()?;
+        Ok(Self {
+            weights,
+            bias,
+            scales,
+        })
+    }
+}
+
+impl Default for LinearValue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Evaluator for LinearValue {
+    fn evaluate(&self, env: &Env) -> f32 {
+        if env.is_done() {
+            combat_return(env)
+        } else {
+            self.predict(&encoding::encode(env.game()))
+        }
+    }
+}
+
+pub fn value_agent(model: LinearValue) -> GreedyAgent<LinearValue> {
+    GreedyAgent { evaluator: model }
+}
+
+pub const ACT1_COMBATS: [Combat; 17] = [
+    Combat::Cultist,
+    Combat::JawWorm,
+    Combat::TwoLouses,
+    Combat::SmallSlimes,
+    Combat::BlueSlaver,
+    Combat::GremlinGang,
+    Combat::Looter,
+    Combat::LargeSlime,
+    Combat::LotsOfSlimes,
+    Combat::ExordiumThugs,
+    Combat::ExordiumWildlife,
+    Combat::RedSlaver,
+    Combat::ThreeLouses,
+    Combat::TwoFungiBeasts,
+    Combat::GremlinNob,
+    Combat::Lagavulin,
+    Combat::ThreeSentries,
+];
+
+fn episode_config(seed: u64, combats: &[Combat]) -> EnvConfig {
+    EnvConfig {
+        episode: Episode::starting_deck_combat(combats[seed as usize % combats.len()]),
+        rewards: Default::default(),
+        limits: Default::default(),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TrainConfig {
+    pub episodes: usize,
+    pub alpha: f32,
+    pub lambda: f32,
+    // chance of a random action instead of the greedy one
+    pub epsilon: f32,
+    pub seed: u64,
+    pub combats: Vec<Combat>,
+    pub max_steps: usize,
+}
+
+impl Default for TrainConfig {
+    fn default() -> Self {
+        Self {
+            episodes: 1000,
+            alpha: 0.01,
+            lambda: 0.7,
+            epsilon: 0.1,
+            seed: 0,
+            combats: ACT1_COMBATS.to_vec(),
+            max_steps: 300,
+        }
+    }
+}
+
+// TD(lambda) on self-play: the current model picks moves by one step
+// lookahead and is updated towards the value of the state that followed.
+// `progress` is called with each episode's return.
+pub fn train<F: FnMut(usize, f32)>(model: &mut LinearValue, config: &TrainConfig, mut progress: F) {
+    let mut rng = Rand::seed_from_u64(config.seed);
+    let n = model.weights.len();
+    for episode in 0..config.episodes {
+        let mut env = Env::default();
+        let seed = config.seed.wrapping_add(episode as u64);
+        env.reset(seed, episode_config(seed, &config.combats));
+
+        let mut traces = vec![0.0; n];
+        let mut bias_trace = 0.0;
+        let mut features = encoding::encode(env.game());
+        model.update_scales(&features, &mut traces);
+        for _ in 0..config.max_steps {
+            if env.is_done() {
+                break;
+            }
+            let action = if rng.random::<f32>() < config.epsilon {
+                let actions = agent::legal_actions(&env);
+                actions[rng.random_range(0..actions.len())]
+            } else {
+                agent::best_action(&agent::evaluate_actions(&env, model))
+            };
+            env.step(action);
+
+            let next = if env.is_done() {
+                None
+            } else {
+                let next = encoding::encode(env.game());
+                model.update_scales(&next, &mut traces);
+                Some(next)
+            };
+            let x = model.scaled(&features);
+            let target = match &next {
+                Some(next) => model.predict(next),
+                None => combat_return(&env),
+            };
+            let delta = target - model.predict_scaled(&x);
+            for i in 0..n {
+                traces[i] = config.lambda * traces[i] + x[i];
+                model.weights[i] += config.alpha * delta * traces[i];
+            }
+            bias_trace = config.lambda * bias_trace + 1.0;
+            model.bias += config.alpha * delta * bias_trace;
+            if let Some(next) = next {
+                features = next;
+            }
+        }
+        progress(episode, combat_return(&env));
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EvalResult {
+    pub episodes: usize,
+    pub wins: usize,
+    pub mean_return: f32,
+}
+
+impl EvalResult {
+    pub fn win_rate(&self) -> f32 {
+        self.wins as f32 / self.episodes as f32
+    }
+}
+
+// Plays one episode per seed in `seed..seed + episodes`, cycling through `combats`.
+pub fn evaluate<A: Agent + ?Sized>(
+    agent: &mut A,
+    episodes: usize,
+    seed: u64,
+    combats: &[Combat],
+    max_steps: usize,
+) -> EvalResult {
+    let mut result = EvalResult {
+        episodes,
+        ..Default::default()
+    };
+    for episode in 0..episodes {
+        let seed = seed.wrapping_add(episode as u64);
+        let mut env = Env::default();
+        env.reset(seed, episode_config(seed, combats));
+        let info = agent::run_episode(&mut env, agent, max_steps);
+        if info.combats_won > 0 {
+            result.wins += 1;
+        }
+        result.mean_return += combat_return(&env) / episodes as f32;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_load() {
+        let mut model = LinearValue::new();
+        model.weights[3] = 1.5;
+        model.scales[3] = 4.0;
+        model.bias = -0.25;
+        let path = std::env::temp_dir().join(format!("slay-i-value-{}.bin", std::process::id()));
+        model.save(&path).unwrap();
+        let loaded = LinearValue::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(model, loaded);
+    }
+
+    #[test]
+    fn test_update_scales_keeps_prediction() {
+        let mut model = LinearValue::new();
+        let mut features = vec![0.0; model.weights.len()];
+        features[0] = 2.0;
+        features[1] = -3.0;
+        model.weights[0] = 0.5;
+        model.weights[1] = 0.25;
+        let before = model.predict(&features);
+        let mut traces = vec![1.0; model.weights.len()];
+        features[1] = -6.0;
+        model.update_scales(&features, &mut traces);
+        assert_eq!(model.scales[1], 6.0);
+        assert_eq!(traces[1], 1.0 / 6.0);
+        features[1] = -3.0;
+        assert!((model.predict(&features) - before).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_train() {
+        let mut model = LinearValue::new();
+        let config = TrainConfig {
+            episodes: 2,
+            combats: vec![Combat::Cultist],
+            max_steps: 100,
+            ..Default::default()
+        };
+        let mut returns = vec![];
+        train(&mut model, &config, |_, r| returns.push(r));
+        assert_eq!(returns.len(), 2);
+        assert_ne!(model, LinearValue::new());
+        assert!(model.weights.iter().all(|w| w.is_finite()));
+
+        let result = evaluate(&mut value_agent(model), 1, 0, &[Combat::Cultist], 100);
+        assert_eq!(result.episodes, 1);
+    }
+
+    #[test]
+    fn test_trained_value_ranks_states() {
+        let mut model = LinearValue::new();
+        let config = TrainConfig {
+            episodes: 20,
+            combats: vec![Combat::Cultist],
+            max_steps: 100,
+            ..Default::default()
+        };
+        train(&mut model, &config, |_, _| {});
+
+        let mut env = Env::default();
+        env.reset(100, episode_config(0, &config.combats));
+        let mut winning = env.game().clone();
+        winning.monsters[0].creature.cur_hp = 1;
+        let mut losing = env.game().clone();
+        losing.player.cur_hp = 1;
+        assert!(
+            model.predict(&encoding::encode(&winning)) > model.predict(&encoding::encode(&losing))
+        );
+    }
+}